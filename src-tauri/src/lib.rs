@@ -58,38 +58,125 @@ pub enum Operations {
     Adler32CheckSum,
     AffineCipherDecode,
     AffineCipherEncode,
+    AnalyseBlocks,
     AnalyseHash,
     Argon2Compare,
     Argon2,
+    ARIADecrypt,
+    ARIAEncrypt,
     AtbashCipher,
+    AutokeyCipherDecode,
+    AutokeyCipherEncode,
     BaconCipherEncode,
     BaconCipherDecode,
     BcryptCompare,
     Bcrypt,
     BcryptParse,
+    BeaufortCipherDecode,
+    BeaufortCipherEncode,
+    BifidCipherDecode,
     BifidCipherEncode,
     Blake2b,
     Blake2s,
+    BlowfishDecrypt,
+    BlowfishEncrypt,
+    CaesarCipher,
+    CamelliaDecrypt,
+    CamelliaEncrypt,
+    ColumnarTranspositionDecode,
+    ColumnarTranspositionEncode,
+    CrackAffineCipher,
+    CrackCaesarCipher,
+    CrackHillCipher,
+    CrackSubstitutionCipher,
+    CrackVigenereCipher,
+    DESDecrypt,
+    DESEncrypt,
+    ECCompressPoint,
+    ECDecompressPoint,
+    ECDHSharedSecret,
+    ECDSASign,
+    ECDSAVerify,
+    ECGenerateKeyPair,
+    Ed25519GenerateKeyPair,
+    Ed25519Sign,
+    Ed25519Verify,
+    Enigma,
     FromBase64,
     FromBase,
+    GenerateKeywordAlphabet,
+    GOSTGenerateKeyPair,
+    GOSTSign,
+    GOSTVerify,
+    GronsfeldCipherDecode,
+    GronsfeldCipherEncode,
+    HillCipherDecode,
+    HillCipherEncode,
     HMAC,
+    JWTDecode,
+    JWTSign,
+    JWTVerify,
     KuznechikDecrypt,
     KuznechikEncrypt,
+    Lorenz,
     MagmaDecrypt,
     MagmaEncrypt,
     MD2,
     MD4,
     MD5,
+    PaddingOracleAttack,
+    ParseASN1,
+    ParseCSR,
+    ParseX509Certificate,
+    PGPDecrypt,
+    PGPEncrypt,
+    PGPGenerateKeyPair,
+    PGPImportKey,
+    PGPListPackets,
+    PGPSign,
+    PGPVerify,
+    PlayfairCipherDecode,
+    PlayfairCipherEncode,
+    PortaCipher,
+    RailFenceCipherDecode,
+    RailFenceCipherEncode,
+    RC2Decrypt,
+    RC2Encrypt,
+    RC4,
+    RC4Drop,
+    ROT13,
+    ROT47,
+    ROT8000,
+    RouteCipherDecode,
+    RouteCipherEncode,
+    RSAConvertKey,
     RSADecrypt,
     RSAEncrypt,
+    RSAGenerateKeyPair,
+    RSASign,
+    RSAVerify,
     Scrypt,
+    ScytaleCipherDecode,
+    ScytaleCipherEncode,
+    SerpentDecrypt,
+    SerpentEncrypt,
     SHA1,
     SHA2,
     SHA3,
+    SM4Decrypt,
+    SM4Encrypt,
+    Substitute,
     ToBase64,
     ToBase,
+    TripleDESDecrypt,
+    TripleDESEncrypt,
+    TwofishDecrypt,
+    TwofishEncrypt,
+    Typex,
     VigenereCipherDecode,
     VigenereCipherEncode,
+    X25519GenerateKeyPair,
+    X25519SharedSecret,
 }
 
 pub const DOCS_URL: &str = "soon I transfer all documentation to somewhere :/";
@@ -0,0 +1,280 @@
+use itertools::Itertools;
+use regex::Regex;
+use serde::{
+    de::{self, Unexpected},
+    Deserialize, Deserializer,
+};
+
+use crate::utils::SupportedLanguages;
+
+/// Ordered symbols of a classical cipher alphabet with their lower and upper case forms and the
+/// layout of the Polybius square. Operations take it either by a preset name, one of "en", "ru",
+/// "ru_with_yo", "uk", "de", or as a custom string of symbols like "αβγδεζηθικλμνξοπρστυφχψω".
+#[derive(Clone)]
+pub struct Alphabet {
+    lower: String,
+    upper: String,
+    /// Upper case symbols of the Polybius square row by row, if the alphabet has one.
+    polybius: Option<String>,
+    /// Letter merged into another one to fit the Polybius square, like J into I for English.
+    polybius_merge: Option<(char, char)>,
+}
+
+impl Alphabet {
+    /// Alphabet by its preset name or built from the custom string of symbols, the symbols must
+    /// be distinct regardless of their case and there must be at least two of them.
+    pub fn new(name: &str) -> Option<Self> {
+        Self::preset(name).or_else(|| Self::custom(name))
+    }
+
+    fn preset(name: &str) -> Option<Self> {
+        let (lower, upper, polybius, polybius_merge) = match name {
+            "en" => (
+                "abcdefghijklmnopqrstuvwxyz",
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+                "ABCDEFGHIKLMNOPQRSTUVWXYZ",
+                Some(('J', 'I')),
+            ),
+            "ru" => (
+                "абвгдежзийклмнопрстуфхцчшщъыьэюя",
+                "АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
+                "АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ,.-=",
+                None,
+            ),
+            "ru_with_yo" => (
+                "абвгдеёжзийклмнопрстуфхцчшщъыьэюя",
+                "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
+                "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ,.-",
+                None,
+            ),
+            "uk" => (
+                "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя",
+                "АБВГҐДЕЄЖЗИІЇЙКЛМНОПРСТУФХЦЧШЩЬЮЯ",
+                "АБВГҐДЕЄЖЗИІЇЙКЛМНОПРСТУФХЦЧШЩЬЮЯ,.-",
+                None,
+            ),
+            "de" => (
+                "abcdefghijklmnopqrstuvwxyzäöüß",
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÜẞ",
+                "ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÜẞ,.-=!?",
+                None,
+            ),
+            _ => return None,
+        };
+
+        Some(Self {
+            lower: lower.to_string(),
+            upper: upper.to_string(),
+            polybius: Some(polybius.to_string()),
+            polybius_merge,
+        })
+    }
+
+    /// Symbols without a single character case pair, like digits, are the same in both cases.
+    /// The symbols fill the Polybius square only if their number is a perfect square.
+    fn custom(symbols: &str) -> Option<Self> {
+        let lower: String = symbols
+            .chars()
+            .map(|ch| single_char(ch.to_lowercase(), ch))
+            .collect();
+        let upper: String = symbols
+            .chars()
+            .map(|ch| single_char(ch.to_uppercase(), ch))
+            .collect();
+
+        let length = lower.chars().count();
+        let distinct = lower
+            .chars()
+            .zip(upper.chars())
+            .flat_map(|(l, u)| if l == u { vec![l] } else { vec![l, u] })
+            .all_unique();
+        if length < 2 || !distinct || lower.chars().any(char::is_whitespace) {
+            return None;
+        }
+
+        let side = (length as f64).sqrt().round() as usize;
+        Some(Self {
+            polybius: (side * side == length).then(|| upper.clone()),
+            lower,
+            upper,
+            polybius_merge: None,
+        })
+    }
+
+    pub fn lower(&self) -> &str {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &str {
+        &self.upper
+    }
+
+    pub fn length(&self) -> usize {
+        self.lower.chars().count()
+    }
+
+    /// Index of the symbol in either case.
+    pub fn index_of(&self, ch: char) -> Option<usize> {
+        let position = |alp: &str| alp.chars().position(|x| x == ch);
+        match ch.is_lowercase() {
+            true => position(&self.lower).or_else(|| position(&self.upper)),
+            false => position(&self.upper).or_else(|| position(&self.lower)),
+        }
+    }
+
+    pub fn contains(&self, ch: char) -> bool {
+        self.index_of(ch).is_some()
+    }
+
+    /// Whether the text is not empty and consists only of the symbols of the alphabet.
+    pub fn is_word(&self, text: &str) -> bool {
+        !text.is_empty() && text.chars().all(|ch| self.contains(ch))
+    }
+
+    /// Whether the text is written with this alphabet: besides its symbols it may have only
+    /// punctuation, whitespace and digits.
+    pub fn is_text(&self, text: &str) -> bool {
+        let other = Regex::new(r"^[\p{P}\s\d]$").unwrap();
+        !text.is_empty()
+            && text
+                .chars()
+                .all(|ch| self.contains(ch) || other.is_match(ch.encode_utf8(&mut [0; 4])))
+    }
+
+    /// Upper case form of the symbol, other characters are uppercased as usual.
+    pub fn to_upper(&self, ch: char) -> char {
+        match self.index_of(ch) {
+            Some(idx) => self.upper.chars().nth(idx).unwrap(),
+            None => single_char(ch.to_uppercase(), ch),
+        }
+    }
+
+    /// Lower case form of the symbol, other characters are lowercased as usual.
+    pub fn to_lower(&self, ch: char) -> char {
+        match self.index_of(ch) {
+            Some(idx) => self.lower.chars().nth(idx).unwrap(),
+            None => single_char(ch.to_lowercase(), ch),
+        }
+    }
+
+    /// Symbols of the Polybius square row by row and its side.
+    pub fn polybius(&self) -> Option<(&str, usize)> {
+        let polybius = self.polybius.as_deref()?;
+        let side = (polybius.chars().count() as f64).sqrt().round() as usize;
+        Some((polybius, side))
+    }
+
+    /// Uppercases the letter and merges it into another one if it doesn't fit the Polybius square.
+    pub fn polybius_letter(&self, ch: char) -> char {
+        match (self.to_upper(ch), self.polybius_merge) {
+            (upper, Some((from, to))) if upper == from => to,
+            (upper, _) => upper,
+        }
+    }
+
+    pub fn polybius_normalize(&self, text: &str) -> String {
+        text.chars().map(|ch| self.polybius_letter(ch)).collect()
+    }
+}
+
+/// The only character of the case mapping, or the symbol itself if it maps to several of them
+/// like ß to SS.
+fn single_char(mut chars: impl Iterator<Item = char>, ch: char) -> char {
+    match (chars.next(), chars.next()) {
+        (Some(x), None) => x,
+        _ => ch,
+    }
+}
+
+impl From<SupportedLanguages> for Alphabet {
+    fn from(lang: SupportedLanguages) -> Self {
+        Self::preset(match lang {
+            SupportedLanguages::EN => "en",
+            SupportedLanguages::RU => "ru",
+            SupportedLanguages::RU_WITH_YO => "ru_with_yo",
+        })
+        .unwrap()
+    }
+}
+
+impl<'de> Deserialize<'de> for Alphabet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        Self::new(&name).ok_or_else(|| {
+            de::Error::invalid_value(
+                Unexpected::Str(&name),
+                &"an alphabet preset or a string of at least two distinct symbols",
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::libs::ciphers::caesar_cipher;
+
+    #[test]
+    fn presets() {
+        for (name, length, side) in [
+            ("en", 26, 5),
+            ("ru", 32, 6),
+            ("ru_with_yo", 33, 6),
+            ("uk", 33, 6),
+            ("de", 30, 6),
+        ] {
+            let lang = Alphabet::new(name).unwrap();
+            assert_eq!(lang.length(), length);
+            assert_eq!(lang.upper().chars().count(), length);
+            assert_eq!(lang.polybius().unwrap().1, side);
+        }
+
+        let de = Alphabet::new("de").unwrap();
+        assert_eq!(de.to_upper('ß'), 'ẞ');
+        assert_eq!(de.index_of('Ä'), Some(26));
+        assert_eq!(
+            Alphabet::new("en").unwrap().polybius_normalize("Jump"),
+            "IUMP"
+        );
+    }
+
+    #[test]
+    fn custom() {
+        let greek = Alphabet::new("αβγδεζηθικλμνξοπ").unwrap();
+        assert_eq!(greek.upper(), "ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠ");
+        assert_eq!(greek.polybius(), Some(("ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠ", 4)));
+        assert!(greek.is_word("ΑβΓ"));
+        assert!(greek.is_text("Αβγ, δε 42!"));
+        assert!(!greek.is_text("Αβγ abc"));
+
+        let digits = Alphabet::new("0123456789abc").unwrap();
+        assert_eq!(digits.upper(), "0123456789ABC");
+        assert_eq!(digits.polybius(), None);
+
+        for symbols in ["", "a", "abca", "abA", "ab c"] {
+            assert!(Alphabet::new(symbols).is_none(), "{symbols}");
+        }
+    }
+
+    #[test]
+    fn caesar_round_trip() {
+        for (name, input) in [
+            ("en", "Hello, World! 42"),
+            ("ru_with_yo", "Съешь же ещё, Ёжик!"),
+            ("uk", "Їжак і ґудзик, Єва!"),
+            ("de", "Grüße aus Köln, Straße!"),
+            ("αβγδεζηθικλμνξοπρστυφχψω", "Καλημέρα, κόσμε!"),
+        ] {
+            let lang = Alphabet::new(name).unwrap();
+            let length = lang.length() as i16;
+            for shift in [1, -3, 100, i16::MIN] {
+                let encoded = caesar_cipher(input, &lang, shift);
+                assert_ne!(encoded, input);
+                assert_eq!(caesar_cipher(&encoded, &lang, -(shift % length)), input);
+            }
+        }
+    }
+}
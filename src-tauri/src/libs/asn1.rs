@@ -0,0 +1,485 @@
+use std::borrow::Cow;
+
+use anyhow::{bail, Result};
+use pkcs8::der::pem;
+use x509_parser::oid_registry::Oid;
+
+use crate::{libs::x509::oid_name, utils::decode_der};
+
+const MAX_DEPTH: usize = 64;
+
+const UNIVERSAL: u8 = 0;
+const APPLICATION: u8 = 1;
+const CONTEXT_SPECIFIC: u8 = 2;
+
+struct Header {
+    class: u8,
+    constructed: bool,
+    tag: u32,
+    header_len: usize,
+    /// `None` for the BER indefinite length form.
+    length: Option<usize>,
+}
+
+/// Reads DER/BER written as hex, base64 or PEM with any label.
+pub fn read_asn1(input: &str) -> Result<Vec<u8>> {
+    let input = input.trim();
+    let der = match input.starts_with("-----BEGIN ") {
+        true => match pem::decode_vec(input.as_bytes()) {
+            Ok((_, der)) => der,
+            Err(_) => bail!("Invalid PEM block."),
+        },
+        false => decode_der(input)?,
+    };
+
+    if der.is_empty() {
+        bail!("Input is empty.");
+    }
+
+    Ok(der)
+}
+
+/// Dumps every element in the `openssl asn1parse` layout: offset, depth, header length, content length,
+/// primitive or constructed form, tag and decoded value. Octet and bit strings holding a complete
+/// ASN.1 structure are expanded in place.
+pub fn dump(data: &[u8]) -> Result<String> {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        (offset, _) = element(data, offset, data.len(), 0, &mut lines)?;
+    }
+
+    Ok(lines.join("\n"))
+}
+
+/// Dumps the elements of `data[start..end]`. Returns the offset after the last element.
+/// `indefinite` is the offset of the enclosing element when it uses the indefinite length form.
+fn elements(
+    data: &[u8],
+    start: usize,
+    end: usize,
+    depth: usize,
+    indefinite: Option<usize>,
+    lines: &mut Vec<String>,
+) -> Result<usize> {
+    let mut offset = start;
+    loop {
+        if offset >= end {
+            if let Some(parent) = indefinite {
+                bail!("Missing end-of-contents marker for the element at offset {parent}.");
+            }
+            return Ok(offset);
+        }
+        let (next, end_of_contents) = element(data, offset, end, depth, lines)?;
+        offset = next;
+        if end_of_contents && indefinite.is_some() {
+            return Ok(offset);
+        }
+    }
+}
+
+/// Dumps a single element. Returns the offset after it and whether it was an end-of-contents marker.
+fn element(
+    data: &[u8],
+    offset: usize,
+    end: usize,
+    depth: usize,
+    lines: &mut Vec<String>,
+) -> Result<(usize, bool)> {
+    if depth > MAX_DEPTH {
+        bail!("ASN.1 structure is nested too deeply.");
+    }
+
+    let header = read_header(data, offset, end)?;
+    let start = offset + header.header_len;
+
+    let (length, content_end) = match header.length {
+        Some(length) => (Some(length), start + length),
+        None => (None, end),
+    };
+
+    let line_index = lines.len();
+    lines.push(String::new());
+
+    let mut value = String::new();
+    let next = match (header.constructed, length) {
+        (true, Some(_)) => {
+            elements(data, start, content_end, depth + 1, None, lines)?;
+            content_end
+        }
+        (true, None) => elements(data, start, content_end, depth + 1, Some(offset), lines)?,
+        (false, Some(_)) => {
+            let content = &data[start..content_end];
+            match encapsulated(&header, content) {
+                Some(skip) => {
+                    let mut nested = Vec::new();
+                    match elements(
+                        data,
+                        start + skip,
+                        content_end,
+                        depth + 1,
+                        None,
+                        &mut nested,
+                    ) {
+                        Ok(_) => lines.extend(nested),
+                        Err(_) => value = primitive_value(&header, content),
+                    }
+                }
+                None => value = primitive_value(&header, content),
+            }
+            content_end
+        }
+        (false, None) => bail!("Primitive element at offset {offset} has indefinite length."),
+    };
+
+    let form = match header.constructed {
+        true => "cons",
+        false => "prim",
+    };
+    let length = match length {
+        Some(length) => format!("l={length:4} "),
+        None => "l=inf  ".to_string(),
+    };
+    let line = format!(
+        "{offset:5}:d={depth:<2} hl={} {length}{form}: {:depth$}{:<18}{value}",
+        header.header_len,
+        "",
+        tag_name(&header),
+    );
+    lines[line_index] = line.trim_end().to_string();
+
+    let end_of_contents = header.class == UNIVERSAL && header.tag == 0 && header.length == Some(0);
+
+    Ok((next, end_of_contents))
+}
+
+fn read_header(data: &[u8], offset: usize, end: usize) -> Result<Header> {
+    let truncated = || anyhow::anyhow!("Unexpected end of data in the header at offset {offset}.");
+
+    let mut position = offset;
+    let mut next_byte = || -> Result<u8> {
+        let byte = *data[..end].get(position).ok_or_else(truncated)?;
+        position += 1;
+        Ok(byte)
+    };
+
+    let identifier = next_byte()?;
+    let mut tag = (identifier & 0x1f) as u32;
+    if tag == 0x1f {
+        tag = 0;
+        loop {
+            let byte = next_byte()?;
+            if tag > u32::MAX >> 7 {
+                bail!("Tag number at offset {offset} is too large.");
+            }
+            tag = tag << 7 | (byte & 0x7f) as u32;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let length = match next_byte()? {
+        0x80 => None,
+        0xff => bail!("Invalid length at offset {offset}."),
+        byte if byte & 0x80 == 0 => Some(byte as usize),
+        byte => {
+            let mut length = 0usize;
+            for _ in 0..byte & 0x7f {
+                if length > usize::MAX >> 8 {
+                    bail!("Invalid length at offset {offset}.");
+                }
+                length = length << 8 | next_byte()? as usize;
+            }
+            Some(length)
+        }
+    };
+
+    let header_len = position - offset;
+    if length.is_some_and(|length| length > end - position) {
+        bail!("Length of the element at offset {offset} exceeds the available data.");
+    }
+
+    Ok(Header {
+        class: identifier >> 6,
+        constructed: identifier & 0x20 != 0,
+        tag,
+        header_len,
+        length,
+    })
+}
+
+/// Returns how many leading bytes to skip when the content may hold a nested structure.
+fn encapsulated(header: &Header, content: &[u8]) -> Option<usize> {
+    match (header.class, header.tag) {
+        (UNIVERSAL, 4) if !content.is_empty() => Some(0),
+        (UNIVERSAL, 3) if content.len() > 1 && content[0] == 0 => Some(1),
+        _ => None,
+    }
+}
+
+fn tag_name(header: &Header) -> Cow<'static, str> {
+    let tag = header.tag;
+    match header.class {
+        UNIVERSAL => match tag {
+            0 => "EOC".into(),
+            1 => "BOOLEAN".into(),
+            2 => "INTEGER".into(),
+            3 => "BIT STRING".into(),
+            4 => "OCTET STRING".into(),
+            5 => "NULL".into(),
+            6 => "OBJECT".into(),
+            7 => "OBJECT DESCRIPTOR".into(),
+            8 => "EXTERNAL".into(),
+            9 => "REAL".into(),
+            10 => "ENUMERATED".into(),
+            12 => "UTF8STRING".into(),
+            13 => "RELATIVE OID".into(),
+            16 => "SEQUENCE".into(),
+            17 => "SET".into(),
+            18 => "NUMERICSTRING".into(),
+            19 => "PRINTABLESTRING".into(),
+            20 => "T61STRING".into(),
+            21 => "VIDEOTEXSTRING".into(),
+            22 => "IA5STRING".into(),
+            23 => "UTCTIME".into(),
+            24 => "GENERALIZEDTIME".into(),
+            25 => "GRAPHICSTRING".into(),
+            26 => "VISIBLESTRING".into(),
+            27 => "GENERALSTRING".into(),
+            28 => "UNIVERSALSTRING".into(),
+            30 => "BMPSTRING".into(),
+            _ => format!("<ASN1 {tag}>").into(),
+        },
+        APPLICATION => format!("appl [ {tag} ]").into(),
+        CONTEXT_SPECIFIC => format!("cont [ {tag} ]").into(),
+        _ => format!("priv [ {tag} ]").into(),
+    }
+}
+
+fn primitive_value(header: &Header, content: &[u8]) -> String {
+    if header.class != UNIVERSAL {
+        return text_or_hex(content);
+    }
+
+    match header.tag {
+        0 | 5 => String::new(),
+        1 => match content {
+            [0] => ":FALSE".to_string(),
+            [_] => ":TRUE".to_string(),
+            _ => ":BAD BOOLEAN".to_string(),
+        },
+        2 | 10 => integer(content),
+        3 => match content.split_first() {
+            Some((0, bits)) => format!("[HEX DUMP]:{}", upper_hex(bits)),
+            Some((unused, bits)) => {
+                format!("[HEX DUMP]:{} ({unused} unused bits)", upper_hex(bits))
+            }
+            None => ":BAD BIT STRING".to_string(),
+        },
+        4 => text_or_hex(content),
+        6 => match valid_oid(content) {
+            true => format!(":{}", oid_name(&Oid::new(Cow::Borrowed(content)))),
+            false => ":BAD OBJECT".to_string(),
+        },
+        12 | 18..=27 => format!(":{}", String::from_utf8_lossy(content)),
+        28 => format!(
+            ":{}",
+            content
+                .chunks(4)
+                .map(|c| {
+                    let code = c.iter().fold(0u32, |acc, &byte| acc << 8 | byte as u32);
+                    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                })
+                .collect::<String>()
+        ),
+        30 => format!(
+            ":{}",
+            String::from_utf16_lossy(
+                &content
+                    .chunks(2)
+                    .map(|c| c.iter().fold(0u16, |acc, &byte| acc << 8 | byte as u16))
+                    .collect::<Vec<_>>()
+            )
+        ),
+        _ => text_or_hex(content),
+    }
+}
+
+/// Writes an INTEGER as upper-case hex, negative values as minus and magnitude.
+fn integer(content: &[u8]) -> String {
+    let Some(&first) = content.first() else {
+        return ":BAD INTEGER".to_string();
+    };
+
+    let (sign, mut magnitude) = match first & 0x80 {
+        0 => ("", content.to_vec()),
+        _ => {
+            let mut magnitude: Vec<u8> = content.iter().map(|byte| !byte).collect();
+            for byte in magnitude.iter_mut().rev() {
+                let (sum, overflow) = byte.overflowing_add(1);
+                *byte = sum;
+                if !overflow {
+                    break;
+                }
+            }
+            ("-", magnitude)
+        }
+    };
+    while magnitude.len() > 1 && magnitude[0] == 0 {
+        magnitude.remove(0);
+    }
+
+    format!(":{sign}{}", upper_hex(&magnitude))
+}
+
+fn text_or_hex(content: &[u8]) -> String {
+    match !content.is_empty() && content.iter().all(|byte| (0x20..0x7f).contains(byte)) {
+        true => format!(":{}", String::from_utf8_lossy(content)),
+        false if content.is_empty() => String::new(),
+        false => format!("[HEX DUMP]:{}", upper_hex(content)),
+    }
+}
+
+fn valid_oid(content: &[u8]) -> bool {
+    content.last().is_some_and(|byte| byte & 0x80 == 0)
+        && content
+            .iter()
+            .enumerate()
+            .all(|(i, &byte)| byte != 0x80 || (i > 0 && content[i - 1] & 0x80 != 0))
+}
+
+fn upper_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02X}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // PKCS#10 request made with OpenSSL 3.5, the dump matches `openssl asn1parse -i` apart from
+    // the short OID names and the expanded OCTET and BIT STRING contents.
+    const CSR_PEM: &str = r"-----BEGIN CERTIFICATE REQUEST-----
+MIIBPTCB4wIBADA5MQswCQYDVQQGEwJHQjEUMBIGA1UECgwLRXhhbXBsZSBMdGQx
+FDASBgNVBAMMC2V4YW1wbGUuY29tMFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE
+CiK/J0QO2SxO8ouMSlRrejlEmbos8YYg1xZJ5HZPg+xWLGNVjw9Y9EyNH0YDfcaa
+l2smxuMN74pWwh8KzQqkNKBIMEYGCSqGSIb3DQEJDjE5MDcwKAYDVR0RBCEwH4IL
+ZXhhbXBsZS5jb22HECABDbgAAAAAAAAAAAAAAAEwCwYDVR0PBAQDAgeAMAoGCCqG
+SM49BAMCA0kAMEYCIQDKdZGnh2K/Zqv58a4vaQsN3sy414aQi1NozCCLLWkQ8gIh
+AMgFcHD83BM8xFhYxnbBI9RBYhOWJzN0n+KKikPoDTiw
+-----END CERTIFICATE REQUEST-----";
+
+    #[test]
+    fn certificate_request() {
+        assert_eq!(
+            dump(&read_asn1(CSR_PEM).unwrap()).unwrap(),
+            "    0:d=0  hl=4 l= 317 cons: SEQUENCE
+    4:d=1  hl=3 l= 227 cons:  SEQUENCE
+    7:d=2  hl=2 l=   1 prim:   INTEGER           :00
+   10:d=2  hl=2 l=  57 cons:   SEQUENCE
+   12:d=3  hl=2 l=  11 cons:    SET
+   14:d=4  hl=2 l=   9 cons:     SEQUENCE
+   16:d=5  hl=2 l=   3 prim:      OBJECT            :countryName
+   21:d=5  hl=2 l=   2 prim:      PRINTABLESTRING   :GB
+   25:d=3  hl=2 l=  20 cons:    SET
+   27:d=4  hl=2 l=  18 cons:     SEQUENCE
+   29:d=5  hl=2 l=   3 prim:      OBJECT            :organizationName
+   34:d=5  hl=2 l=  11 prim:      UTF8STRING        :Example Ltd
+   47:d=3  hl=2 l=  20 cons:    SET
+   49:d=4  hl=2 l=  18 cons:     SEQUENCE
+   51:d=5  hl=2 l=   3 prim:      OBJECT            :commonName
+   56:d=5  hl=2 l=  11 prim:      UTF8STRING        :example.com
+   69:d=2  hl=2 l=  89 cons:   SEQUENCE
+   71:d=3  hl=2 l=  19 cons:    SEQUENCE
+   73:d=4  hl=2 l=   7 prim:     OBJECT            :id-ecPublicKey
+   82:d=4  hl=2 l=   8 prim:     OBJECT            :prime256v1
+   92:d=3  hl=2 l=  66 prim:    BIT STRING        [HEX DUMP]:040A22BF27440ED92C4EF28B8C4A546B7A394499BA2CF18620D71649E4764F83EC562C63558F0F58F44C8D1F46037DC69A976B26C6E30DEF8A56C21F0ACD0AA434
+  160:d=2  hl=2 l=  72 cons:   cont [ 0 ]
+  162:d=3  hl=2 l=  70 cons:    SEQUENCE
+  164:d=4  hl=2 l=   9 prim:     OBJECT            :extensionRequest
+  175:d=4  hl=2 l=  57 cons:     SET
+  177:d=5  hl=2 l=  55 cons:      SEQUENCE
+  179:d=6  hl=2 l=  40 cons:       SEQUENCE
+  181:d=7  hl=2 l=   3 prim:        OBJECT            :subjectAltName
+  186:d=7  hl=2 l=  33 prim:        OCTET STRING
+  188:d=8  hl=2 l=  31 cons:         SEQUENCE
+  190:d=9  hl=2 l=  11 prim:          cont [ 2 ]        :example.com
+  203:d=9  hl=2 l=  16 prim:          cont [ 7 ]        [HEX DUMP]:20010DB8000000000000000000000001
+  221:d=6  hl=2 l=  11 cons:       SEQUENCE
+  223:d=7  hl=2 l=   3 prim:        OBJECT            :keyUsage
+  228:d=7  hl=2 l=   4 prim:        OCTET STRING
+  230:d=8  hl=2 l=   2 prim:         BIT STRING        [HEX DUMP]:80 (7 unused bits)
+  234:d=1  hl=2 l=  10 cons:  SEQUENCE
+  236:d=2  hl=2 l=   8 prim:   OBJECT            :ecdsa-with-SHA256
+  246:d=1  hl=2 l=  73 prim:  BIT STRING
+  249:d=2  hl=2 l=  70 cons:   SEQUENCE
+  251:d=3  hl=2 l=  33 prim:    INTEGER           :CA7591A78762BF66ABF9F1AE2F690B0DDECCB8D786908B5368CC208B2D6910F2
+  286:d=3  hl=2 l=  33 prim:    INTEGER           :C8057070FCDC133CC45858C676C123D4416213962733749FE28A8A43E80D38B0"
+        );
+    }
+
+    #[test]
+    fn indefinite_length() {
+        let ber = read_asn1("30800201052480040261620401630000000031800101000000").unwrap();
+        assert_eq!(
+            dump(&ber).unwrap(),
+            "    0:d=0  hl=2 l=inf  cons: SEQUENCE
+    2:d=1  hl=2 l=   1 prim:  INTEGER           :05
+    5:d=1  hl=2 l=inf  cons:  OCTET STRING
+    7:d=2  hl=2 l=   2 prim:   OCTET STRING      :ab
+   11:d=2  hl=2 l=   1 prim:   OCTET STRING      :c
+   14:d=2  hl=2 l=   0 prim:   EOC
+   16:d=1  hl=2 l=   0 prim:  EOC
+   18:d=0  hl=2 l=inf  cons: SET
+   20:d=1  hl=2 l=   1 prim:  BOOLEAN           :FALSE
+   23:d=1  hl=2 l=   0 prim:  EOC"
+        );
+    }
+
+    #[test]
+    fn input_formats() {
+        let der = read_asn1(CSR_PEM).unwrap();
+        let base64: String = CSR_PEM
+            .lines()
+            .filter(|x| !x.starts_with("-----"))
+            .collect();
+        assert_eq!(read_asn1(&base64).unwrap(), der);
+        assert_eq!(read_asn1(&crate::utils::to_hex(&der)).unwrap(), der);
+    }
+
+    #[test]
+    fn invalid_input() {
+        for (input, error) in [
+            ("", "Input is empty."),
+            (
+                "-----BEGIN DATA-----\n!!\n-----END DATA-----",
+                "Invalid PEM block.",
+            ),
+        ] {
+            assert_eq!(read_asn1(input).unwrap_err().to_string(), error);
+        }
+
+        for (input, error) in [
+            (
+                "308002010500",
+                "Unexpected end of data in the header at offset 5.",
+            ),
+            (
+                "3080020105",
+                "Missing end-of-contents marker for the element at offset 0.",
+            ),
+            (
+                "3004020105",
+                "Length of the element at offset 0 exceeds the available data.",
+            ),
+            (
+                "0480",
+                "Primitive element at offset 0 has indefinite length.",
+            ),
+            ("30ff", "Invalid length at offset 0."),
+        ] {
+            let der = read_asn1(input).unwrap();
+            assert_eq!(dump(&der).unwrap_err().to_string(), error);
+        }
+    }
+}
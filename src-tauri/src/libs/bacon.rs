@@ -2,7 +2,7 @@ use std::collections::BTreeMap;
 
 use serde::Deserialize;
 
-use crate::utils::{get_alphabet, SupportedLanguages};
+use crate::{libs::alphabet::Alphabet, utils::SupportedLanguages};
 
 pub struct BaconCipher {
     en_map: BTreeMap<char, String>,
@@ -15,14 +15,19 @@ impl BaconCipher {
         item_b: char,
         translation: SupportedBaconTranslation,
         alp: SupportedBaconAlphabet,
-        lang: SupportedLanguages,
+        lang: Alphabet,
     ) -> Self {
-        let (alphabet, _, _, _, length, _) = get_alphabet(&lang);
+        let alphabet = lang.lower();
+        let length = lang.length();
+        let width = match is_legacy_preset(alphabet) {
+            true => (length as f64).sqrt().round() as usize,
+            false => (usize::BITS - (length - 1).leading_zeros()) as usize,
+        };
 
         let en_map = alphabet
             .chars()
             .map(|c| {
-                Self::char_by_alphabet(c, alphabet, &translation, &alp, &length, &item_a, &item_b)
+                Self::char_by_alphabet(c, alphabet, &translation, &alp, &width, &item_a, &item_b)
             })
             .collect();
 
@@ -34,7 +39,7 @@ impl BaconCipher {
                     alphabet,
                     &translation,
                     &alp,
-                    &length,
+                    &width,
                     &item_a,
                     &item_b,
                 );
@@ -71,12 +76,15 @@ impl BaconCipher {
         alphabet: &str,
         translation: &SupportedBaconTranslation,
         alp: &SupportedBaconAlphabet,
-        length: &u8,
+        width: &usize,
         a: &char,
         b: &char,
     ) -> (char, String) {
+        // I and J, U and V share their codes in the standard alphabet, which is defined only for
+        // the latin letters.
+        let latin = alphabet.starts_with("abcdefghijklmnopqrstuvwxyz") && c.is_ascii_lowercase();
         let костыль = match alp {
-            SupportedBaconAlphabet::Standard => {
+            SupportedBaconAlphabet::Standard if latin => {
                 if ('a'..'j').contains(&c) {
                     0
                 } else if ('j'..'v').contains(&c) {
@@ -85,29 +93,43 @@ impl BaconCipher {
                     2
                 }
             }
-            SupportedBaconAlphabet::Complete => 0,
+            _ => 0,
         };
 
-        let string = match (*length as f64).sqrt().round() as i8 {
-            5 => format!("{:05b}", alphabet.find(c).unwrap() - костыль),
-            6 => format!("{:06b}", alphabet.find(c).unwrap()),
-            _ => unreachable!(),
-        }
-        .chars()
-        .map(|x| match translation {
-            SupportedBaconTranslation::ZeroOne => x,
-            SupportedBaconTranslation::AB => match x {
-                '0' => *a,
-                '1' => *b,
-                _ => unreachable!(),
-            },
-        })
-        .collect();
+        let index = match is_legacy_preset(alphabet) {
+            true => alphabet.find(c),
+            false => alphabet.chars().position(|x| x == c),
+        };
+        let index = index.unwrap() - костыль;
+        let string = format!("{index:0width$b}")
+            .chars()
+            .map(|x| match translation {
+                SupportedBaconTranslation::ZeroOne => x,
+                SupportedBaconTranslation::AB => match x {
+                    '0' => *a,
+                    '1' => *b,
+                    _ => unreachable!(),
+                },
+            })
+            .collect();
 
         (c, string)
     }
 }
 
+/// The English and Russian presets keep the codes they had before custom alphabets, so the texts
+/// encoded earlier still decode: the width is the rounded square root of the alphabet length and
+/// the letters are numbered by their byte offset in the alphabet.
+fn is_legacy_preset(alphabet: &str) -> bool {
+    [
+        SupportedLanguages::EN,
+        SupportedLanguages::RU,
+        SupportedLanguages::RU_WITH_YO,
+    ]
+    .into_iter()
+    .any(|lang| Alphabet::from(lang).lower() == alphabet)
+}
+
 impl Default for BaconCipher {
     fn default() -> Self {
         BaconCipher::new(
@@ -115,7 +137,7 @@ impl Default for BaconCipher {
             'B',
             SupportedBaconTranslation::AB,
             SupportedBaconAlphabet::Standard,
-            SupportedLanguages::EN,
+            Alphabet::from(SupportedLanguages::EN),
         )
     }
 }
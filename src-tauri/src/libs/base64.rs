@@ -8,3 +8,11 @@ pub fn to_base64(data: &[u8]) -> String {
 pub fn from_base64(data: &str) -> Result<Vec<u8>> {
     Ok(general_purpose::STANDARD.decode(data.as_bytes())?)
 }
+
+pub fn to_base64_url(data: &[u8]) -> String {
+    general_purpose::URL_SAFE_NO_PAD.encode(data)
+}
+
+pub fn from_base64_url(data: &str) -> Result<Vec<u8>> {
+    Ok(general_purpose::URL_SAFE_NO_PAD.decode(data.trim_end_matches('=').as_bytes())?)
+}
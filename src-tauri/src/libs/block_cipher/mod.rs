@@ -0,0 +1,413 @@
+use anyhow::{anyhow, bail, Result};
+use aria::{Aria128, Aria192, Aria256};
+use blowfish::Blowfish;
+use camellia::{Camellia128, Camellia192, Camellia256};
+use cipher::{
+    generic_array::GenericArray, BlockDecrypt, BlockEncrypt, BlockSizeUser, InvalidLength, KeyInit,
+};
+use des::{Des, TdesEde2, TdesEde3};
+use kuznyechik::Kuznyechik;
+use magma::Magma;
+use rc2::Rc2;
+use serde::Deserialize;
+use serpent::Serpent;
+use sm4::Sm4;
+use twofish::Twofish;
+
+use crate::{
+    libs::base64::{from_base64, to_base64},
+    utils::{from_hex, to_hex},
+};
+
+pub mod modes;
+pub mod padding;
+pub mod padding_oracle;
+
+pub use modes::Mode;
+pub use padding::Padding;
+
+pub trait BlockPrimitive {
+    fn block_size(&self) -> usize;
+    fn encrypt_block(&self, block: &mut [u8]);
+    fn decrypt_block(&self, block: &mut [u8]);
+}
+
+impl<C: BlockEncrypt + BlockDecrypt> BlockPrimitive for C {
+    fn block_size(&self) -> usize {
+        <C as BlockSizeUser>::block_size()
+    }
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        BlockEncrypt::encrypt_block(self, GenericArray::from_mut_slice(block))
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        BlockDecrypt::decrypt_block(self, GenericArray::from_mut_slice(block))
+    }
+}
+
+#[derive(Deserialize, Copy, Clone, Debug)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum BlockCipherFunction {
+    DES,
+    TripleDES,
+    Blowfish,
+    RC2,
+    SM4,
+    Camellia,
+    ARIA,
+    Twofish,
+    Serpent,
+    Magma,
+    Kuznechik,
+}
+
+impl BlockCipherFunction {
+    pub fn new_cipher(&self, key: &[u8]) -> Result<Box<dyn BlockPrimitive>> {
+        let invalid_key = |_: InvalidLength| anyhow!(self.key_error(key));
+
+        let cipher: Box<dyn BlockPrimitive> = match self {
+            Self::DES => Box::new(Des::new_from_slice(key).map_err(invalid_key)?),
+            Self::TripleDES => match key.len() {
+                16 => Box::new(TdesEde2::new_from_slice(key).map_err(invalid_key)?),
+                _ => Box::new(TdesEde3::new_from_slice(key).map_err(invalid_key)?),
+            },
+            Self::Blowfish => Box::new(<Blowfish>::new_from_slice(key).map_err(invalid_key)?),
+            Self::RC2 => Box::new(Rc2::new_from_slice(key).map_err(invalid_key)?),
+            Self::SM4 => Box::new(Sm4::new_from_slice(key).map_err(invalid_key)?),
+            Self::Camellia => match key.len() {
+                16 => Box::new(Camellia128::new_from_slice(key).map_err(invalid_key)?),
+                24 => Box::new(Camellia192::new_from_slice(key).map_err(invalid_key)?),
+                _ => Box::new(Camellia256::new_from_slice(key).map_err(invalid_key)?),
+            },
+            Self::ARIA => match key.len() {
+                16 => Box::new(Aria128::new_from_slice(key).map_err(invalid_key)?),
+                24 => Box::new(Aria192::new_from_slice(key).map_err(invalid_key)?),
+                _ => Box::new(Aria256::new_from_slice(key).map_err(invalid_key)?),
+            },
+            Self::Twofish => Box::new(Twofish::new_from_slice(key).map_err(invalid_key)?),
+            Self::Serpent => Box::new(Serpent::new_from_slice(key).map_err(invalid_key)?),
+            Self::Magma => Box::new(Magma::new_from_slice(key).map_err(invalid_key)?),
+            Self::Kuznechik => Box::new(Kuznyechik::new_from_slice(key).map_err(invalid_key)?),
+        };
+
+        Ok(cipher)
+    }
+
+    fn key_error(&self, key: &[u8]) -> String {
+        let expected = match self {
+            Self::DES => "8 bytes",
+            Self::TripleDES => "16 bytes (EDE2) or 24 bytes (EDE3)",
+            Self::Blowfish => "from 4 to 56 bytes",
+            Self::RC2 => "from 1 to 128 bytes",
+            Self::SM4 => "16 bytes",
+            Self::Magma | Self::Kuznechik => "32 bytes",
+            Self::Camellia | Self::ARIA | Self::Twofish => "16, 24 or 32 bytes",
+            Self::Serpent => "up to 32 bytes",
+        };
+        format!(
+            "Invalid key length for {self:?}: expected {expected}, got {} bytes.",
+            key.len()
+        )
+    }
+}
+
+pub struct BlockCipher {
+    algorithm: BlockCipherFunction,
+    input: Vec<u8>,
+    key: Vec<u8>,
+    iv: Vec<u8>,
+    mode: Mode,
+    padding: Padding,
+}
+
+impl BlockCipher {
+    pub fn new(algorithm: BlockCipherFunction) -> Self {
+        Self {
+            algorithm,
+            input: Vec::new(),
+            key: Vec::new(),
+            iv: Vec::new(),
+            mode: Mode::CBC,
+            padding: Padding::PKCS7,
+        }
+    }
+
+    pub fn encrypt(&self, format: OutputFormat) -> Result<String> {
+        let output = match self.mode {
+            Mode::XTS => {
+                let (cipher, tweak_cipher) = self.xts_ciphers()?;
+                modes::xts_encrypt(
+                    cipher.as_ref(),
+                    tweak_cipher.as_ref(),
+                    &self.iv,
+                    &self.input,
+                )?
+            }
+            mode => {
+                let cipher = self.algorithm.new_cipher(&self.key)?;
+                modes::encrypt(cipher.as_ref(), mode, self.padding, &self.iv, &self.input)?
+            }
+        };
+
+        Ok(encode_output(&output, format))
+    }
+
+    pub fn decrypt(&self, format: OutputFormat) -> Result<String> {
+        let output = match self.mode {
+            Mode::XTS => {
+                let (cipher, tweak_cipher) = self.xts_ciphers()?;
+                modes::xts_decrypt(
+                    cipher.as_ref(),
+                    tweak_cipher.as_ref(),
+                    &self.iv,
+                    &self.input,
+                )?
+            }
+            mode => {
+                let cipher = self.algorithm.new_cipher(&self.key)?;
+                modes::decrypt(cipher.as_ref(), mode, self.padding, &self.iv, &self.input)?
+            }
+        };
+
+        Ok(encode_output(&output, format))
+    }
+
+    pub fn set_mode(self, mode: Mode) -> Self {
+        Self { mode, ..self }
+    }
+
+    pub fn set_padding(self, padding: Padding) -> Self {
+        Self { padding, ..self }
+    }
+
+    pub fn set_input(self, input: &str, format: InputFormat) -> Result<Self> {
+        Ok(Self {
+            input: decode_input(input, format)?,
+            ..self
+        })
+    }
+
+    pub fn set_key(self, key: &[u8]) -> Result<Self> {
+        Ok(Self {
+            key: key.to_vec(),
+            ..self
+        })
+    }
+
+    pub fn set_iv(self, iv: &[u8]) -> Result<Self> {
+        Ok(Self {
+            iv: iv.to_vec(),
+            ..self
+        })
+    }
+
+    fn xts_ciphers(&self) -> Result<(Box<dyn BlockPrimitive>, Box<dyn BlockPrimitive>)> {
+        if self.key.len() % 2 != 0 {
+            bail!(
+                "XTS key must be a concatenation of two keys of equal length, got {} bytes.",
+                self.key.len()
+            );
+        }
+
+        let (data_key, tweak_key) = self.key.split_at(self.key.len() / 2);
+        Ok((
+            self.algorithm.new_cipher(data_key)?,
+            self.algorithm.new_cipher(tweak_key)?,
+        ))
+    }
+}
+
+/// `Raw` output is decoded as UTF-8 with invalid sequences replaced, it doesn't keep arbitrary bytes.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Hex,
+    Base64,
+    Raw,
+}
+
+/// `Raw` input is taken as UTF-8 text.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum InputFormat {
+    Hex,
+    Base64,
+    Raw,
+}
+
+pub fn decode_input(input: &str, format: InputFormat) -> Result<Vec<u8>> {
+    Ok(match format {
+        InputFormat::Hex => from_hex(input)?,
+        InputFormat::Base64 => from_base64(input)?,
+        InputFormat::Raw => input.as_bytes().to_vec(),
+    })
+}
+
+pub fn encode_output(output: &[u8], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Hex => to_hex(output),
+        OutputFormat::Base64 => to_base64(output),
+        OutputFormat::Raw => String::from_utf8_lossy(output).to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypt_block(algorithm: BlockCipherFunction, key: &str, plaintext: &str) -> String {
+        let cipher = algorithm.new_cipher(&from_hex(key).unwrap()).unwrap();
+        let mut block = from_hex(plaintext).unwrap();
+        cipher.encrypt_block(&mut block);
+        let encrypted = to_hex(&block);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(to_hex(&block), plaintext, "{algorithm:?}");
+        encrypted
+    }
+
+    #[test]
+    fn known_answers() {
+        use BlockCipherFunction::*;
+
+        for (algorithm, key, plaintext, ciphertext) in [
+            // Worked example from J. Orlin Grabbe's DES walkthrough.
+            (
+                DES,
+                "133457799bbcdff1",
+                "0123456789abcdef",
+                "85e813540f0ab405",
+            ),
+            // SP 800-67 example, the 16-byte key is checked against OpenSSL des-ede.
+            (
+                TripleDES,
+                "0123456789abcdef23456789abcdef01456789abcdef0123",
+                "5468652071756663",
+                "a826fd8ce53b855f",
+            ),
+            (
+                TripleDES,
+                "0123456789abcdef23456789abcdef01",
+                "5468652071756663",
+                "c44862f70cf2fbdc",
+            ),
+            // Schneier's test vectors.
+            (
+                Blowfish,
+                "0000000000000000",
+                "0000000000000000",
+                "4ef997456198dd78",
+            ),
+            (
+                Blowfish,
+                "fedcba9876543210",
+                "0123456789abcdef",
+                "0aceab0fc6a0a28d",
+            ),
+            // RFC 2268, the effective key length equals the key length.
+            (
+                RC2,
+                "ffffffffffffffff",
+                "ffffffffffffffff",
+                "278b27e42e2f0d49",
+            ),
+            (
+                RC2,
+                "88bca90e90875a7f0f79c384627bafb2",
+                "0000000000000000",
+                "2269552ab0f85ca6",
+            ),
+            // GB/T 32907 example 1.
+            (
+                SM4,
+                "0123456789abcdeffedcba9876543210",
+                "0123456789abcdeffedcba9876543210",
+                "681edf34d206965e86b3e94f536e4246",
+            ),
+            // RFC 3713 appendix A.
+            (
+                Camellia,
+                "0123456789abcdeffedcba9876543210",
+                "0123456789abcdeffedcba9876543210",
+                "67673138549669730857065648eabe43",
+            ),
+            (
+                Camellia,
+                "0123456789abcdeffedcba98765432100011223344556677",
+                "0123456789abcdeffedcba9876543210",
+                "b4993401b3e996f84ee5cee7d79b09b9",
+            ),
+            (
+                Camellia,
+                "0123456789abcdeffedcba987654321000112233445566778899aabbccddeeff",
+                "0123456789abcdeffedcba9876543210",
+                "9acc237dff16d76c20ef7c919e3a7509",
+            ),
+            // RFC 5794 appendix A.
+            (
+                ARIA,
+                "000102030405060708090a0b0c0d0e0f",
+                "00112233445566778899aabbccddeeff",
+                "d718fbd6ab644c739da95f3be6451778",
+            ),
+            (
+                ARIA,
+                "000102030405060708090a0b0c0d0e0f1011121314151617",
+                "00112233445566778899aabbccddeeff",
+                "26449c1805dbe7aa25a468ce263a9e79",
+            ),
+            (
+                ARIA,
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+                "00112233445566778899aabbccddeeff",
+                "f92bd7c79fb72e2f2b8f80c1972d24fc",
+            ),
+            // Twofish paper, appendix A.
+            (
+                Twofish,
+                "00000000000000000000000000000000",
+                "00000000000000000000000000000000",
+                "9f589f5cf6122c32b6bfec2f2ae8c35a",
+            ),
+            (
+                Twofish,
+                "0123456789abcdeffedcba98765432100011223344556677",
+                "00000000000000000000000000000000",
+                "cfd1d2e5a9be9cdf501f13b892bd2248",
+            ),
+            (
+                Twofish,
+                "0123456789abcdeffedcba987654321000112233445566778899aabbccddeeff",
+                "00000000000000000000000000000000",
+                "37527be0052334b89f0cfccae87cfa20",
+            ),
+            // NESSIE set 1, vector 0.
+            (
+                Serpent,
+                "80000000000000000000000000000000",
+                "00000000000000000000000000000000",
+                "264e5481eff42a4606abda06c0bfda3d",
+            ),
+            // GOST R 34.12-2015 examples.
+            (
+                Magma,
+                "ffeeddccbbaa99887766554433221100f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+                "fedcba9876543210",
+                "4ee901e5c2d8ca3d",
+            ),
+            (
+                Kuznechik,
+                "8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef",
+                "1122334455667700ffeeddccbbaa9988",
+                "7f679d90bebc24305a468d42b9d4edcd",
+            ),
+        ] {
+            assert_eq!(
+                encrypt_block(algorithm, key, plaintext),
+                ciphertext,
+                "{algorithm:?} with a {}-byte key",
+                key.len() / 2
+            );
+        }
+    }
+}
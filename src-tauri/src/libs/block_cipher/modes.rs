@@ -0,0 +1,400 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+use super::{
+    padding::{pad, unpad, Padding},
+    BlockPrimitive,
+};
+
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Mode {
+    CBC,
+    CTR,
+    OFB,
+    CFB,
+    CFB8,
+    ECB,
+    XTS,
+}
+
+impl Mode {
+    fn is_padded(&self) -> bool {
+        matches!(self, Self::ECB | Self::CBC)
+    }
+}
+
+pub fn encrypt(
+    cipher: &dyn BlockPrimitive,
+    mode: Mode,
+    padding: Padding,
+    iv: &[u8],
+    input: &[u8],
+) -> Result<Vec<u8>> {
+    let block_size = cipher.block_size();
+    check_iv(mode, iv, block_size)?;
+
+    let mut output = match mode.is_padded() {
+        true => pad(input, block_size, padding)?,
+        false => input.to_vec(),
+    };
+
+    match mode {
+        Mode::ECB => output
+            .chunks_mut(block_size)
+            .for_each(|block| cipher.encrypt_block(block)),
+        Mode::CBC => {
+            let mut previous = iv.to_vec();
+            for block in output.chunks_mut(block_size) {
+                xor_in_place(block, &previous);
+                cipher.encrypt_block(block);
+                previous.copy_from_slice(block);
+            }
+        }
+        Mode::CFB => {
+            let mut register = iv.to_vec();
+            for block in output.chunks_mut(block_size) {
+                cipher.encrypt_block(&mut register);
+                xor_in_place(block, &register);
+                register[..block.len()].copy_from_slice(block);
+            }
+        }
+        Mode::CFB8 => {
+            let mut register = iv.to_vec();
+            for byte in output.iter_mut() {
+                *byte ^= cfb8_keystream_byte(cipher, &register);
+                shift_register(&mut register, *byte);
+            }
+        }
+        Mode::OFB | Mode::CTR => keystream_xor(cipher, mode, iv, &mut output),
+        Mode::XTS => bail!("XTS mode requires separate data and tweak keys."),
+    }
+
+    Ok(output)
+}
+
+pub fn decrypt(
+    cipher: &dyn BlockPrimitive,
+    mode: Mode,
+    padding: Padding,
+    iv: &[u8],
+    input: &[u8],
+) -> Result<Vec<u8>> {
+    let block_size = cipher.block_size();
+    check_iv(mode, iv, block_size)?;
+
+    if mode.is_padded() && input.len() % block_size != 0 {
+        bail!("Input length must be a multiple of the block size ({block_size} bytes).");
+    }
+
+    let mut output = input.to_vec();
+
+    match mode {
+        Mode::ECB => output
+            .chunks_mut(block_size)
+            .for_each(|block| cipher.decrypt_block(block)),
+        Mode::CBC => {
+            let mut previous = iv.to_vec();
+            for block in output.chunks_mut(block_size) {
+                let ciphertext = block.to_vec();
+                cipher.decrypt_block(block);
+                xor_in_place(block, &previous);
+                previous = ciphertext;
+            }
+        }
+        Mode::CFB => {
+            let mut register = iv.to_vec();
+            for block in output.chunks_mut(block_size) {
+                cipher.encrypt_block(&mut register);
+                let ciphertext = block.to_vec();
+                xor_in_place(block, &register);
+                register[..ciphertext.len()].copy_from_slice(&ciphertext);
+            }
+        }
+        Mode::CFB8 => {
+            let mut register = iv.to_vec();
+            for byte in output.iter_mut() {
+                let ciphertext = *byte;
+                *byte ^= cfb8_keystream_byte(cipher, &register);
+                shift_register(&mut register, ciphertext);
+            }
+        }
+        Mode::OFB | Mode::CTR => keystream_xor(cipher, mode, iv, &mut output),
+        Mode::XTS => bail!("XTS mode requires separate data and tweak keys."),
+    }
+
+    match mode.is_padded() {
+        true => unpad(&output, block_size, padding),
+        false => Ok(output),
+    }
+}
+
+pub fn xts_encrypt(
+    cipher: &dyn BlockPrimitive,
+    tweak_cipher: &dyn BlockPrimitive,
+    tweak: &[u8],
+    input: &[u8],
+) -> Result<Vec<u8>> {
+    xts(cipher, tweak_cipher, tweak, input, true)
+}
+
+pub fn xts_decrypt(
+    cipher: &dyn BlockPrimitive,
+    tweak_cipher: &dyn BlockPrimitive,
+    tweak: &[u8],
+    input: &[u8],
+) -> Result<Vec<u8>> {
+    xts(cipher, tweak_cipher, tweak, input, false)
+}
+
+/// XEX-based tweaked-codebook mode with ciphertext stealing as described in IEEE 1619.
+fn xts(
+    cipher: &dyn BlockPrimitive,
+    tweak_cipher: &dyn BlockPrimitive,
+    tweak: &[u8],
+    input: &[u8],
+    encrypt: bool,
+) -> Result<Vec<u8>> {
+    const BLOCK_SIZE: usize = 16;
+
+    if cipher.block_size() != BLOCK_SIZE {
+        bail!(
+            "XTS mode requires a cipher with 128-bit block, got {}-bit block.",
+            cipher.block_size() * 8
+        );
+    }
+    check_iv(Mode::XTS, tweak, BLOCK_SIZE)?;
+    if input.len() < BLOCK_SIZE {
+        bail!("Input length must be at least {BLOCK_SIZE} bytes in XTS mode.");
+    }
+
+    let process = |block: &mut [u8], tweak: &[u8]| {
+        xor_in_place(block, tweak);
+        match encrypt {
+            true => cipher.encrypt_block(block),
+            false => cipher.decrypt_block(block),
+        }
+        xor_in_place(block, tweak);
+    };
+
+    let mut output = input.to_vec();
+    let mut tweak = tweak.to_vec();
+    tweak_cipher.encrypt_block(&mut tweak);
+
+    let tail = input.len() % BLOCK_SIZE;
+    let full_blocks = input.len() / BLOCK_SIZE - usize::from(tail != 0);
+
+    for block in output[..full_blocks * BLOCK_SIZE].chunks_mut(BLOCK_SIZE) {
+        process(block, &tweak);
+        multiply_by_alpha(&mut tweak);
+    }
+
+    if tail != 0 {
+        let mut next_tweak = tweak.clone();
+        multiply_by_alpha(&mut next_tweak);
+
+        let (current_tweak, stolen_tweak) = match encrypt {
+            true => (&tweak, &next_tweak),
+            false => (&next_tweak, &tweak),
+        };
+
+        let offset = full_blocks * BLOCK_SIZE;
+        let mut last = output[offset..offset + BLOCK_SIZE].to_vec();
+        process(&mut last, current_tweak);
+
+        let mut stolen = output[offset + BLOCK_SIZE..].to_vec();
+        stolen.extend_from_slice(&last[tail..]);
+        process(&mut stolen, stolen_tweak);
+
+        output[offset..offset + BLOCK_SIZE].copy_from_slice(&stolen);
+        output[offset + BLOCK_SIZE..].copy_from_slice(&last[..tail]);
+    }
+
+    Ok(output)
+}
+
+fn multiply_by_alpha(tweak: &mut [u8]) {
+    let carry = tweak[tweak.len() - 1] >> 7;
+    for idx in (1..tweak.len()).rev() {
+        tweak[idx] = (tweak[idx] << 1) | (tweak[idx - 1] >> 7);
+    }
+    tweak[0] = (tweak[0] << 1) ^ (0x87 * carry);
+}
+
+fn cfb8_keystream_byte(cipher: &dyn BlockPrimitive, register: &[u8]) -> u8 {
+    let mut keystream = register.to_vec();
+    cipher.encrypt_block(&mut keystream);
+    keystream[0]
+}
+
+fn shift_register(register: &mut [u8], byte: u8) {
+    register.rotate_left(1);
+    if let Some(last) = register.last_mut() {
+        *last = byte;
+    }
+}
+
+fn keystream_xor(cipher: &dyn BlockPrimitive, mode: Mode, iv: &[u8], data: &mut [u8]) {
+    let mut register = iv.to_vec();
+    let mut keystream = iv.to_vec();
+
+    for block in data.chunks_mut(cipher.block_size()) {
+        match mode {
+            Mode::OFB => {
+                cipher.encrypt_block(&mut register);
+                keystream.copy_from_slice(&register);
+            }
+            _ => {
+                keystream.copy_from_slice(&register);
+                cipher.encrypt_block(&mut keystream);
+                increment_counter(&mut register);
+            }
+        }
+        xor_in_place(block, &keystream);
+    }
+}
+
+fn increment_counter(counter: &mut [u8]) {
+    for byte in counter.iter_mut().rev() {
+        *byte = byte.wrapping_add(1);
+        if *byte != 0 {
+            break;
+        }
+    }
+}
+
+fn xor_in_place(block: &mut [u8], other: &[u8]) {
+    block.iter_mut().zip(other).for_each(|(x, y)| *x ^= y);
+}
+
+fn check_iv(mode: Mode, iv: &[u8], block_size: usize) -> Result<()> {
+    if mode != Mode::ECB && iv.len() != block_size {
+        bail!(
+            "IV must be {block_size} bytes long for {mode:?} mode, got {} bytes.",
+            iv.len()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aes::Aes128;
+    use cipher::KeyInit;
+
+    use super::*;
+    use crate::utils::{from_hex, to_hex};
+
+    // NIST SP 800-38A, appendix F, AES-128.
+    const KEY: &str = "2b7e151628aed2a6abf7158809cf4f3c";
+    const IV: &str = "000102030405060708090a0b0c0d0e0f";
+    const COUNTER: &str = "f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff";
+    const PLAINTEXT: &str = "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411e5fbc1191a0a52eff69f2445df4f9b17ad2b417be66c3710";
+    const CFB_CIPHERTEXT: &str = "3b3fd92eb72dad20333449f8e83cfb4ac8a64537a0b3a93fcde3cdad9f1ce58b26751f67a3cbb140b1808cf187a4f4dfc04b05357c5d1c0eeac4c66f9ff7f2e6";
+    const OFB_CIPHERTEXT: &str = "3b3fd92eb72dad20333449f8e83cfb4a7789508d16918f03f53c52dac54ed8259740051e9c5fecf64344f7a82260edcc304c6528f659c77866a510d9c1d6ae5e";
+    const CTR_CIPHERTEXT: &str = "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e5b4f09020db03eab1e031dda2fbe03d1792170a0f3009cee";
+
+    fn check(mode: Mode, iv: &str, plaintext: &str, ciphertext: &str) {
+        let cipher = Aes128::new_from_slice(&from_hex(KEY).unwrap()).unwrap();
+        let (iv, plaintext) = (from_hex(iv).unwrap(), from_hex(plaintext).unwrap());
+
+        let encrypted = encrypt(&cipher, mode, Padding::None, &iv, &plaintext).unwrap();
+        assert_eq!(to_hex(&encrypted), ciphertext, "{mode:?} encryption");
+        let decrypted = decrypt(&cipher, mode, Padding::None, &iv, &encrypted).unwrap();
+        assert_eq!(decrypted, plaintext, "{mode:?} decryption");
+    }
+
+    #[test]
+    fn ecb() {
+        check(Mode::ECB, "", PLAINTEXT, "3ad77bb40d7a3660a89ecaf32466ef97f5d3d58503b9699de785895a96fdbaaf43b1cd7f598ece23881b00e3ed0306887b0c785e27e8ad3f8223207104725dd4");
+    }
+
+    #[test]
+    fn cbc() {
+        check(Mode::CBC, IV, PLAINTEXT, "7649abac8119b246cee98e9b12e9197d5086cb9b507219ee95db113a917678b273bed6b8e3c1743b7116e69e222295163ff1caa1681fac09120eca307586e1a7");
+    }
+
+    #[test]
+    fn cfb() {
+        check(Mode::CFB, IV, PLAINTEXT, CFB_CIPHERTEXT);
+    }
+
+    #[test]
+    fn cfb8() {
+        check(
+            Mode::CFB8,
+            IV,
+            &PLAINTEXT[..36],
+            "3b79424c9c0dd436bace9e0ed4586a4f32b9",
+        );
+    }
+
+    #[test]
+    fn ofb() {
+        check(Mode::OFB, IV, PLAINTEXT, OFB_CIPHERTEXT);
+    }
+
+    #[test]
+    fn ctr() {
+        check(Mode::CTR, COUNTER, PLAINTEXT, CTR_CIPHERTEXT);
+    }
+
+    #[test]
+    fn stream_modes_keep_partial_block() {
+        for (mode, iv, ciphertext) in [
+            (Mode::CFB, IV, CFB_CIPHERTEXT),
+            (Mode::OFB, IV, OFB_CIPHERTEXT),
+            (Mode::CTR, COUNTER, CTR_CIPHERTEXT),
+        ] {
+            check(mode, iv, &PLAINTEXT[..42], &ciphertext[..42]);
+        }
+    }
+
+    fn check_xts(key: &str, tweak_key: &str, tweak: &str, plaintext: &str, ciphertext: &str) {
+        let cipher = Aes128::new_from_slice(&from_hex(key).unwrap()).unwrap();
+        let tweak_cipher = Aes128::new_from_slice(&from_hex(tweak_key).unwrap()).unwrap();
+        let (tweak, plaintext) = (from_hex(tweak).unwrap(), from_hex(plaintext).unwrap());
+
+        let encrypted = xts_encrypt(&cipher, &tweak_cipher, &tweak, &plaintext).unwrap();
+        assert_eq!(to_hex(&encrypted), ciphertext);
+        let decrypted = xts_decrypt(&cipher, &tweak_cipher, &tweak, &encrypted).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    // IEEE 1619-2007, annex B, the tweak is the data unit number in little endian.
+    #[test]
+    fn xts() {
+        check_xts(
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "00000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "917cf69ebd68b2ec9b9fe9a3eadda692cd43d2f59598ed858c02c2652fbf922e",
+        );
+        check_xts(
+            "11111111111111111111111111111111",
+            "22222222222222222222222222222222",
+            "33333333330000000000000000000000",
+            "4444444444444444444444444444444444444444444444444444444444444444",
+            "c454185e6a16936e39334038acef838bfb186fff7480adc4289382ecd6d394f0",
+        );
+        check_xts(
+            "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0",
+            "22222222222222222222222222222222",
+            "33333333330000000000000000000000",
+            "4444444444444444444444444444444444444444444444444444444444444444",
+            "af85336b597afc1a900b2eb21ec949d292df4c047e0b21532186a5971a227a89",
+        );
+    }
+
+    #[test]
+    fn xts_ciphertext_stealing() {
+        check_xts(
+            "fffefdfcfbfaf9f8f7f6f5f4f3f2f1f0",
+            "bfbebdbcbbbab9b8b7b6b5b4b3b2b1b0",
+            "9a785634120000000000000000000000",
+            "000102030405060708090a0b0c0d0e0f10",
+            "6c1625db4671522d3d7599601de7ca09ed",
+        );
+    }
+}
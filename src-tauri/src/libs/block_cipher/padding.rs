@@ -0,0 +1,191 @@
+use anyhow::{bail, Context, Result};
+use rand::RngCore;
+use serde::Deserialize;
+
+#[derive(Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Padding {
+    #[serde(alias = "pkcs5")]
+    PKCS7,
+    ISO7816,
+    X923,
+    /// Random bytes followed by the padding length, also accepted as "random".
+    #[serde(alias = "random")]
+    ISO10126,
+    Zero,
+    #[serde(alias = "no")]
+    None,
+}
+
+pub fn pad(input: &[u8], block_size: usize, padding: Padding) -> Result<Vec<u8>> {
+    let mut output = input.to_vec();
+    let remainder = input.len() % block_size;
+    let padding_len = block_size - remainder;
+
+    match padding {
+        Padding::PKCS7 => output.extend(vec![padding_len as u8; padding_len]),
+        Padding::ISO7816 => {
+            output.push(0x80);
+            output.extend(vec![0; padding_len - 1]);
+        }
+        Padding::X923 => {
+            output.extend(vec![0; padding_len - 1]);
+            output.push(padding_len as u8);
+        }
+        Padding::ISO10126 => {
+            let mut filler = vec![0; padding_len - 1];
+            rand::thread_rng().fill_bytes(&mut filler);
+            output.extend(filler);
+            output.push(padding_len as u8);
+        }
+        Padding::Zero if remainder != 0 => output.extend(vec![0; padding_len]),
+        Padding::Zero => {}
+        Padding::None if remainder != 0 => bail!(
+            "Input length must be a multiple of the block size ({block_size} bytes) when padding is disabled."
+        ),
+        Padding::None => {}
+    }
+
+    Ok(output)
+}
+
+pub fn unpad(input: &[u8], block_size: usize, padding: Padding) -> Result<Vec<u8>> {
+    let len = match padding {
+        Padding::PKCS7 => {
+            let padding_len = *input.last().context("Input is empty.")? as usize;
+            check_padding_len(input, padding_len, block_size)?;
+            if input[input.len() - padding_len..]
+                .iter()
+                .any(|&x| x as usize != padding_len)
+            {
+                bail!("Invalid padding.");
+            }
+            input.len() - padding_len
+        }
+        Padding::ISO7816 => {
+            let marker = input
+                .iter()
+                .rposition(|&x| x != 0)
+                .context("Invalid padding.")?;
+            check_padding_len(input, input.len() - marker, block_size)?;
+            if input[marker] != 0x80 {
+                bail!("Invalid padding.");
+            }
+            marker
+        }
+        Padding::X923 => {
+            let padding_len = *input.last().context("Input is empty.")? as usize;
+            check_padding_len(input, padding_len, block_size)?;
+            if input[input.len() - padding_len..input.len() - 1]
+                .iter()
+                .any(|&x| x != 0)
+            {
+                bail!("Invalid padding.");
+            }
+            input.len() - padding_len
+        }
+        Padding::ISO10126 => {
+            let padding_len = *input.last().context("Input is empty.")? as usize;
+            check_padding_len(input, padding_len, block_size)?;
+            input.len() - padding_len
+        }
+        Padding::Zero => input.iter().rposition(|&x| x != 0).map_or(0, |idx| idx + 1),
+        Padding::None => input.len(),
+    };
+
+    Ok(input[..len].to_vec())
+}
+
+fn check_padding_len(input: &[u8], padding_len: usize, block_size: usize) -> Result<()> {
+    if padding_len == 0 || padding_len > block_size || padding_len > input.len() {
+        bail!("Invalid padding.");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PADDINGS: [Padding; 5] = [
+        Padding::PKCS7,
+        Padding::ISO7816,
+        Padding::X923,
+        Padding::ISO10126,
+        Padding::Zero,
+    ];
+
+    #[test]
+    fn padded_blocks() {
+        let input = b"YELLOW SUBMARINE";
+        for (padding, expected) in [
+            (Padding::PKCS7, "0505050505"),
+            (Padding::ISO7816, "8000000000"),
+            (Padding::X923, "0000000005"),
+            (Padding::Zero, "0000000000"),
+        ] {
+            let padded = pad(&input[..11], 16, padding).unwrap();
+            assert_eq!(crate::utils::to_hex(&padded[11..]), expected, "{padding:?}");
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for padding in PADDINGS {
+            for block_size in [8, 16] {
+                for len in 0..=2 * block_size {
+                    let input: Vec<u8> = (1..=len as u8).collect();
+                    let padded = pad(&input, block_size, padding).unwrap();
+                    assert_eq!(padded.len() % block_size, 0, "{padding:?}");
+                    assert_eq!(unpad(&padded, block_size, padding).unwrap(), input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn random_padding() {
+        let padded = pad(&[1; 11], 16, Padding::ISO10126).unwrap();
+        assert_eq!(padded.len(), 16);
+        assert_eq!(padded[15], 5);
+        assert_eq!(unpad(&padded, 16, Padding::ISO10126).unwrap(), [1; 11]);
+
+        let padding: Padding = serde_json::from_str("\"random\"").unwrap();
+        assert!(matches!(padding, Padding::ISO10126));
+    }
+
+    #[test]
+    fn full_block_of_padding() {
+        for padding in [
+            Padding::PKCS7,
+            Padding::ISO7816,
+            Padding::X923,
+            Padding::ISO10126,
+        ] {
+            assert_eq!(pad(&[1; 16], 16, padding).unwrap().len(), 32, "{padding:?}");
+        }
+        assert_eq!(pad(&[1; 16], 16, Padding::Zero).unwrap().len(), 16);
+    }
+
+    #[test]
+    fn no_padding() {
+        assert_eq!(pad(&[1; 16], 16, Padding::None).unwrap(), [1; 16]);
+        assert!(pad(&[1; 15], 16, Padding::None).is_err());
+        assert_eq!(unpad(&[1; 16], 16, Padding::None).unwrap(), [1; 16]);
+    }
+
+    #[test]
+    fn invalid_padding() {
+        let mut block = [7u8; 16];
+        block[15] = 0;
+        assert!(unpad(&block, 16, Padding::PKCS7).is_err());
+        block[15] = 17;
+        assert!(unpad(&block, 16, Padding::PKCS7).is_err());
+        block[15] = 3;
+        assert!(unpad(&block, 16, Padding::PKCS7).is_err());
+        assert!(unpad(&block, 16, Padding::X923).is_err());
+        assert!(unpad(&[0; 16], 16, Padding::ISO10126).is_err());
+        assert!(unpad(&[0; 16], 16, Padding::ISO7816).is_err());
+        assert!(unpad(&[], 16, Padding::PKCS7).is_err());
+    }
+}
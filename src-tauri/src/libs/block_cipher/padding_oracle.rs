@@ -0,0 +1,135 @@
+use anyhow::{bail, Result};
+
+/// Recovers CBC plaintext using only an oracle that tells whether a ciphertext has valid PKCS#7 padding.
+///
+/// The oracle receives a forged previous block (or IV) and a single ciphertext block.
+/// Returned plaintext still contains its padding.
+pub fn padding_oracle_attack<F>(
+    block_size: usize,
+    iv: &[u8],
+    ciphertext: &[u8],
+    oracle: F,
+) -> Result<Vec<u8>>
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    if iv.len() != block_size {
+        bail!(
+            "IV must be {block_size} bytes long, got {} bytes.",
+            iv.len()
+        );
+    }
+    if ciphertext.is_empty() || ciphertext.len() % block_size != 0 {
+        bail!(
+            "Ciphertext length must be a non-zero multiple of the block size ({block_size} bytes)."
+        );
+    }
+
+    let mut plaintext = Vec::with_capacity(ciphertext.len());
+    let mut previous = iv;
+
+    for block in ciphertext.chunks(block_size) {
+        let intermediate = recover_intermediate(block, &oracle)?;
+        plaintext.extend(intermediate.iter().zip(previous).map(|(x, y)| x ^ y));
+        previous = block;
+    }
+
+    Ok(plaintext)
+}
+
+/// Finds the block cipher decryption of `block` one byte at a time, from the last byte to the first.
+fn recover_intermediate<F>(block: &[u8], oracle: &F) -> Result<Vec<u8>>
+where
+    F: Fn(&[u8], &[u8]) -> bool,
+{
+    let block_size = block.len();
+    let mut intermediate = vec![0u8; block_size];
+
+    for padding_len in 1..=block_size {
+        let position = block_size - padding_len;
+        let mut forged = vec![0u8; block_size];
+        forged[position + 1..]
+            .iter_mut()
+            .zip(&intermediate[position + 1..])
+            .for_each(|(x, y)| *x = y ^ padding_len as u8);
+
+        let guess = (0..=u8::MAX).find(|&guess| {
+            forged[position] = guess;
+            if !oracle(&forged, block) {
+                return false;
+            }
+            // A valid padding for the last byte may also be "02 02" or longer, so the byte
+            // before it is flipped to make sure the padding really is a single "01".
+            if padding_len == 1 && position > 0 {
+                let mut check = forged.clone();
+                check[position - 1] ^= 1;
+                return oracle(&check, block);
+            }
+            true
+        });
+
+        match guess {
+            Some(guess) => intermediate[position] = guess ^ padding_len as u8,
+            None => bail!("Padding oracle rejected every guess, ciphertext is probably not CBC with PKCS#7 padding."),
+        }
+    }
+
+    Ok(intermediate)
+}
+
+#[cfg(test)]
+mod tests {
+    use aes::Aes128;
+    use cipher::KeyInit;
+    use des::Des;
+
+    use super::*;
+    use crate::libs::block_cipher::{
+        modes::{decrypt, encrypt, Mode},
+        padding::Padding,
+        BlockPrimitive,
+    };
+
+    fn recover(cipher: &dyn BlockPrimitive, plaintext: &[u8]) {
+        let block_size = cipher.block_size();
+        let iv: Vec<u8> = (0..block_size as u8).collect();
+        let ciphertext = encrypt(cipher, Mode::CBC, Padding::PKCS7, &iv, plaintext).unwrap();
+
+        let oracle = |previous: &[u8], block: &[u8]| {
+            decrypt(cipher, Mode::CBC, Padding::PKCS7, previous, block).is_ok()
+        };
+        let recovered = padding_oracle_attack(block_size, &iv, &ciphertext, oracle).unwrap();
+
+        let padding_len = block_size - plaintext.len() % block_size;
+        assert_eq!(&recovered[..plaintext.len()], plaintext);
+        assert_eq!(
+            recovered[plaintext.len()..],
+            vec![padding_len as u8; padding_len]
+        );
+    }
+
+    #[test]
+    fn recovers_plaintext() {
+        let aes = Aes128::new_from_slice(b"YELLOW SUBMARINE").unwrap();
+        recover(&aes, b"Padding oracles leak the whole plaintext.");
+        recover(&aes, b"exactly 16 bytes");
+        recover(&aes, &[2; 30]);
+
+        let des = Des::new_from_slice(b"8bytekey").unwrap();
+        recover(&des, b"Attack at dawn");
+    }
+
+    #[test]
+    fn rejects_bad_lengths() {
+        let oracle = |_: &[u8], _: &[u8]| true;
+        assert!(padding_oracle_attack(16, &[0; 8], &[0; 16], oracle).is_err());
+        assert!(padding_oracle_attack(16, &[0; 16], &[0; 20], oracle).is_err());
+        assert!(padding_oracle_attack(16, &[0; 16], &[], oracle).is_err());
+    }
+
+    #[test]
+    fn fails_without_valid_padding() {
+        let oracle = |_: &[u8], _: &[u8]| false;
+        assert!(padding_oracle_attack(16, &[0; 16], &[0; 16], oracle).is_err());
+    }
+}
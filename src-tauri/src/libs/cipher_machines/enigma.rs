@@ -0,0 +1,265 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+use super::{letters, pairs, wiring, Rotor, RotorMachine};
+
+#[derive(Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EnigmaModel {
+    M3,
+    M4,
+}
+
+#[derive(Deserialize, Copy, Clone)]
+#[serde(rename_all = "lowercase")]
+#[allow(clippy::upper_case_acronyms)]
+pub enum EnigmaRotor {
+    I,
+    II,
+    III,
+    IV,
+    V,
+    VI,
+    VII,
+    VIII,
+}
+
+impl EnigmaRotor {
+    /// Wiring and the letters shown in the window when the rotor turns the next one over.
+    fn wiring(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::I => ("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q"),
+            Self::II => ("AJDKSIRUXBLHWTMCQGZNPYFVOE", "E"),
+            Self::III => ("BDFHJLCPRTXVZNYEIWGAKMUSQO", "V"),
+            Self::IV => ("ESOVPZJAYQUIRHXLNFTGKDCMWB", "J"),
+            Self::V => ("VZBRGITYUPSDNHLXAWMJQOFECK", "Z"),
+            Self::VI => ("JPGVOUMFYQBENHZRDKASXLICTW", "ZM"),
+            Self::VII => ("NZJHGRCXMYSWBOUFAIVLPEKQDT", "ZM"),
+            Self::VIII => ("FKQHTLXOCBJSPDZRAMEWNIUYGV", "ZM"),
+        }
+    }
+}
+
+/// The fourth rotor of the M4, it stays in place.
+#[derive(Deserialize, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum EnigmaFourthRotor {
+    Beta,
+    Gamma,
+}
+
+impl EnigmaFourthRotor {
+    fn wiring(&self) -> &'static str {
+        match self {
+            Self::Beta => "LEYJVCNIXWPBQMDRTAKZGFUHOS",
+            Self::Gamma => "FSOKANUERHMBTIJYCWLQPZXVGD",
+        }
+    }
+}
+
+#[derive(Deserialize, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum EnigmaReflector {
+    B,
+    C,
+    BThin,
+    CThin,
+}
+
+impl EnigmaReflector {
+    fn wiring(&self) -> &'static str {
+        match self {
+            Self::B => "YRUHQSLDPXNGOKMIEBFZCWVJAT",
+            Self::C => "FVPJIAOYEDRZXWGCTKUQSBNMHL",
+            Self::BThin => "ENKQAUYWJICOPBLMDXZVFTHRGS",
+            Self::CThin => "RDOBJNTKVEHMLFCWZAXGYIPSUQ",
+        }
+    }
+
+    fn is_thin(&self) -> bool {
+        matches!(self, Self::BThin | Self::CThin)
+    }
+}
+
+pub struct EnigmaSettings<'a> {
+    pub model: EnigmaModel,
+    pub fourth_rotor: Option<EnigmaFourthRotor>,
+    pub rotors: [EnigmaRotor; 3],
+    pub reflector: EnigmaReflector,
+    pub ring_settings: &'a str,
+    pub positions: &'a str,
+    pub plugboard: &'a str,
+}
+
+/// Sets up the machine, rotors, ring settings and positions go from left to right, the ring
+/// settings and positions of the M4 start with its fourth rotor.
+pub fn enigma(settings: EnigmaSettings) -> Result<RotorMachine> {
+    let EnigmaSettings {
+        model,
+        fourth_rotor,
+        rotors,
+        reflector,
+        ring_settings,
+        positions,
+        plugboard,
+    } = settings;
+
+    let stator = match (model, fourth_rotor, reflector.is_thin()) {
+        (EnigmaModel::M3, None, false) => None,
+        (EnigmaModel::M4, Some(rotor), true) => Some(rotor),
+        (EnigmaModel::M3, _, _) => bail!("M3 has only three rotors and a thick reflector."),
+        (EnigmaModel::M4, _, _) => bail!("M4 needs the fourth rotor and a thin reflector."),
+    };
+
+    let ring_settings = letters(ring_settings, "Ring settings")?;
+    let positions = letters(positions, "Positions")?;
+    let count = 3 + stator.is_some() as usize;
+    if ring_settings.len() != count || positions.len() != count {
+        bail!("Ring settings and positions must have a letter for each of the {count} rotors.");
+    }
+
+    let mut machine_rotors = Vec::with_capacity(count);
+    if let Some(stator) = stator {
+        machine_rotors.push(Rotor::new(
+            &wiring(stator.wiring())?,
+            &[],
+            ring_settings[0],
+            positions[0],
+        ));
+    }
+    for (idx, rotor) in rotors.iter().enumerate() {
+        let (rotor_wiring, notches) = rotor.wiring();
+        let idx = idx + count - 3;
+        machine_rotors.push(Rotor::new(
+            &wiring(rotor_wiring)?,
+            &letters(notches, "Notches")?,
+            ring_settings[idx],
+            positions[idx],
+        ));
+    }
+
+    Ok(RotorMachine {
+        rotors: machine_rotors,
+        reflector: wiring(reflector.wiring())?,
+        plugboard: pairs(plugboard, "Plugboard", false)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn m3(
+        rotors: [EnigmaRotor; 3],
+        ring_settings: &str,
+        positions: &str,
+        plugboard: &str,
+    ) -> RotorMachine {
+        enigma(EnigmaSettings {
+            model: EnigmaModel::M3,
+            fourth_rotor: None,
+            rotors,
+            reflector: EnigmaReflector::B,
+            ring_settings,
+            positions,
+            plugboard,
+        })
+        .unwrap()
+    }
+
+    fn positions(machine: &RotorMachine) -> String {
+        machine
+            .rotors
+            .iter()
+            .map(|rotor| (b'A' + rotor.position) as char)
+            .collect()
+    }
+
+    #[test]
+    fn m3_default_settings() {
+        use EnigmaRotor::*;
+        let mut machine = m3([I, II, III], "AAA", "AAA", "");
+        assert_eq!(machine.encipher("AAAAA"), "BDZGO");
+    }
+
+    #[test]
+    fn double_stepping() {
+        use EnigmaRotor::*;
+        let mut machine = m3([I, II, III], "AAA", "ADU", "");
+        let steps: Vec<String> = (0..3)
+            .map(|_| {
+                machine.encipher("A");
+                positions(&machine)
+            })
+            .collect();
+        assert_eq!(steps, ["ADV", "AEW", "BFX"]);
+    }
+
+    #[test]
+    fn m3_operation_barbarossa() {
+        use EnigmaRotor::*;
+        let mut machine = m3([II, IV, V], "BUL", "BLA", "AV BS CG DL FU HZ IN KM OW RX");
+        assert_eq!(
+            machine.encipher("EDPUD NRGYS ZRCXN UYTPO MRMBO FKTBZ REZKM LXLVE FGUEY SIOZV"),
+            "AUFKL XABTE ILUNG XVONX KURTI NOWAX KURTI NOWAX NORDW ESTLX"
+        );
+    }
+
+    #[test]
+    fn m4_donitz_message() {
+        use EnigmaRotor::*;
+        let mut machine = enigma(EnigmaSettings {
+            model: EnigmaModel::M4,
+            fourth_rotor: Some(EnigmaFourthRotor::Beta),
+            rotors: [V, VI, VIII],
+            reflector: EnigmaReflector::CThin,
+            ring_settings: "EPEL",
+            positions: "CDSZ",
+            plugboard: "AE BF CM DQ HU JN LX PR SZ VW",
+        })
+        .unwrap();
+        assert_eq!(
+            machine.encipher("LANO TCTO UARB BFPM HPHG CZXT DYGA HGUF XGEW KBLK GJWL QXXT"),
+            "KRKR ALLE XXFO LGEN DESI STSO FORT BEKA NNTZ UGEB ENXX ICHH"
+        );
+    }
+
+    #[test]
+    fn m4_with_beta_at_a_matches_m3() {
+        use EnigmaRotor::*;
+        let input = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG";
+        let mut machine = enigma(EnigmaSettings {
+            model: EnigmaModel::M4,
+            fourth_rotor: Some(EnigmaFourthRotor::Beta),
+            rotors: [I, II, III],
+            reflector: EnigmaReflector::BThin,
+            ring_settings: "AAAA",
+            positions: "AAAA",
+            plugboard: "",
+        })
+        .unwrap();
+        assert_eq!(
+            machine.encipher(input),
+            m3([I, II, III], "AAA", "AAA", "").encipher(input)
+        );
+    }
+
+    #[test]
+    fn reciprocal() {
+        use EnigmaRotor::*;
+        let input = "ATTACK AT DAWN, 42 MEN!".repeat(40);
+        let settings = (
+            [VI, VII, VIII],
+            "XYZ",
+            "QEV",
+            "AB CD EF GH IJ KL MN OP QR ST",
+        );
+        let (rotors, ring_settings, positions, plugboard) = settings;
+        let encoded = m3(rotors, ring_settings, positions, plugboard).encipher(&input);
+        assert_ne!(encoded, input);
+        assert_eq!(
+            m3(rotors, ring_settings, positions, plugboard).encipher(&encoded),
+            input
+        );
+    }
+}
@@ -0,0 +1,472 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+pub const CHI_SIZES: [usize; 5] = [41, 31, 29, 26, 23];
+pub const PSI_SIZES: [usize; 5] = [43, 47, 51, 53, 59];
+pub const MU_SIZES: [usize; 2] = [61, 37];
+
+#[derive(Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LorenzModel {
+    Sz40,
+    Sz42a,
+    Sz42b,
+}
+
+/// The SZ42b limitation depends on the plaintext, so the machine has to know which of the
+/// texts it is given.
+#[derive(Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LorenzMode {
+    Send,
+    Receive,
+}
+
+#[derive(Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TeleprinterFormat {
+    Plaintext,
+    Ita2,
+}
+
+struct Wheel {
+    cams: Vec<u8>,
+    position: usize,
+}
+
+impl Wheel {
+    fn new(name: &str, pattern: &str, size: usize, start: usize) -> Result<Self> {
+        let cams: Vec<u8> = pattern
+            .chars()
+            .map(|ch| match ch {
+                'x' | 'X' | '1' => Ok(1),
+                '.' | '0' => Ok(0),
+                _ => bail!("{name} wheel pattern must consist of `x` and `.` characters."),
+            })
+            .collect::<Result<_>>()?;
+
+        if cams.len() != size {
+            bail!("{name} wheel must have {size} cams.");
+        }
+        if !(1..=size).contains(&start) {
+            bail!("{name} wheel start position must be from 1 to {size}.");
+        }
+
+        Ok(Self {
+            cams,
+            position: start - 1,
+        })
+    }
+
+    fn bit(&self) -> u8 {
+        self.cams[self.position]
+    }
+
+    fn step(&mut self) {
+        self.position = (self.position + 1) % self.cams.len();
+    }
+}
+
+pub struct LorenzSettings<'a> {
+    pub model: LorenzModel,
+    pub chi: &'a [String; 5],
+    pub psi: &'a [String; 5],
+    pub mu: &'a [String; 2],
+    pub chi_start: [usize; 5],
+    pub psi_start: [usize; 5],
+    pub mu_start: [usize; 2],
+}
+
+pub struct LorenzMachine {
+    model: LorenzModel,
+    chi: Vec<Wheel>,
+    psi: Vec<Wheel>,
+    mu: Vec<Wheel>,
+}
+
+impl LorenzMachine {
+    pub fn new(settings: LorenzSettings) -> Result<Self> {
+        let wheels = |name: &str, patterns: &[String], sizes: &[usize], starts: &[usize]| {
+            patterns
+                .iter()
+                .zip(sizes.iter().zip(starts))
+                .enumerate()
+                .map(|(idx, (pattern, (&size, &start)))| {
+                    Wheel::new(&format!("{name} {}", idx + 1), pattern, size, start)
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+
+        Ok(Self {
+            model: settings.model,
+            chi: wheels("Chi", settings.chi, &CHI_SIZES, &settings.chi_start)?,
+            psi: wheels("Psi", settings.psi, &PSI_SIZES, &settings.psi_start)?,
+            mu: wheels("Mu", settings.mu, &MU_SIZES, &settings.mu_start)?,
+        })
+    }
+
+    /// Adds the chi and psi streams to the teleprinter codes, impulse 1 is the lowest bit. After
+    /// every character the chi wheels and the mu 61 wheel move, the mu 37 wheel moves if mu 61
+    /// showed a cross and the psi wheels move if mu 37 showed a cross. On the SZ42 a dot of the
+    /// limitation makes the psi wheels move anyway, the limitation is chi 2 one back, on the
+    /// SZ42b added to impulse 5 of the plaintext two back.
+    pub fn encipher(&mut self, codes: &[u8], mode: LorenzMode) -> Vec<u8> {
+        let mut chi2_back = 0;
+        let mut p5_back = [0u8; 2];
+
+        codes
+            .iter()
+            .map(|&code| {
+                let key = (0..5).fold(0, |key, idx| {
+                    key | (self.chi[idx].bit() ^ self.psi[idx].bit()) << idx
+                });
+                let output = code ^ key;
+
+                let limitation = match self.model {
+                    LorenzModel::Sz40 => 1,
+                    LorenzModel::Sz42a => chi2_back,
+                    LorenzModel::Sz42b => chi2_back ^ p5_back[1],
+                };
+                let total_motor = self.mu[1].bit() | (limitation ^ 1);
+
+                let plaintext = match mode {
+                    LorenzMode::Send => code,
+                    LorenzMode::Receive => output,
+                };
+                p5_back = [plaintext >> 4 & 1, p5_back[0]];
+                chi2_back = self.chi[1].bit();
+
+                self.chi.iter_mut().for_each(Wheel::step);
+                if total_motor == 1 {
+                    self.psi.iter_mut().for_each(Wheel::step);
+                }
+                if self.mu[0].bit() == 1 {
+                    self.mu[1].step();
+                }
+                self.mu[0].step();
+
+                output
+            })
+            .collect()
+    }
+}
+
+/// ITA2 codes of the letters from A to Z, impulse 1 is the lowest bit.
+const ITA2_LETTERS: [u8; 26] = [
+    0b00011, 0b11001, 0b01110, 0b01001, 0b00001, 0b01101, 0b11010, 0b10100, 0b00110, 0b01011,
+    0b01111, 0b10010, 0b11100, 0b01100, 0b11000, 0b10110, 0b10111, 0b01010, 0b00101, 0b10000,
+    0b00111, 0b11110, 0b10011, 0b11101, 0b10101, 0b10001,
+];
+/// Figures shifted keys of the letters, the ones without an agreed figure are skipped.
+const ITA2_FIGURES: [Option<char>; 26] = [
+    Some('-'),
+    Some('?'),
+    Some(':'),
+    None,
+    Some('3'),
+    None,
+    None,
+    None,
+    Some('8'),
+    None,
+    Some('('),
+    Some(')'),
+    Some('.'),
+    Some(','),
+    Some('9'),
+    Some('0'),
+    Some('1'),
+    Some('4'),
+    Some('\''),
+    Some('5'),
+    Some('7'),
+    Some('='),
+    Some('2'),
+    Some('/'),
+    Some('6'),
+    Some('+'),
+];
+
+const NULL: u8 = 0b00000;
+const SPACE: u8 = 0b00100;
+const CARRIAGE_RETURN: u8 = 0b01000;
+const LINE_FEED: u8 = 0b00010;
+const FIGURES: u8 = 0b11011;
+const LETTERS: u8 = 0b11111;
+
+fn letter_code(letter: char) -> u8 {
+    ITA2_LETTERS[(letter as u8 - b'A') as usize]
+}
+
+fn code_letter(code: u8) -> Option<char> {
+    ITA2_LETTERS
+        .iter()
+        .position(|&x| x == code)
+        .map(|idx| (b'A' + idx as u8) as char)
+}
+
+/// Teleprinter codes of the text, shifts to figures and back to letters are inserted when needed.
+pub fn text_to_ita2(text: &str) -> Result<Vec<u8>> {
+    let mut codes = Vec::new();
+    let mut figures = false;
+
+    for ch in text.chars() {
+        let ch = ch.to_ascii_uppercase();
+        let (code, needs_figures) = match ch {
+            'A'..='Z' => (letter_code(ch), Some(false)),
+            ' ' => (SPACE, None),
+            '\r' => (CARRIAGE_RETURN, None),
+            '\n' => (LINE_FEED, None),
+            _ => match ITA2_FIGURES.iter().position(|&x| x == Some(ch)) {
+                Some(idx) => (ITA2_LETTERS[idx], Some(true)),
+                None => bail!("Character `{ch}` can't be written in ITA2."),
+            },
+        };
+
+        if let Some(needs_figures) = needs_figures {
+            if needs_figures != figures {
+                codes.push(if needs_figures { FIGURES } else { LETTERS });
+                figures = needs_figures;
+            }
+        }
+        codes.push(code);
+    }
+
+    Ok(codes)
+}
+
+/// Text printed by the teleprinter, figures without an agreed character are skipped.
+pub fn ita2_to_text(codes: &[u8]) -> String {
+    let mut text = String::new();
+    let mut figures = false;
+
+    for &code in codes {
+        match code {
+            NULL => {}
+            SPACE => text.push(' '),
+            CARRIAGE_RETURN => text.push('\r'),
+            LINE_FEED => text.push('\n'),
+            FIGURES => figures = true,
+            LETTERS => figures = false,
+            _ => {
+                let idx = ITA2_LETTERS.iter().position(|&x| x == code).unwrap();
+                match figures {
+                    true => text.extend(ITA2_FIGURES[idx]),
+                    false => text.push((b'A' + idx as u8) as char),
+                }
+            }
+        }
+    }
+
+    text
+}
+
+/// Codes written in the Bletchley Park notation: letters for the letter codes, `/` for null, `9`
+/// for space, `3` for line feed, `4` for carriage return, `5` or `+` for figures shift and `8`
+/// or `-` for letters shift.
+pub fn bletchley_to_ita2(text: &str) -> Result<Vec<u8>> {
+    text.chars()
+        .filter(|ch| !ch.is_whitespace())
+        .map(|ch| {
+            Ok(match ch.to_ascii_uppercase() {
+                upper @ 'A'..='Z' => letter_code(upper),
+                '/' => NULL,
+                '9' => SPACE,
+                '3' => LINE_FEED,
+                '4' => CARRIAGE_RETURN,
+                '5' | '+' => FIGURES,
+                '8' | '-' => LETTERS,
+                _ => bail!(
+                    "Character `{ch}` is not a teleprinter code in the Bletchley Park notation."
+                ),
+            })
+        })
+        .collect()
+}
+
+pub fn ita2_to_bletchley(codes: &[u8]) -> String {
+    codes
+        .iter()
+        .map(|&code| match code {
+            NULL => '/',
+            SPACE => '9',
+            LINE_FEED => '3',
+            CARRIAGE_RETURN => '4',
+            FIGURES => '5',
+            LETTERS => '8',
+            _ => code_letter(code).unwrap(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wheel pattern with crosses at the given cams, counted from 1.
+    fn pattern(size: usize, crosses: &[usize]) -> String {
+        (1..=size)
+            .map(|cam| match crosses.contains(&cam) {
+                true => 'x',
+                false => '.',
+            })
+            .collect()
+    }
+
+    /// Machine with dots on all the cams except the given crosses of chi 1, psi 1 and the motor
+    /// wheels.
+    fn machine(
+        model: LorenzModel,
+        chi1: &[usize],
+        psi1: &[usize],
+        mu: [&[usize]; 2],
+    ) -> LorenzMachine {
+        let wheels = |sizes: &[usize], first: &[usize]| -> Vec<String> {
+            sizes
+                .iter()
+                .enumerate()
+                .map(|(idx, &size)| pattern(size, if idx == 0 { first } else { &[] }))
+                .collect()
+        };
+        let chi = wheels(&CHI_SIZES, chi1);
+        let psi = wheels(&PSI_SIZES, psi1);
+        let mu = [pattern(MU_SIZES[0], mu[0]), pattern(MU_SIZES[1], mu[1])];
+
+        LorenzMachine::new(LorenzSettings {
+            model,
+            chi: &chi.try_into().unwrap(),
+            psi: &psi.try_into().unwrap(),
+            mu: &mu,
+            chi_start: [1; 5],
+            psi_start: [1; 5],
+            mu_start: [1; 2],
+        })
+        .unwrap()
+    }
+
+    fn encipher(machine: &mut LorenzMachine, input: &str) -> String {
+        let codes = bletchley_to_ita2(input).unwrap();
+        ita2_to_bletchley(&machine.encipher(&codes, LorenzMode::Send))
+    }
+
+    #[test]
+    fn chi_stream() {
+        // A is 11000 in impulses 1 to 5, the cross of chi 1 turns it into 01000 which is 3.
+        let mut machine = machine(LorenzModel::Sz40, &[1, 3], &[], [&[], &[]]);
+        assert_eq!(encipher(&mut machine, "AAAA"), "3A3A");
+    }
+
+    #[test]
+    fn psi_motion() {
+        // The psi wheels stay in place while mu 37 shows a dot.
+        let mut stopped = machine(LorenzModel::Sz40, &[], &[1, 2], [&[], &[]]);
+        assert_eq!(encipher(&mut stopped, "AAAA"), "3333");
+
+        let mut moving = machine(LorenzModel::Sz40, &[], &[1, 2], [&[], &[1]]);
+        assert_eq!(encipher(&mut moving, "AAAA"), "33AA");
+
+        // Mu 37 moves to its cross only after a cross of mu 61.
+        let mut stopped = machine(LorenzModel::Sz40, &[], &[1, 2], [&[], &[2]]);
+        assert_eq!(encipher(&mut stopped, "AAAA"), "3333");
+
+        let mut motor = machine(LorenzModel::Sz40, &[], &[1, 2], [&[1], &[2]]);
+        assert_eq!(encipher(&mut motor, "AAAA"), "333A");
+    }
+
+    #[test]
+    fn limitation() {
+        // The limitation of the SZ42a is chi 2 one back, its dot moves the psi wheels.
+        let mut machine = machine(LorenzModel::Sz42a, &[], &[1, 2], [&[], &[]]);
+        assert_eq!(encipher(&mut machine, "AAAA"), "33AA");
+    }
+
+    #[test]
+    fn teleprinter_codes() {
+        let text = "GENERAL ROMMEL (1891-1944) ASKS: WHERE? 'NORTH OF 37.5' = +/2\r\n";
+        let codes = text_to_ita2(text).unwrap();
+        assert_eq!(ita2_to_text(&codes), text);
+        assert_eq!(
+            bletchley_to_ita2(&ita2_to_bletchley(&codes)).unwrap(),
+            codes
+        );
+        assert_eq!(ita2_to_bletchley(&text_to_ita2("HI 5").unwrap()), "HI95T");
+    }
+
+    /// Machine with fixed wheel patterns and start positions, shared by the tests below.
+    fn set_machine(model: LorenzModel) -> LorenzMachine {
+        let chi = [
+            ".x.xxxx...xxx.xx...xxxx.x..x.x.......x.x.",
+            ".x...x...x.....xx...x...xxx....",
+            "x......x.x..x.....x.....x...x",
+            "..x.x..x..x..x....xxx.xxxx",
+            "...xxx....x.x..x.xxxxxx",
+        ]
+        .map(String::from);
+        let psi = [
+            ".x..x.xx..xxxx...xxx.....x......xxx..xx.x.x",
+            "x.x.x..x..x.x..xx......xxxx.....x...x.x.xx.x.xx",
+            "xxxx..x....xx....x.xxxx..xx..xxxxx..x.xxxx..xx.xx.x",
+            "xxxxx.x.........x.x..x.xxxxxxxxxxx..xx.xxxx.x..xxx..x",
+            "...x..xxx..x...x.x.xx..xx.xx.xx...xx.xxx.x.x..x.x...xx...xx",
+        ]
+        .map(String::from);
+        let mu = [
+            "..x.xxxx.xxxxxx....x...xx.xx.xxxxx...xx.x.xx.xxx...xxx...x.xx",
+            "xx.xxx..x.xxx.x.xx.xxxxx.xx.x.xxxx...",
+        ]
+        .map(String::from);
+
+        LorenzMachine::new(LorenzSettings {
+            model,
+            chi: &chi,
+            psi: &psi,
+            mu: &mu,
+            chi_start: [1, 2, 3, 4, 5],
+            psi_start: [6, 7, 8, 9, 10],
+            mu_start: [11, 12],
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn known_ciphertext() {
+        // The first three characters come before the first psi and limitation differences, so
+        // all the models agree on them.
+        let plaintext = "ATTACK9AT9DAWN9FROM9THE9NORTH9AND9HOLD9THE9BRIDGE9UNTIL9RELIEVED";
+        for (model, ciphertext) in [
+            (
+                LorenzModel::Sz40,
+                "ZREWSRWRZSXTNNLW5YTUAALO9R3ZOBQ48GEAWROJ4YEXUI8XRNLPSHCF9U94F/YT",
+            ),
+            (
+                LorenzModel::Sz42a,
+                "ZRE3AKIC5IG3QZ3RVGLE3STGWCOMF4BLPWF9FTAA83A8RKKNS4VO99/ZSHQDA8GM",
+            ),
+            (
+                LorenzModel::Sz42b,
+                "ZRE3ANTMAXIN3BG5AM9YUWUFUV3Z4ARUHZLPZYRBUGCLACPOUR9QM/NKV4J55K4M",
+            ),
+        ] {
+            assert_eq!(encipher(&mut set_machine(model), plaintext), ciphertext);
+            let codes = bletchley_to_ita2(ciphertext).unwrap();
+            assert_eq!(
+                ita2_to_bletchley(&set_machine(model).encipher(&codes, LorenzMode::Receive)),
+                plaintext
+            );
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let codes =
+            text_to_ita2(&"ATTACK AT 0600 HOURS, 15TH PANZER DIVISION.\r\n".repeat(20)).unwrap();
+        for model in [LorenzModel::Sz40, LorenzModel::Sz42a, LorenzModel::Sz42b] {
+            let encoded = set_machine(model).encipher(&codes, LorenzMode::Send);
+            assert_ne!(encoded, codes);
+            assert_eq!(
+                set_machine(model).encipher(&encoded, LorenzMode::Receive),
+                codes
+            );
+        }
+    }
+}
@@ -0,0 +1,151 @@
+//! Rotor cipher machines of the Enigma family and the Lorenz teleprinter cipher attachment.
+//! Letters are numbered from 0 for A to 25 for Z.
+
+use anyhow::{bail, Result};
+
+pub mod enigma;
+pub mod lorenz;
+pub mod typex;
+
+pub struct Rotor {
+    forward: [u8; 26],
+    backward: [u8; 26],
+    notches: Vec<u8>,
+    ring: u8,
+    position: u8,
+}
+
+impl Rotor {
+    pub fn new(wiring: &[u8; 26], notches: &[u8], ring: u8, position: u8) -> Self {
+        let mut backward = [0u8; 26];
+        for (input, &output) in wiring.iter().enumerate() {
+            backward[output as usize] = input as u8;
+        }
+
+        Self {
+            forward: *wiring,
+            backward,
+            notches: notches.to_vec(),
+            ring,
+            position,
+        }
+    }
+
+    fn at_notch(&self) -> bool {
+        self.notches.contains(&self.position)
+    }
+
+    fn step(&mut self) {
+        self.position = (self.position + 1) % 26;
+    }
+
+    fn through(&self, wiring: &[u8; 26], letter: u8) -> u8 {
+        let shift = (26 + self.position - self.ring) % 26;
+        (wiring[((letter + shift) % 26) as usize] + 26 - shift) % 26
+    }
+}
+
+/// Rotors from left to right, only the three rightmost of them move, the ones to their left are
+/// stators. The middle moving rotor steps together with the left one, which gives the double
+/// stepping of the Enigma.
+pub struct RotorMachine {
+    pub rotors: Vec<Rotor>,
+    pub reflector: [u8; 26],
+    pub plugboard: [u8; 26],
+}
+
+impl RotorMachine {
+    fn step(&mut self) {
+        let count = self.rotors.len();
+        let (left, middle, right) = (count - 3, count - 2, count - 1);
+
+        if self.rotors[middle].at_notch() {
+            self.rotors[middle].step();
+            self.rotors[left].step();
+        } else if self.rotors[right].at_notch() {
+            self.rotors[middle].step();
+        }
+        self.rotors[right].step();
+    }
+
+    pub fn encipher_letter(&mut self, letter: u8) -> u8 {
+        self.step();
+
+        let mut letter = self.plugboard[letter as usize];
+        for rotor in self.rotors.iter().rev() {
+            letter = rotor.through(&rotor.forward, letter);
+        }
+        letter = self.reflector[letter as usize];
+        for rotor in self.rotors.iter() {
+            letter = rotor.through(&rotor.backward, letter);
+        }
+        self.plugboard[letter as usize]
+    }
+
+    /// Enciphers the letters of the text in upper case, other characters are left as is and
+    /// don't move the rotors.
+    pub fn encipher(&mut self, input: &str) -> String {
+        input
+            .chars()
+            .map(|ch| match ch.to_ascii_uppercase() {
+                upper @ 'A'..='Z' => (b'A' + self.encipher_letter(upper as u8 - b'A')) as char,
+                _ => ch,
+            })
+            .collect()
+    }
+}
+
+/// Letters of the text, case insensitive, as numbers.
+pub fn letters(text: &str, what: &str) -> Result<Vec<u8>> {
+    text.chars()
+        .map(|ch| match ch.to_ascii_uppercase() {
+            upper @ 'A'..='Z' => Ok(upper as u8 - b'A'),
+            _ => bail!("{what} must consist only of latin letters."),
+        })
+        .collect()
+}
+
+/// Rotor wiring written as the letters the alphabet is mapped to.
+pub fn wiring(text: &str) -> Result<[u8; 26]> {
+    let letters = letters(text, "Rotor wiring")?;
+    let mut wiring = [0u8; 26];
+    if letters.len() != 26 {
+        bail!("Rotor wiring must be a permutation of the alphabet.");
+    }
+    for (idx, &letter) in letters.iter().enumerate() {
+        if letters[..idx].contains(&letter) {
+            bail!("Rotor wiring must be a permutation of the alphabet.");
+        }
+        wiring[idx] = letter;
+    }
+    Ok(wiring)
+}
+
+/// Swaps the letters of every pair, pairs are separated by whitespace like "AB CD EF". The
+/// reflector needs all 13 pairs, the plugboard may have any number of them.
+pub fn pairs(text: &str, what: &str, all: bool) -> Result<[u8; 26]> {
+    let mut swaps: [u8; 26] = core::array::from_fn(|idx| idx as u8);
+    let mut count = 0;
+
+    for pair in text.split_whitespace() {
+        let pair = letters(pair, what)?;
+        let [a, b] = pair[..] else {
+            bail!("{what} must consist of pairs of letters.");
+        };
+        if a == b || swaps[a as usize] != a || swaps[b as usize] != b {
+            bail!(
+                "Every letter can be used in the {} only once.",
+                what.to_lowercase()
+            );
+        }
+        swaps[a as usize] = b;
+        swaps[b as usize] = a;
+        count += 1;
+    }
+
+    if all && count != 13 {
+        bail!("{what} must pair all the 26 letters.");
+    }
+
+    Ok(swaps)
+}
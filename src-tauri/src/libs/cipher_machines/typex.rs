@@ -0,0 +1,149 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+use super::{letters, pairs, wiring, Rotor, RotorMachine};
+
+/// Typex rotor wirings were never published, so every rotor is given by its wiring and the
+/// letters shown in the window when it turns the next rotor over.
+#[derive(Deserialize)]
+pub struct TypexRotor {
+    pub wiring: String,
+    pub notches: String,
+    pub reversed: bool,
+}
+
+pub struct TypexSettings<'a> {
+    pub rotors: &'a [TypexRotor],
+    pub reflector: &'a str,
+    pub ring_settings: &'a str,
+    pub positions: &'a str,
+    pub plugboard: &'a str,
+}
+
+/// Sets up the machine with five rotors from left to right, the two leftmost of them are
+/// stators. A reversed rotor is inserted backwards, which inverts and mirrors its wiring.
+pub fn typex(settings: TypexSettings) -> Result<RotorMachine> {
+    const ROTORS: usize = 5;
+
+    let TypexSettings {
+        rotors,
+        reflector,
+        ring_settings,
+        positions,
+        plugboard,
+    } = settings;
+
+    let ring_settings = letters(ring_settings, "Ring settings")?;
+    let positions = letters(positions, "Positions")?;
+    if rotors.len() != ROTORS || ring_settings.len() != ROTORS || positions.len() != ROTORS {
+        bail!("Typex needs {ROTORS} rotors with a ring setting and a position for each of them.");
+    }
+
+    let mut machine_rotors = Vec::with_capacity(ROTORS);
+    for (idx, rotor) in rotors.iter().enumerate() {
+        let mut rotor_wiring = wiring(&rotor.wiring)?;
+        if rotor.reversed {
+            let straight = rotor_wiring;
+            for (input, &output) in straight.iter().enumerate() {
+                rotor_wiring[((26 - output) % 26) as usize] = (26 - input as u8) % 26;
+            }
+        }
+
+        machine_rotors.push(Rotor::new(
+            &rotor_wiring,
+            &letters(&rotor.notches, "Notches")?,
+            ring_settings[idx],
+            positions[idx],
+        ));
+    }
+
+    Ok(RotorMachine {
+        rotors: machine_rotors,
+        reflector: pairs(reflector, "Reflector", true)?,
+        plugboard: pairs(plugboard, "Plugboard", false)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDENTITY: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    const REFLECTOR: &str = "JO MK EP QI FA DG CZ BR VS LY TX NU WH";
+
+    fn rotor(wiring: &str, notches: &str, reversed: bool) -> TypexRotor {
+        TypexRotor {
+            wiring: wiring.to_string(),
+            notches: notches.to_string(),
+            reversed,
+        }
+    }
+
+    fn rotors() -> Vec<TypexRotor> {
+        vec![
+            rotor("FKTYWCQXGZAOIVJHPLBDMRNUES", "CLOUY", false),
+            rotor("XSGFZYWIUVQKBPTDLNOHAJCREM", "AIKMO", true),
+            rotor("GJZTSIFDEWRBYLUMAXVPKHOQNC", "DINRX", false),
+            rotor("ISBGFQJATNVMCEODYXLKUZHRWP", "BEJOY", true),
+            rotor("NVCQMEBAGOIZLFWXRPHUDYSTJK", "GIKNS", false),
+        ]
+    }
+
+    fn machine(rotors: &[TypexRotor], reflector: &str, positions: &str) -> RotorMachine {
+        typex(TypexSettings {
+            rotors,
+            reflector,
+            ring_settings: "CZXQA",
+            positions,
+            plugboard: "AB CD",
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn straight_stators_work_as_enigma() {
+        // Two stators wired straight through and the Enigma I rotors with reflector B.
+        let rotors = [
+            rotor(IDENTITY, "", false),
+            rotor(IDENTITY, "", false),
+            rotor("EKMFLGDQVZNTOWYHXUSPAIBRCJ", "Q", false),
+            rotor("AJDKSIRUXBLHWTMCQGZNPYFVOE", "E", false),
+            rotor("BDFHJLCPRTXVZNYEIWGAKMUSQO", "V", false),
+        ];
+        let mut machine = typex(TypexSettings {
+            rotors: &rotors,
+            reflector: "AY BR CU DH EQ FS GL IP JX KN MO TZ VW",
+            ring_settings: "AAAAA",
+            positions: "AAAAA",
+            plugboard: "",
+        })
+        .unwrap();
+        assert_eq!(machine.encipher("AAAAA"), "BDZGO");
+    }
+
+    #[test]
+    fn reversed_rotor() {
+        // Swapping A and B, inserted backwards the rotor swaps A and Z.
+        let input = "ATTACK AT DAWN";
+        let mut reversed = rotors();
+        reversed[1] = rotor("BACDEFGHIJKLMNOPQRSTUVWXYZ", "AIKMO", true);
+        let mut mirrored = rotors();
+        mirrored[1] = rotor("ZBCDEFGHIJKLMNOPQRSTUVWXYA", "AIKMO", false);
+        assert_eq!(
+            machine(&reversed, REFLECTOR, "QWERT").encipher(input),
+            machine(&mirrored, REFLECTOR, "QWERT").encipher(input)
+        );
+    }
+
+    #[test]
+    fn reciprocal() {
+        let input = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG, 42 TIMES.".repeat(30);
+        let rotors = rotors();
+        let encoded = machine(&rotors, REFLECTOR, "QWERT").encipher(&input);
+        assert_ne!(encoded, input);
+        assert_eq!(
+            machine(&rotors, REFLECTOR, "QWERT").encipher(&encoded),
+            input
+        );
+    }
+}
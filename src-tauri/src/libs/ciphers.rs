@@ -1,25 +1,18 @@
 use crate::{
-    traits::CharTrait,
-    utils::{
-        get_alphabet, get_char_by_index, get_index_by_char, modulus, validate_lang,
-        SupportedLanguages,
-    },
+    libs::alphabet::Alphabet,
+    utils::{get_char_by_index, mod_inv, modulus},
 };
 use anyhow::{anyhow, bail, Result};
 use itertools::Itertools;
 use num::Integer;
+use serde::Deserialize;
 
-pub fn affine_cipher_encode(
-    input: &str,
-    lang: SupportedLanguages,
-    a: i16,
-    b: i16,
-) -> Result<String> {
-    if !validate_lang(input, &lang) {
+pub fn affine_cipher_encode(input: &str, lang: &Alphabet, a: i16, b: i16) -> Result<String> {
+    if !lang.is_text(input) {
         bail!("Wrong language.");
     };
 
-    let (alp_lower, alp_upper, _, _, alp_length, _) = get_alphabet(&lang);
+    let (alp_lower, alp_upper, alp_length) = (lang.lower(), lang.upper(), lang.length());
     if a.gcd(&(alp_length as i16)) != 1 {
         return Err(anyhow!(
             "The value of `a` must be coprime to alphabet length({}).",
@@ -27,46 +20,166 @@ pub fn affine_cipher_encode(
         ));
     }
 
-    let mut output = String::with_capacity(alp_length as usize);
+    let mut output = String::with_capacity(alp_length);
 
     for c in input.chars() {
-        if !c.is_alphabetic() {
+        let Some(x) = lang.index_of(c) else {
             output.push(c);
             continue;
-        }
+        };
 
-        let x = match c.is_lowercase() {
-            true => get_index_by_char(alp_lower, c),
-            false => get_index_by_char(alp_upper, c),
-        } as i16;
+        let x = modulus(a * x as i16 + b, alp_length as i16);
 
-        let x = modulus(a * x + b, alp_length as i16);
+        output.push(match c.is_lowercase() {
+            true => get_char_by_index(alp_lower, x),
+            false => get_char_by_index(alp_upper, x),
+        });
+    }
+
+    Ok(output)
+}
+
+pub fn affine_cipher_decode(input: &str, lang: &Alphabet, a: i16, b: i16) -> Result<String> {
+    if !lang.is_text(input) {
+        bail!("Wrong language.");
+    };
+
+    let (alp_lower, alp_upper, alp_length) = (lang.lower(), lang.upper(), lang.length());
+    if a.gcd(&(alp_length as i16)) != 1 {
+        return Err(anyhow!(
+            "The value of `a` must be coprime to alphabet length({}).",
+            alp_length
+        ));
+    }
+
+    let inv_a = mod_inv(a, alp_length as i16);
+    let mut output = String::with_capacity(alp_length);
+
+    for c in input.chars() {
+        let Some(y) = lang.index_of(c) else {
+            output.push(c);
+            continue;
+        };
+
+        let x = modulus(inv_a * (y as i16 - b), alp_length as i16);
 
         output.push(match c.is_lowercase() {
             true => get_char_by_index(alp_lower, x),
-            false => get_char_by_index(alp_upper, x).to_upper_case(),
+            false => get_char_by_index(alp_upper, x),
         });
     }
 
     Ok(output)
 }
 
-pub fn gen_polybius_square(alphabet: &SupportedLanguages, keyword: &str) -> Vec<char> {
-    let (_, _, _, alpha, _, _) = get_alphabet(alphabet);
-    let pol_array = format!("{}{}", keyword, alpha)
+/// Builds the Polybius square of the alphabet with the keyword letters first, returns the square
+/// and its side.
+pub fn gen_polybius_square(alphabet: &Alphabet, keyword: &str) -> Result<(Vec<char>, usize)> {
+    let Some((alpha, size)) = alphabet.polybius() else {
+        bail!("The alphabet doesn't fill a Polybius square.");
+    };
+    let polybius = format!("{}{}", keyword, alpha)
         .chars()
         .unique()
-        .collect::<Vec<_>>();
+        .take(size * size)
+        .collect();
 
-    let size = match alphabet {
-        SupportedLanguages::EN => 5,
-        SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => 6,
-    };
-    let mut polybius: Vec<char> = Vec::new();
+    Ok((polybius, size))
+}
+
+/// Keyword letters without repeats followed by the rest of the alphabet, other characters of the
+/// keyword are skipped.
+pub fn keyword_alphabet(lang: &Alphabet, keyword: &str) -> String {
+    keyword
+        .chars()
+        .filter_map(|ch| lang.index_of(ch))
+        .chain(0..lang.length())
+        .unique()
+        .map(|idx| get_char_by_index(lang.lower(), idx))
+        .collect()
+}
 
-    for i in 0..size {
-        polybius.extend(pol_array[i * size..i * size + size].iter());
+pub fn caesar_cipher(input: &str, lang: &Alphabet, shift: i16) -> String {
+    let alp_length = lang.length() as i16;
+    let shift = modulus(shift, alp_length);
+
+    input
+        .chars()
+        .map(|c| {
+            for alp in [lang.lower(), lang.upper()] {
+                if let Some(idx) = alp.chars().position(|x| x == c) {
+                    return get_char_by_index(alp, (idx as i16 + shift) % alp_length);
+                }
+            }
+            c
+        })
+        .collect()
+}
+
+/// Turns the digits of the Gronsfeld key into the letters of the alphabet with the same shifts.
+pub fn gronsfeld_key(lang: &Alphabet, key: &str) -> Result<String> {
+    if key.is_empty() || !key.chars().all(|ch| ch.is_ascii_digit()) {
+        bail!("Key must consist only of digits.");
     }
 
-    polybius
+    Ok(key
+        .chars()
+        .map(|ch| {
+            get_char_by_index(
+                lang.lower(),
+                ch.to_digit(10).unwrap() as usize % lang.length(),
+            )
+        })
+        .collect())
+}
+
+#[derive(Deserialize, Copy, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayfairSplitRule {
+    InsertFiller,
+    ReplaceWithFiller,
+}
+
+/// Builds the Playfair square from the keyword ignoring its whitespace, returns the square and
+/// its side.
+pub fn playfair_square(lang: &Alphabet, keyword: &str) -> Result<(Vec<char>, usize)> {
+    let (square, _) = gen_polybius_square(lang, "")?;
+    let keyword: String = lang
+        .polybius_normalize(keyword)
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .collect();
+
+    if !keyword.chars().all(|ch| square.contains(&ch)) {
+        bail!("The keyword must consist only of the alphabet characters.");
+    }
+
+    gen_polybius_square(lang, &keyword)
+}
+
+/// Encodes a digraph with `shift` = 1 or decodes it with `shift` = size - 1. Letters in the same
+/// row or column are shifted along it, otherwise they take the corners of their rectangle.
+pub fn playfair_digraph(
+    square: &[char],
+    size: usize,
+    (a, b): (char, char),
+    shift: usize,
+) -> (char, char) {
+    let position = |ch: char| square.iter().position(|&x| x == ch).unwrap();
+    let (row_a, col_a) = (position(a) / size, position(a) % size);
+    let (row_b, col_b) = (position(b) / size, position(b) % size);
+
+    if row_a == row_b {
+        (
+            square[row_a * size + (col_a + shift) % size],
+            square[row_b * size + (col_b + shift) % size],
+        )
+    } else if col_a == col_b {
+        (
+            square[(row_a + shift) % size * size + col_a],
+            square[(row_b + shift) % size * size + col_b],
+        )
+    } else {
+        (square[row_a * size + col_b], square[row_b * size + col_a])
+    }
 }
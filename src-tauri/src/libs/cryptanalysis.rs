@@ -0,0 +1,663 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    libs::alphabet::Alphabet,
+    utils::{get_char_by_index, SupportedLanguages},
+};
+
+/// Relative letter frequencies in percent, in the order of the lowercase alphabet.
+const EN_FREQUENCIES: [f64; 26] = [
+    8.167, 1.492, 2.782, 4.253, 12.702, 2.228, 2.015, 6.094, 6.966, 0.153, 0.772, 4.025, 2.406,
+    6.749, 7.507, 1.929, 0.095, 5.987, 6.327, 9.056, 2.758, 0.978, 2.360, 0.150, 1.974, 0.074,
+];
+const RU_FREQUENCIES: [f64; 32] = [
+    8.01, 1.59, 4.54, 1.70, 2.98, 8.45, 0.94, 1.65, 7.35, 1.21, 3.49, 4.40, 3.21, 6.70, 10.97,
+    2.81, 4.73, 5.47, 6.26, 2.62, 0.26, 0.97, 0.48, 1.44, 0.73, 0.36, 0.04, 1.90, 1.74, 0.32, 0.64,
+    2.01,
+];
+const RU_WITH_YO_FREQUENCIES: [f64; 33] = [
+    8.01, 1.59, 4.54, 1.70, 2.98, 8.45, 0.04, 0.94, 1.65, 7.35, 1.21, 3.49, 4.40, 3.21, 6.70,
+    10.97, 2.81, 4.73, 5.47, 6.26, 2.62, 0.26, 0.97, 0.48, 1.44, 0.73, 0.36, 0.04, 1.90, 1.74,
+    0.32, 0.64, 2.01,
+];
+
+/// Most frequent quadgrams of the language, counted across word boundaries.
+const EN_QUADGRAMS: &[&str] = &[
+    "TION", "NTHE", "THER", "THAT", "OFTH", "FTHE", "THES", "WITH", "INTH", "ATIO", "OTHE", "TTHE",
+    "DTHE", "INGT", "ETHE", "SAND", "STHE", "HERE", "THEC", "MENT", "THEM", "RTHE", "THEP", "FROM",
+    "THIS", "TING", "THEI", "NGTH", "IONS", "ANDT",
+];
+const RU_QUADGRAMS: &[&str] = &[
+    "ЕНИЯ", "ЕНИЕ", "СТВО", "ОСТИ", "НОГО", "ТОРО", "ЕСТВ", "ОВАН", "АНИЯ", "ОСТЬ", "ЛЬНО", "ПРОС",
+    "ТЕЛЬ", "ЧТОБ", "ПРЕД", "ЕСКИ", "СКОЙ", "ОВОР", "ТОГО", "ЕЛЬН", "ОСТО", "АНИЕ", "ПОСЛ", "СКИХ",
+    "ТВЕН", "ЛЬКО", "КОТО", "ОТОР",
+];
+
+/// Most frequent bigrams of the language.
+const EN_BIGRAMS: &[&str] = &[
+    "TH", "HE", "IN", "ER", "AN", "RE", "ON", "AT", "EN", "ND", "TI", "ES", "OR", "TE", "OF", "ED",
+    "IS", "IT", "AL", "AR", "ST", "TO", "NT", "NG", "SE", "HA", "AS", "OU", "IO", "LE", "VE", "CO",
+    "ME", "DE", "HI", "RI", "RO", "IC", "NE", "EA", "RA", "CE",
+];
+const RU_BIGRAMS: &[&str] = &[
+    "СТ", "НО", "ТО", "НА", "ЕН", "ОВ", "НИ", "РА", "ВО", "КО", "ЛИ", "РЕ", "ОР", "ПО", "ЕР", "ОС",
+    "ЛО", "ПР", "ЕТ", "ОЛ", "ГО", "ТА", "ЕЛ", "АЛ", "ОТ", "ЛЕ", "ВА", "ОМ", "ИЕ", "ТЕ", "ОД", "ЕС",
+    "НЕ", "ИТ", "ОЙ", "ЧТ", "АН", "ЕМ", "ИЛ", "ДЕ",
+];
+
+/// Most frequent words of the language.
+const EN_WORDS: &[&str] = &[
+    "THE", "OF", "AND", "TO", "A", "IN", "IS", "IT", "YOU", "THAT", "HE", "WAS", "FOR", "ON",
+    "ARE", "WITH", "AS", "I", "HIS", "THEY", "BE", "AT", "ONE", "HAVE", "THIS", "FROM", "OR",
+    "HAD", "BY", "NOT", "BUT", "WHAT", "SOME", "WE", "CAN", "OUT", "OTHER", "WERE", "ALL", "THERE",
+    "WHEN", "UP", "USE", "YOUR", "HOW", "SAID", "AN", "EACH", "SHE", "WHICH", "DO", "THEIR",
+    "TIME", "IF", "WILL", "WAY", "ABOUT", "MANY", "THEN", "THEM", "WOULD", "LIKE", "SO", "THESE",
+    "HER", "LONG", "MAKE", "THING", "SEE", "HIM", "TWO", "HAS", "LOOK", "MORE", "DAY", "COULD",
+    "GO", "COME", "DID", "NO", "MOST", "PEOPLE", "MY", "OVER", "KNOW", "THAN", "FIRST", "WHO",
+    "MAY", "DOWN", "BEEN", "NOW", "ANY", "NEW", "WORK", "PART", "TAKE", "GET", "MADE", "WHERE",
+    "AFTER", "BACK", "LITTLE", "ONLY", "MAN", "YEAR", "CAME", "EVERY", "GOOD", "ME", "GIVE", "OUR",
+    "UNDER",
+];
+const RU_WORDS: &[&str] = &[
+    "И",
+    "В",
+    "НЕ",
+    "НА",
+    "Я",
+    "ЧТО",
+    "ОН",
+    "С",
+    "А",
+    "КАК",
+    "ПО",
+    "ЭТО",
+    "К",
+    "НО",
+    "ОНИ",
+    "МЫ",
+    "ИЗ",
+    "У",
+    "ТО",
+    "ЗА",
+    "ТАК",
+    "ЖЕ",
+    "ОТ",
+    "БЫ",
+    "ВЫ",
+    "О",
+    "ВСЕ",
+    "ЕГО",
+    "ОНА",
+    "БЫЛО",
+    "ДЛЯ",
+    "БЫЛ",
+    "ТЫ",
+    "ТОЛЬКО",
+    "МНЕ",
+    "УЖЕ",
+    "ИЛИ",
+    "НЕТ",
+    "ДА",
+    "КОГДА",
+    "ИХ",
+    "ДАЖЕ",
+    "НУ",
+    "ВОТ",
+    "ЕСЛИ",
+    "ДО",
+    "ЕЩЕ",
+    "БЫЛА",
+    "НЕГО",
+    "МЕНЯ",
+    "ТАМ",
+    "СЕБЯ",
+    "МОЖНО",
+    "ПРИ",
+    "БЫЛИ",
+    "НИ",
+    "ЧТОБЫ",
+    "ТЕБЯ",
+    "ГДЕ",
+    "ЕСТЬ",
+    "МОЖЕТ",
+    "ЛИ",
+    "БУДЕТ",
+    "ВАС",
+    "НАС",
+    "БЕЗ",
+    "КТО",
+    "ПОД",
+    "НАМ",
+    "ВРЕМЯ",
+    "ТОМ",
+    "ЭТОГО",
+    "ТОЖЕ",
+    "РАЗ",
+    "ПОСЛЕ",
+    "ТОГДА",
+    "СЕЙЧАС",
+    "ПОТОМ",
+    "ОЧЕНЬ",
+    "ЭТОТ",
+    "ЕЕ",
+    "ИМ",
+    "СО",
+    "ВО",
+    "МОЙ",
+    "НАШ",
+    "СВОЙ",
+    "ВЕСЬ",
+    "ОДИН",
+    "ДВА",
+    "ДЕНЬ",
+    "ЖИЗНЬ",
+    "ЧЕЛОВЕК",
+    "ДЕЛО",
+    "СКАЗАЛ",
+    "НАДО",
+    "КОТОРЫЙ",
+    "ТОТ",
+    "ЭТИ",
+    "ЧЕМ",
+    "ТЕМ",
+    "УЖ",
+    "ЗДЕСЬ",
+    "ВСЕГО",
+    "ВСЕХ",
+    "ТЕПЕРЬ",
+    "ХОРОШО",
+    "САМ",
+    "СЕБЕ",
+    "ЕМУ",
+    "ЕЙ",
+    "ТУТ",
+    "НАД",
+    "ПЕРЕД",
+    "ЧЕРЕЗ",
+    "ПРО",
+    "ЧЕГО",
+];
+
+/// Bonus added for every frequent quadgram found, in the same log10 units as letter scores.
+const QUADGRAM_BONUS: f64 = 2.0;
+/// Bonuses used only to solve the substitution cipher, where every letter changes independently of
+/// its neighbours.
+const BIGRAM_BONUS: f64 = 0.5;
+const WORD_BONUS: f64 = 1.0;
+
+pub fn letter_frequencies(lang: &SupportedLanguages) -> &'static [f64] {
+    match lang {
+        SupportedLanguages::EN => &EN_FREQUENCIES,
+        SupportedLanguages::RU => &RU_FREQUENCIES,
+        SupportedLanguages::RU_WITH_YO => &RU_WITH_YO_FREQUENCIES,
+    }
+}
+
+/// Alphabet indexes of the letters of the text, other characters are skipped.
+pub fn letter_indexes(text: &str, lang: &SupportedLanguages) -> Vec<usize> {
+    let alphabet = Alphabet::from(*lang);
+    let map: HashMap<char, usize> = alphabet
+        .lower()
+        .chars()
+        .enumerate()
+        .chain(alphabet.upper().chars().enumerate())
+        .map(|(idx, ch)| (ch, idx))
+        .collect();
+
+    text.chars()
+        .filter_map(|ch| map.get(&ch).copied())
+        .collect()
+}
+
+/// Average log10 probability of the letters of the text with a bonus for frequent quadgrams,
+/// the higher the score the more the text looks like the language.
+pub fn score_text(text: &str, lang: &SupportedLanguages) -> f64 {
+    score_letters(&letter_indexes(text, lang), lang)
+}
+
+pub fn score_letters(letters: &[usize], lang: &SupportedLanguages) -> f64 {
+    if letters.is_empty() {
+        return f64::NEG_INFINITY;
+    }
+
+    let frequencies = letter_frequencies(lang);
+    let alphabet = Alphabet::from(*lang);
+    let quadgrams = match lang {
+        SupportedLanguages::EN => EN_QUADGRAMS,
+        SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => RU_QUADGRAMS,
+    };
+
+    let upper: Vec<char> = letters
+        .iter()
+        .map(|&idx| get_char_by_index(alphabet.upper(), idx))
+        .collect();
+    let quadgram_hits = upper
+        .windows(4)
+        .filter(|window| {
+            let window: String = window.iter().collect();
+            quadgrams.contains(&window.as_str())
+        })
+        .count();
+
+    let letter_score: f64 = letters
+        .iter()
+        .map(|&idx| (frequencies[idx] / 100.0).log10())
+        .sum();
+
+    (letter_score + QUADGRAM_BONUS * quadgram_hits as f64) / letters.len() as f64
+}
+
+pub fn index_of_coincidence(letters: &[usize], alp_length: usize) -> f64 {
+    let n = letters.len();
+    if n < 2 {
+        return 0.0;
+    }
+
+    let mut counts = vec![0usize; alp_length];
+    letters.iter().for_each(|&idx| counts[idx] += 1);
+
+    counts
+        .iter()
+        .map(|&c| c * c.saturating_sub(1))
+        .sum::<usize>() as f64
+        / (n * (n - 1)) as f64
+}
+
+/// Counts how many distances between repeated trigrams are divisible by every key length
+/// up to `max_key_length`, the result is indexed by the key length.
+pub fn kasiski_examination(letters: &[usize], max_key_length: usize) -> Vec<usize> {
+    let mut positions: HashMap<&[usize], usize> = HashMap::new();
+    let mut factors = vec![0usize; max_key_length + 1];
+
+    for (pos, trigram) in letters.windows(3).enumerate() {
+        if let Some(previous) = positions.insert(trigram, pos) {
+            let distance = pos - previous;
+            (2..=max_key_length)
+                .filter(|length| distance % length == 0)
+                .for_each(|length| factors[length] += 1);
+        }
+    }
+
+    factors
+}
+
+/// Most probable Vigenère key lengths: the ones whose columns have the index of coincidence
+/// closest to the language, the ones supported by most of the repeated trigrams and their
+/// divisors.
+pub fn vigenere_key_lengths(
+    letters: &[usize],
+    lang: &SupportedLanguages,
+    max_key_length: usize,
+) -> Vec<usize> {
+    const CANDIDATES: usize = 3;
+
+    let frequencies = letter_frequencies(lang);
+    let language_ioc: f64 = frequencies.iter().map(|p| (p / 100.0).powi(2)).sum();
+    let max_key_length = max_key_length.min(letters.len() / 2).max(1);
+
+    let mut by_ioc: Vec<(usize, f64)> = (1..=max_key_length)
+        .map(|length| {
+            let ioc = (0..length)
+                .map(|column| {
+                    let column: Vec<usize> = letters
+                        .iter()
+                        .skip(column)
+                        .step_by(length)
+                        .copied()
+                        .collect();
+                    index_of_coincidence(&column, frequencies.len())
+                })
+                .sum::<f64>()
+                / length as f64;
+            (length, (ioc - language_ioc).abs())
+        })
+        .collect();
+    by_ioc.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+    let factors = kasiski_examination(letters, max_key_length);
+    let mut by_kasiski: Vec<(usize, usize)> = factors
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, count)| count > 0)
+        .collect();
+    by_kasiski.sort_by_key(|&(_, count)| Reverse(count));
+
+    let mut lengths: Vec<usize> = Vec::new();
+    for length in by_ioc
+        .iter()
+        .map(|&(length, _)| length)
+        .take(CANDIDATES)
+        .chain(
+            by_kasiski
+                .iter()
+                .map(|&(length, _)| length)
+                .take(CANDIDATES),
+        )
+    {
+        // Multiples of the key length score as well as the length itself, so its divisors are
+        // tried too as the short columns of a long key are less reliable.
+        for divisor in (1..=length).filter(|divisor| length % divisor == 0) {
+            if !lengths.contains(&divisor) {
+                lengths.push(divisor);
+            }
+        }
+    }
+
+    lengths
+}
+
+/// Caesar shift of a single column that gives the letter distribution closest to the
+/// language by the chi-squared statistic.
+pub fn best_caesar_shift(letters: &[usize], lang: &SupportedLanguages) -> usize {
+    let frequencies = letter_frequencies(lang);
+    let alp_length = frequencies.len();
+
+    let mut counts = vec![0usize; alp_length];
+    letters.iter().for_each(|&idx| counts[idx] += 1);
+
+    let chi_squared = |shift: usize| -> f64 {
+        (0..alp_length)
+            .map(|plain| {
+                let observed = counts[(plain + shift) % alp_length] as f64;
+                let expected = frequencies[plain] / 100.0 * letters.len() as f64;
+                (observed - expected).powi(2) / expected
+            })
+            .sum()
+    };
+
+    (0..alp_length)
+        .min_by(|&a, &b| chi_squared(a).total_cmp(&chi_squared(b)))
+        .unwrap_or_default()
+}
+
+/// Vigenère key of the given length. Every letter is first found by frequency analysis of its
+/// column alone, then refined by the score of the whole decoded text, which also takes the
+/// neighbouring columns into account through quadgrams.
+pub fn vigenere_key(letters: &[usize], lang: &SupportedLanguages, length: usize) -> Vec<usize> {
+    let alp_length = letter_frequencies(lang).len();
+    let decode = |key: &[usize]| -> Vec<usize> {
+        letters
+            .iter()
+            .enumerate()
+            .map(|(pos, &idx)| (idx + alp_length - key[pos % length]) % alp_length)
+            .collect()
+    };
+
+    let mut key: Vec<usize> = (0..length)
+        .map(|column| {
+            let column: Vec<usize> = letters
+                .iter()
+                .skip(column)
+                .step_by(length)
+                .copied()
+                .collect();
+            best_caesar_shift(&column, lang)
+        })
+        .collect();
+
+    for column in 0..length {
+        key[column] = (0..alp_length)
+            .map(|shift| {
+                let mut candidate = key.clone();
+                candidate[column] = shift;
+                (shift, score_letters(&decode(&candidate), lang))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(shift, _)| shift)
+            .unwrap_or_default();
+    }
+
+    key
+}
+
+/// Shortest key that repeats into the given one, "abcabc" becomes "abc".
+pub fn shortest_period(key: &[usize]) -> &[usize] {
+    (1..=key.len())
+        .find(|&period| key.len() % period == 0 && key.chunks(period).all(|c| c == &key[..period]))
+        .map(|period| &key[..period])
+        .unwrap_or(key)
+}
+
+/// Whether the key is a longer multiple of the shorter one with a few letters overfitted to the
+/// columns, less than a quarter of its letters may differ from the repeated shorter key.
+pub fn nearly_repeats(key: &[usize], shorter: &[usize]) -> bool {
+    if shorter.is_empty() || shorter.len() >= key.len() || key.len() % shorter.len() != 0 {
+        return false;
+    }
+
+    let mismatches = key
+        .iter()
+        .zip(shorter.iter().cycle())
+        .filter(|(a, b)| a != b)
+        .count();
+
+    mismatches * 4 < key.len()
+}
+
+/// Monoalphabetic substitution key, the plaintext letter index for every ciphertext letter index.
+/// Letters are first matched by their frequency ranks, then pairs of them are swapped while the
+/// decoded text keeps looking more like the language. The search is repeated from slightly
+/// shuffled keys to get out of local maxima, the seed is fixed so the result is reproducible.
+pub fn substitution_key(text: &str, lang: &SupportedLanguages) -> Vec<usize> {
+    const RESTARTS: usize = 20;
+    const SHUFFLED_PAIRS: usize = 3;
+
+    let frequencies = letter_frequencies(lang);
+    let alp_length = frequencies.len();
+    let scorer = SubstitutionScorer::new(text, lang);
+
+    let mut counts = vec![0usize; alp_length];
+    scorer
+        .words
+        .iter()
+        .flatten()
+        .for_each(|&idx| counts[idx] += 1);
+
+    let mut cipher_ranks: Vec<usize> = (0..alp_length).collect();
+    cipher_ranks.sort_by_key(|&idx| Reverse(counts[idx]));
+    let mut plain_ranks: Vec<usize> = (0..alp_length).collect();
+    plain_ranks.sort_by(|&a, &b| frequencies[b].total_cmp(&frequencies[a]));
+
+    let mut key = vec![0usize; alp_length];
+    for (&cipher, &plain) in cipher_ranks.iter().zip(&plain_ranks) {
+        key[cipher] = plain;
+    }
+    let mut best = scorer.climb(&mut key);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..RESTARTS {
+        let mut candidate = key.clone();
+        for _ in 0..SHUFFLED_PAIRS {
+            candidate.swap(rng.gen_range(0..alp_length), rng.gen_range(0..alp_length));
+        }
+        let score = scorer.climb(&mut candidate);
+        if score > best {
+            best = score;
+            key = candidate;
+        }
+    }
+
+    key
+}
+
+struct SubstitutionScorer {
+    words: Vec<Vec<usize>>,
+    log_frequencies: Vec<f64>,
+    /// Whether the bigram of letters `a`, `b` is frequent, indexed by `a * alp_length + b`.
+    bigrams: Vec<bool>,
+    quadgrams: HashSet<Vec<usize>>,
+    dictionary: HashSet<Vec<usize>>,
+}
+
+impl SubstitutionScorer {
+    fn new(text: &str, lang: &SupportedLanguages) -> Self {
+        let alphabet = Alphabet::from(*lang);
+        let (bigrams, quadgrams, dictionary) = match lang {
+            SupportedLanguages::EN => (EN_BIGRAMS, EN_QUADGRAMS, EN_WORDS),
+            SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => {
+                (RU_BIGRAMS, RU_QUADGRAMS, RU_WORDS)
+            }
+        };
+        let alp_length = letter_frequencies(lang).len();
+        let indexes = |ngrams: &[&str]| -> HashSet<Vec<usize>> {
+            ngrams
+                .iter()
+                .map(|ngram| letter_indexes(ngram, lang))
+                .collect()
+        };
+        let bigrams = indexes(bigrams);
+
+        Self {
+            words: text
+                .split(|ch: char| !alphabet.contains(ch))
+                .filter(|word| !word.is_empty())
+                .map(|word| letter_indexes(word, lang))
+                .collect(),
+            log_frequencies: letter_frequencies(lang)
+                .iter()
+                .map(|p| (p / 100.0).log10())
+                .collect(),
+            bigrams: (0..alp_length * alp_length)
+                .map(|idx| bigrams.contains(&[idx / alp_length, idx % alp_length][..]))
+                .collect(),
+            quadgrams: indexes(quadgrams),
+            dictionary: indexes(dictionary),
+        }
+    }
+
+    /// Same units as `score_letters`, with bonuses for frequent bigrams and for every letter of
+    /// the words found among the most frequent ones, as the word boundaries are kept by the
+    /// cipher.
+    fn score(&self, key: &[usize]) -> f64 {
+        let mut decoded: Vec<usize> = Vec::new();
+        let mut dictionary_letters = 0;
+        for word in &self.words {
+            let start = decoded.len();
+            decoded.extend(word.iter().map(|&idx| key[idx]));
+            if self.dictionary.contains(&decoded[start..]) {
+                dictionary_letters += word.len();
+            }
+        }
+
+        let letter_score: f64 = decoded.iter().map(|&idx| self.log_frequencies[idx]).sum();
+        let alp_length = self.log_frequencies.len();
+        let bigram_hits = decoded
+            .windows(2)
+            .filter(|window| self.bigrams[window[0] * alp_length + window[1]])
+            .count();
+        let quadgram_hits = decoded
+            .windows(4)
+            .filter(|window| self.quadgrams.contains(*window))
+            .count();
+
+        (letter_score
+            + BIGRAM_BONUS * bigram_hits as f64
+            + QUADGRAM_BONUS * quadgram_hits as f64
+            + WORD_BONUS * dictionary_letters as f64)
+            / decoded.len().max(1) as f64
+    }
+
+    /// Swaps pairs of plaintext letters while it improves the score, returns the final score.
+    fn climb(&self, key: &mut [usize]) -> f64 {
+        let mut best = self.score(key);
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for a in 0..key.len() {
+                for b in a + 1..key.len() {
+                    key.swap(a, b);
+                    let score = self.score(key);
+                    if score > best {
+                        best = score;
+                        improved = true;
+                    } else {
+                        key.swap(a, b);
+                    }
+                }
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EN: SupportedLanguages = SupportedLanguages::EN;
+    const PLAINTEXT: &str =
+        "The old lighthouse keeper walked along the shore every morning before \
+        the sun came up. He counted the boats that were leaving the harbour and wrote their names \
+        in a small notebook that he kept in the pocket of his coat. When the weather was bad he \
+        stayed inside and listened to the radio, waiting for news from the ships that were still \
+        out at sea.";
+
+    fn shift_letters(letters: &[usize], key: &[usize]) -> Vec<usize> {
+        letters
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(&idx, &shift)| (idx + shift) % 26)
+            .collect()
+    }
+
+    #[test]
+    fn kasiski() {
+        // The trigram "abc" repeats twice, both times 6 letters after the previous one.
+        let letters = letter_indexes("abcxyzabcqqqabc", &EN);
+        assert_eq!(kasiski_examination(&letters, 6), [0, 0, 2, 2, 0, 0, 2]);
+    }
+
+    #[test]
+    fn vigenere_lemon() {
+        let lemon = letter_indexes("lemon", &EN);
+        let letters = shift_letters(&letter_indexes(PLAINTEXT, &EN), &lemon);
+
+        let lengths = vigenere_key_lengths(&letters, &EN, 10);
+        assert!(lengths.contains(&5), "{lengths:?}");
+        assert_eq!(vigenere_key(&letters, &EN, 5), lemon);
+        assert_eq!(shortest_period(&vigenere_key(&letters, &EN, 10)), lemon);
+    }
+
+    #[test]
+    fn caesar_shift() {
+        let letters = letter_indexes(PLAINTEXT, &EN);
+        for shift in [0, 3, 13, 25] {
+            assert_eq!(
+                best_caesar_shift(&shift_letters(&letters, &[shift]), &EN),
+                shift
+            );
+        }
+    }
+
+    #[test]
+    fn coincidence() {
+        let letters = letter_indexes(PLAINTEXT, &EN);
+        let ioc = index_of_coincidence(&letters, 26);
+        assert!((0.055..0.075).contains(&ioc), "{ioc}");
+        let flat: Vec<usize> = (0..26).cycle().take(260).collect();
+        assert!(index_of_coincidence(&flat, 26) < 0.04);
+        assert_eq!(index_of_coincidence(&[], 26), 0.0);
+    }
+
+    #[test]
+    fn periods() {
+        assert_eq!(shortest_period(&[1, 2, 3, 1, 2, 3]), [1, 2, 3]);
+        assert_eq!(shortest_period(&[1, 2, 3, 1, 2]), [1, 2, 3, 1, 2]);
+        assert!(nearly_repeats(&[1, 2, 1, 2, 1, 2, 1, 9], &[1, 2]));
+        assert!(!nearly_repeats(&[1, 2, 1, 9, 1, 2, 1, 9], &[1, 2]));
+        assert!(!nearly_repeats(&[1, 2, 1], &[1, 2]));
+    }
+
+    #[test]
+    fn no_letters() {
+        assert!(letter_indexes("42, 17 & 9!", &EN).is_empty());
+        assert_eq!(score_text("", &EN), f64::NEG_INFINITY);
+        assert_eq!(best_caesar_shift(&[], &EN), 0);
+    }
+}
@@ -0,0 +1,355 @@
+use anyhow::{bail, Result};
+use p256::{
+    ecdsa::signature::{RandomizedSigner, Signer, Verifier},
+    elliptic_curve::sec1::ToEncodedPoint,
+    pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding},
+};
+use serde::Deserialize;
+
+use crate::utils::{decode_der, pem_label, to_hex};
+
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+pub enum EcCurve {
+    #[serde(rename = "P-256")]
+    P256,
+    #[serde(rename = "P-384")]
+    P384,
+    #[serde(rename = "secp256k1")]
+    Secp256k1,
+}
+
+impl std::fmt::Display for EcCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::P256 => write!(f, "P-256"),
+            Self::P384 => write!(f, "P-384"),
+            Self::Secp256k1 => write!(f, "secp256k1"),
+        }
+    }
+}
+
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EcKeyFormat {
+    Raw,
+    Pem,
+    Der,
+}
+
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EcSignatureEncoding {
+    Der,
+    Raw,
+}
+
+/// Expands the body once for every curve with `$c` bound to the curve crate, `p256`, `p384` and
+/// `k256` expose the same API.
+macro_rules! with_curve {
+    ($curve:expr, $c:ident => $body:expr) => {
+        match $curve {
+            EcCurve::P256 => {
+                use p256 as $c;
+                $body
+            }
+            EcCurve::P384 => {
+                use p384 as $c;
+                $body
+            }
+            EcCurve::Secp256k1 => {
+                use k256 as $c;
+                $body
+            }
+        }
+    };
+}
+
+/// Keeps the private scalar and the uncompressed SEC1 point as bytes so that the key doesn't
+/// depend on the curve type.
+pub struct EcKey {
+    curve: EcCurve,
+    secret: Option<Vec<u8>>,
+    public: Vec<u8>,
+}
+
+impl EcKey {
+    pub fn generate(curve: EcCurve) -> Self {
+        with_curve!(curve, c => {
+            let secret = c::SecretKey::random(&mut rand::thread_rng());
+            Self {
+                curve,
+                secret: Some(secret.to_bytes().to_vec()),
+                public: secret.public_key().to_encoded_point(false).as_bytes().to_vec(),
+            }
+        })
+    }
+
+    /// Reads a private key from PKCS#8 or SEC1 PEM, PKCS#8 or SEC1 DER (hex or base64) or a raw
+    /// hex scalar.
+    pub fn parse_private(input: &str, curve: EcCurve) -> Result<Self> {
+        let input = input.trim();
+
+        with_curve!(curve, c => {
+            let secret = match pem_label(input) {
+                Some("PRIVATE KEY") => c::SecretKey::from_pkcs8_pem(input)?,
+                Some("EC PRIVATE KEY") => c::SecretKey::from_sec1_pem(input)?,
+                Some(label) => bail!("Unsupported PEM label \"{label}\" for a private key."),
+                None => {
+                    let der = decode_der(input)?;
+                    let secret = match der.len() == c::FieldBytes::default().len() {
+                        true => c::SecretKey::from_slice(&der).ok(),
+                        false => c::SecretKey::from_pkcs8_der(&der)
+                            .or_else(|_| c::SecretKey::from_sec1_der(&der))
+                            .ok(),
+                    };
+                    let Some(secret) = secret else {
+                        bail!("Invalid private key for {curve} curve.");
+                    };
+                    secret
+                }
+            };
+
+            Ok(Self {
+                curve,
+                secret: Some(secret.to_bytes().to_vec()),
+                public: secret.public_key().to_encoded_point(false).as_bytes().to_vec(),
+            })
+        })
+    }
+
+    /// Reads a public key from SPKI PEM, SPKI DER (hex or base64) or a hex SEC1 point, compressed
+    /// or not. Private keys are accepted as well.
+    pub fn parse_public(input: &str, curve: EcCurve) -> Result<Self> {
+        let input = input.trim();
+
+        with_curve!(curve, c => {
+            let public = match pem_label(input) {
+                Some("PUBLIC KEY") => c::PublicKey::from_public_key_pem(input)?,
+                Some(_) => return Self::parse_private(input, curve),
+                None => {
+                    let der = decode_der(input)?;
+                    let field_len = c::FieldBytes::default().len();
+                    let is_point = matches!(der.first(), Some(2..=4))
+                        && [field_len + 1, 2 * field_len + 1].contains(&der.len());
+
+                    match is_point {
+                        true => match c::PublicKey::from_sec1_bytes(&der) {
+                            Ok(public) => public,
+                            Err(_) => bail!("Point is not on {curve} curve."),
+                        },
+                        false => match c::PublicKey::from_public_key_der(&der) {
+                            Ok(public) => public,
+                            Err(_) => return Self::parse_private(input, curve),
+                        },
+                    }
+                }
+            };
+
+            Ok(Self {
+                curve,
+                secret: None,
+                public: public.to_encoded_point(false).as_bytes().to_vec(),
+            })
+        })
+    }
+
+    /// Encodes the key as PKCS#8, DER encoded keys and raw scalars are returned as hex.
+    pub fn encode_private(&self, format: EcKeyFormat) -> Result<String> {
+        let secret = self.secret()?;
+
+        with_curve!(self.curve, c => {
+            let secret = c::SecretKey::from_slice(secret)?;
+            Ok(match format {
+                EcKeyFormat::Raw => to_hex(&secret.to_bytes()),
+                EcKeyFormat::Pem => secret.to_pkcs8_pem(LineEnding::LF)?.to_string(),
+                EcKeyFormat::Der => to_hex(secret.to_pkcs8_der()?.as_bytes()),
+            })
+        })
+    }
+
+    /// Encodes the key as SPKI, `compressed` affects only raw SEC1 points.
+    pub fn encode_public(&self, format: EcKeyFormat, compressed: bool) -> Result<String> {
+        with_curve!(self.curve, c => {
+            let public = c::PublicKey::from_sec1_bytes(&self.public)?;
+            Ok(match format {
+                EcKeyFormat::Raw => to_hex(public.to_encoded_point(compressed).as_bytes()),
+                EcKeyFormat::Pem => public.to_public_key_pem(LineEnding::LF)?,
+                EcKeyFormat::Der => to_hex(public.to_public_key_der()?.as_bytes()),
+            })
+        })
+    }
+
+    /// Signs the message hashed with the default digest of the curve. The nonce is derived with
+    /// RFC 6979, non deterministic signatures mix random bytes into it.
+    pub fn sign(
+        &self,
+        message: &[u8],
+        deterministic: bool,
+        encoding: EcSignatureEncoding,
+    ) -> Result<Vec<u8>> {
+        let secret = self.secret()?;
+
+        with_curve!(self.curve, c => {
+            let key = c::ecdsa::SigningKey::from_slice(secret)?;
+            let signature: c::ecdsa::Signature = match deterministic {
+                true => key.sign(message),
+                false => key.sign_with_rng(&mut rand::thread_rng(), message),
+            };
+
+            Ok(match encoding {
+                EcSignatureEncoding::Der => signature.to_der().as_bytes().to_vec(),
+                EcSignatureEncoding::Raw => signature.to_bytes().to_vec(),
+            })
+        })
+    }
+
+    pub fn verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        encoding: EcSignatureEncoding,
+    ) -> Result<bool> {
+        with_curve!(self.curve, c => {
+            let key = c::ecdsa::VerifyingKey::from_sec1_bytes(&self.public)?;
+            let signature = match encoding {
+                EcSignatureEncoding::Der => c::ecdsa::Signature::from_der(signature),
+                EcSignatureEncoding::Raw => c::ecdsa::Signature::from_slice(signature),
+            };
+            let Ok(signature) = signature else {
+                bail!("Invalid signature for {} curve.", self.curve);
+            };
+            // secp256k1 accepts only low S values, both S and -S are valid for ECDSA itself.
+            let signature = signature.normalize_s().unwrap_or(signature);
+
+            Ok(key.verify(message, &signature).is_ok())
+        })
+    }
+
+    pub fn diffie_hellman(&self, other: &EcKey) -> Result<Vec<u8>> {
+        let secret = self.secret()?;
+
+        with_curve!(self.curve, c => {
+            let secret = c::SecretKey::from_slice(secret)?;
+            let public = c::PublicKey::from_sec1_bytes(&other.public)?;
+            let shared = c::ecdh::diffie_hellman(secret.to_nonzero_scalar(), public.as_affine());
+
+            Ok(shared.raw_secret_bytes().to_vec())
+        })
+    }
+
+    fn secret(&self) -> Result<&[u8]> {
+        match &self.secret {
+            Some(secret) => Ok(secret),
+            None => bail!("Private key is required, got a public key."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::from_hex;
+
+    // RFC 6979 appendix A.2.5 and A.2.6 keys.
+    const P256_SECRET: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const P256_PUBLIC: &str = "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+    const P384_SECRET: &str = "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d896d5724e4c70a825f872c9ea60d2edf5";
+
+    // Signed by OpenSSL with `-pkeyopt nonce-type:1`, which is RFC 6979 too. OpenSSL keeps the
+    // high S value, k256 returns its low S form.
+    const SECP256K1_SECRET: &str =
+        "49a00979d0966e47af96d609beebe7371f98565919e3c9105e7fc44f78edcddd";
+
+    #[test]
+    fn rfc6979() {
+        for (curve, secret, message, signature) in [
+            (
+                EcCurve::P256,
+                P256_SECRET,
+                "sample",
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+                 f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+            ),
+            (
+                EcCurve::P256,
+                P256_SECRET,
+                "test",
+                "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+                 019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+            ),
+            (
+                EcCurve::P384,
+                P384_SECRET,
+                "sample",
+                "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe46\
+                 99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8",
+            ),
+            (
+                EcCurve::Secp256k1,
+                SECP256K1_SECRET,
+                "sample",
+                "6a8aa0c577c065a0ddf5eef0ce941d81b88e1f8123c17876fa0d52ae0a7d9015\
+                 2382515bcab222193af7be8947898029b82745cb74e7539c7f83ef6559597596",
+            ),
+        ] {
+            let key = EcKey::parse_private(secret, curve).unwrap();
+            let signed = key
+                .sign(message.as_bytes(), true, EcSignatureEncoding::Raw)
+                .unwrap();
+            assert_eq!(to_hex(&signed), signature, "{curve} {message}");
+            assert!(key
+                .verify(message.as_bytes(), &signed, EcSignatureEncoding::Raw)
+                .unwrap());
+            assert!(!key
+                .verify(b"tampered", &signed, EcSignatureEncoding::Raw)
+                .unwrap());
+
+            let der = key
+                .sign(message.as_bytes(), true, EcSignatureEncoding::Der)
+                .unwrap();
+            assert!(key
+                .verify(message.as_bytes(), &der, EcSignatureEncoding::Der)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn point_compression() {
+        let key = EcKey::parse_private(P256_SECRET, EcCurve::P256).unwrap();
+        assert_eq!(
+            key.encode_public(EcKeyFormat::Raw, false).unwrap(),
+            P256_PUBLIC
+        );
+
+        // The y coordinate is odd, so the compressed point starts with 03.
+        let compressed = key.encode_public(EcKeyFormat::Raw, true).unwrap();
+        assert_eq!(compressed, format!("03{}", &P256_PUBLIC[2..66]));
+        let parsed = EcKey::parse_public(&compressed, EcCurve::P256).unwrap();
+        assert_eq!(
+            parsed.encode_public(EcKeyFormat::Raw, false).unwrap(),
+            P256_PUBLIC
+        );
+
+        for curve in [EcCurve::P256, EcCurve::P384, EcCurve::Secp256k1] {
+            let key = EcKey::generate(curve);
+            let uncompressed = key.encode_public(EcKeyFormat::Raw, false).unwrap();
+            let compressed = key.encode_public(EcKeyFormat::Raw, true).unwrap();
+            assert_eq!(compressed.len(), uncompressed.len() / 2 + 1, "{curve}");
+            let parsed = EcKey::parse_public(&compressed, curve).unwrap();
+            assert_eq!(
+                parsed.encode_public(EcKeyFormat::Raw, false).unwrap(),
+                uncompressed
+            );
+        }
+
+        let mut off_curve = from_hex(P256_PUBLIC).unwrap();
+        off_curve[64] ^= 1;
+        assert_eq!(
+            EcKey::parse_public(&to_hex(&off_curve), EcCurve::P256)
+                .err()
+                .unwrap()
+                .to_string(),
+            "Point is not on P-256 curve."
+        );
+    }
+}
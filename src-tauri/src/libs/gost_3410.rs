@@ -0,0 +1,528 @@
+use anyhow::{bail, Result};
+use num::{BigUint, One, Zero};
+use rand::RngCore;
+use serde::Deserialize;
+use streebog::{Digest, Streebog256, Streebog512};
+
+use crate::utils::{from_hex, to_hex};
+
+/// Parameter sets from RFC 7836 and R 1323565.1.024-2019 plus the test sets from the examples
+/// of GOST R 34.10-2012. Twisted Edwards curves are used in their Weierstrass form.
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+pub enum GostCurve {
+    #[serde(rename = "id-GostR3410-2001-TestParamSet")]
+    Test256,
+    #[serde(rename = "id-tc26-gost-3410-2012-256-paramSetA")]
+    Tc26_256A,
+    #[serde(rename = "id-tc26-gost-3410-2012-256-paramSetB")]
+    Tc26_256B,
+    #[serde(rename = "id-tc26-gost-3410-2012-256-paramSetC")]
+    Tc26_256C,
+    #[serde(rename = "id-tc26-gost-3410-2012-256-paramSetD")]
+    Tc26_256D,
+    #[serde(rename = "id-tc26-gost-3410-2012-512-paramSetTest")]
+    Test512,
+    #[serde(rename = "id-tc26-gost-3410-2012-512-paramSetA")]
+    Tc26_512A,
+    #[serde(rename = "id-tc26-gost-3410-2012-512-paramSetB")]
+    Tc26_512B,
+    #[serde(rename = "id-tc26-gost-3410-2012-512-paramSetC")]
+    Tc26_512C,
+}
+
+/// p, a, b, q, x and y of the base point.
+type HexParams = [&'static str; 6];
+
+const TEST_256: HexParams = [
+    "8000000000000000000000000000000000000000000000000000000000000431",
+    "7",
+    "5fbff498aa938ce739b8e022fbafef40563f6e6a3472fc2a514c0ce9dae23b7e",
+    "8000000000000000000000000000000150fe8a1892976154c59cfc193accf5b3",
+    "2",
+    "08e2a8a0e65147d4bd6316030e16d19c85c97f0a9ca267122b96abbcea7e8fc8",
+];
+
+const TC26_256_A: HexParams = [
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd97",
+    "c2173f1513981673af4892c23035a27ce25e2013bf95aa33b22c656f277e7335",
+    "295f9bae7428ed9ccc20e7c359a9d41a22fccd9108e17bf7ba9337a6f8ae9513",
+    "400000000000000000000000000000000fd8cddfc87b6635c115af556c360c67",
+    "91e38443a5e82c0d880923425712b2bb658b9196932e02c78b2582fe742daa28",
+    "32879423ab1a0375895786c4bb46e9565fde0b5344766740af268adb32322e5c",
+];
+
+const TC26_256_B: HexParams = [
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd97",
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffd94",
+    "a6",
+    "ffffffffffffffffffffffffffffffff6c611070995ad10045841b09b761b893",
+    "1",
+    "8d91e471e0989cda27df505a453f2b7635294f2ddf23e3b122acc99c9e9f1e14",
+];
+
+const TC26_256_C: HexParams = [
+    "8000000000000000000000000000000000000000000000000000000000000c99",
+    "8000000000000000000000000000000000000000000000000000000000000c96",
+    "3e1af419a269a5f866a7d3c25c3df80ae979259373ff2b182f49d4ce7e1bbc8b",
+    "800000000000000000000000000000015f700cfff1a624e5e497161bcc8a198f",
+    "1",
+    "3fa8124359f96680b83d1c3eb2c070e5c545c9858d03ecfb744bf8d717717efc",
+];
+
+const TC26_256_D: HexParams = [
+    "9b9f605f5a858107ab1ec85e6b41c8aacf846e86789051d37998f7b9022d759b",
+    "9b9f605f5a858107ab1ec85e6b41c8aacf846e86789051d37998f7b9022d7598",
+    "805a",
+    "9b9f605f5a858107ab1ec85e6b41c8aa582ca3511eddfb74f02f3a6598980bb9",
+    "0",
+    "41ece55743711a8c3cbf3783cd08c0ee4d4dc440d4641a8f366e550dfdb3bb67",
+];
+
+const TEST_512: HexParams = [
+    "4531acd1fe0023c7550d267b6b2fee80922b14b2ffb90f04d4eb7c09b5d2d15df1d852741af4704a0458047e80e4546d35b8336fac224dd81664bbf528be6373",
+    "7",
+    "1cff0806a31116da29d8cfa54e57eb748bc5f377e49400fdd788b649eca1ac4361834013b2ad7322480a89ca58e0cf74bc9e540c2add6897fad0a3084f302adc",
+    "4531acd1fe0023c7550d267b6b2fee80922b14b2ffb90f04d4eb7c09b5d2d15da82f2d7ecb1dbac719905c5eecc423f1d86e25edbe23c595d644aaf187e6e6df",
+    "24d19cc64572ee30f396bf6ebbfd7a6c5213b3b3d7057cc825f91093a68cd762fd60611262cd838dc6b60aa7eee804e28bc849977fac33b4b530f1b120248a9a",
+    "2bb312a43bd2ce6e0d020613c857acddcfbf061e91e5f2c3f32447c259f39b2c83ab156d77f1496bf7eb3351e1ee4e43dc1a18b91b24640b6dbb92cb1add371e",
+];
+
+const TC26_512_A: HexParams = [
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffdc7",
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffdc4",
+    "e8c2505dedfc86ddc1bd0b2b6667f1da34b82574761cb0e879bd081cfd0b6265ee3cb090f30d27614cb4574010da90dd862ef9d4ebee4761503190785a71c760",
+    "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff27e69532f48d89116ff22b8d4e0560609b4b38abfad2b85dcacdb1411f10b275",
+    "3",
+    "7503cfe87a836ae3a61b8816e25450e6ce5e1c93acf1abc1778064fdcbefa921df1626be4fd036e93d75e6a50e3a41e98028fe5fc235f5b889a589cb5215f2a4",
+];
+
+const TC26_512_B: HexParams = [
+    "8000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006f",
+    "8000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006c",
+    "687d1b459dc841457e3e06cf6f5e2517b97c7d614af138bcbf85dc806c4b289f3e965d2db1416d217f8b276fad1ab69c50f78bee1fa3106efb8ccbc7c5140116",
+    "800000000000000000000000000000000000000000000000000000000000000149a1ec142565a545acfdb77bd9d40cfa8b996712101bea0ec6346c54374f25bd",
+    "2",
+    "1a8f7eda389b094c2c071e3647a8940f3c123b697578c213be6dd9e6c8ec7335dcb228fd1edf4a39152cbcaaf8c0398828041055f94ceeec7e21340780fe41bd",
+];
+
+const TC26_512_C: HexParams = [
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffdc7",
+    "dc9203e514a721875485a529d2c722fb187bc8980eb866644de41c68e143064546e861c0e2c9edd92ade71f46fcf50ff2ad97f951fda9f2a2eb6546f39689bd3",
+    "b4c4ee28cebc6c2c8ac12952cf37f16ac7efb6a9f69f4b57ffda2e4f0de5ade038cbc2fff719d2c18de0284b8bfef3b52b8cc7a5f5bf0a3c8d2319a5312557e1",
+    "3fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc98cdba46506ab004c33a9ff5147502cc8eda9e7a769a12694623cef47f023ed",
+    "e2e31edfc23de7bdebe241ce593ef5de2295b7a9cbaef021d385f7074cea043aa27272a7ae602bf2a7b9033db9ed3610c6fb85487eae97aac5bc7928c1950148",
+    "f5ce40d95b5eb899abbccff5911cb8577939804d6527378b8c108c3d2090ff9be18e2d33e3021ed2ef32d85822423b6304f726aa854bae07d0396e9a9addc40f",
+];
+
+type Point = Option<(BigUint, BigUint)>;
+type JacobianPoint = Option<(BigUint, BigUint, BigUint)>;
+
+struct Curve {
+    p: BigUint,
+    a: BigUint,
+    b: BigUint,
+    q: BigUint,
+    base: Point,
+    /// Length of a coordinate and of a signature half in bytes.
+    size: usize,
+}
+
+impl GostCurve {
+    fn params(&self) -> Curve {
+        let (hex, size) = match self {
+            Self::Test256 => (TEST_256, 32),
+            Self::Tc26_256A => (TC26_256_A, 32),
+            Self::Tc26_256B => (TC26_256_B, 32),
+            Self::Tc26_256C => (TC26_256_C, 32),
+            Self::Tc26_256D => (TC26_256_D, 32),
+            Self::Test512 => (TEST_512, 64),
+            Self::Tc26_512A => (TC26_512_A, 64),
+            Self::Tc26_512B => (TC26_512_B, 64),
+            Self::Tc26_512C => (TC26_512_C, 64),
+        };
+        let [p, a, b, q, x, y] = hex.map(|x| BigUint::parse_bytes(x.as_bytes(), 16).unwrap());
+
+        Curve {
+            p,
+            a,
+            b,
+            q,
+            base: Some((x, y)),
+            size,
+        }
+    }
+}
+
+impl Curve {
+    fn sub(&self, a: &BigUint, b: &BigUint) -> BigUint {
+        (a + &self.p - b % &self.p) % &self.p
+    }
+
+    fn inv(&self, a: &BigUint) -> BigUint {
+        a.modpow(&(&self.p - 2u32), &self.p)
+    }
+
+    fn add(&self, lhs: &Point, rhs: &Point) -> Point {
+        let (Some((x1, y1)), Some((x2, y2))) = (lhs, rhs) else {
+            return lhs.clone().or_else(|| rhs.clone());
+        };
+
+        let lambda = if x1 == x2 {
+            if (y1 + y2) % &self.p == BigUint::zero() {
+                return None;
+            }
+            (BigUint::from(3u32) * x1 * x1 + &self.a) * self.inv(&(y1 * 2u32)) % &self.p
+        } else {
+            self.sub(y2, y1) * self.inv(&self.sub(x2, x1)) % &self.p
+        };
+
+        let x3 = self.sub(&self.sub(&(&lambda * &lambda), x1), x2);
+        let y3 = self.sub(&(&lambda * self.sub(x1, &x3)), y1);
+
+        Some((x3, y3))
+    }
+
+    /// Double and add in Jacobian coordinates, so that only the result needs an inversion.
+    fn mul(&self, point: &Point, scalar: &BigUint) -> Point {
+        let Some((x, y)) = point else {
+            return None;
+        };
+
+        let mut result: JacobianPoint = None;
+        for i in (0..scalar.bits()).rev() {
+            result = self.double(&result);
+            if scalar.bit(i) {
+                result = self.add_affine(&result, x, y);
+            }
+        }
+
+        let (x, y, z) = result?;
+        let z_inv = self.inv(&z);
+        let z_inv2 = &z_inv * &z_inv % &self.p;
+
+        Some((x * &z_inv2 % &self.p, y * z_inv2 * z_inv % &self.p))
+    }
+
+    fn double(&self, point: &JacobianPoint) -> JacobianPoint {
+        let (x, y, z) = point.as_ref()?;
+        if y.is_zero() {
+            return None;
+        }
+
+        let p = &self.p;
+        let yy = y * y % p;
+        let zz = z * z % p;
+        let s = x * &yy * 4u32 % p;
+        let m = (x * x * 3u32 + &self.a * &zz * &zz) % p;
+        let x3 = self.sub(&(&m * &m), &(&s * 2u32));
+        let y3 = self.sub(&(&m * self.sub(&s, &x3)), &(&yy * &yy * 8u32));
+        let z3 = y * z * 2u32 % p;
+
+        Some((x3, y3, z3))
+    }
+
+    fn add_affine(&self, point: &JacobianPoint, x2: &BigUint, y2: &BigUint) -> JacobianPoint {
+        let Some((x1, y1, z1)) = point else {
+            return Some((x2.clone(), y2.clone(), BigUint::one()));
+        };
+
+        let p = &self.p;
+        let zz = z1 * z1 % p;
+        let h = self.sub(&(x2 * &zz), x1);
+        let r = self.sub(&(y2 * &zz * z1), y1);
+
+        if h.is_zero() {
+            return match r.is_zero() {
+                true => self.double(point),
+                false => None,
+            };
+        }
+
+        let hh = &h * &h % p;
+        let hhh = &h * &hh % p;
+        let v = x1 * &hh % p;
+        let x3 = self.sub(&self.sub(&(&r * &r), &hhh), &(&v * 2u32));
+        let y3 = self.sub(&(&r * self.sub(&v, &x3)), &(y1 * &hhh));
+        let z3 = z1 * h % p;
+
+        Some((x3, y3, z3))
+    }
+
+    fn random_scalar(&self) -> BigUint {
+        loop {
+            let mut bytes = vec![0; self.size];
+            rand::thread_rng().fill_bytes(&mut bytes);
+            let scalar = BigUint::from_bytes_be(&bytes) % &self.q;
+            if !scalar.is_zero() {
+                return scalar;
+            }
+        }
+    }
+
+    fn to_bytes(&self, value: &BigUint) -> Vec<u8> {
+        let bytes = value.to_bytes_be();
+        let mut out = vec![0; self.size - bytes.len()];
+        out.extend_from_slice(&bytes);
+        out
+    }
+}
+
+pub struct GostKey {
+    curve: GostCurve,
+    secret: Option<BigUint>,
+    public: (BigUint, BigUint),
+}
+
+impl GostKey {
+    pub fn generate(curve: GostCurve) -> Self {
+        Self::from_secret(curve, curve.params().random_scalar())
+    }
+
+    fn from_secret(curve: GostCurve, secret: BigUint) -> Self {
+        let params = curve.params();
+        let public = params.mul(&params.base, &secret).unwrap();
+
+        Self {
+            curve,
+            secret: Some(secret),
+            public,
+        }
+    }
+
+    /// Reads a hex encoded private key d.
+    pub fn parse_private(input: &str, curve: GostCurve) -> Result<Self> {
+        let params = curve.params();
+        let bytes = from_hex(input.trim())?;
+        let secret = BigUint::from_bytes_be(&bytes);
+
+        if bytes.len() > params.size || secret.is_zero() || secret >= params.q {
+            bail!("Private key must be a number between 1 and q - 1 of the curve.");
+        }
+
+        Ok(Self::from_secret(curve, secret))
+    }
+
+    /// Reads a hex encoded public key written as x and y concatenated, with an optional 04 prefix.
+    pub fn parse_public(input: &str, curve: GostCurve) -> Result<Self> {
+        let params = curve.params();
+        let mut bytes = from_hex(input.trim())?;
+
+        if bytes.len() == 2 * params.size + 1 && bytes[0] == 0x04 {
+            bytes.remove(0);
+        }
+        if bytes.len() != 2 * params.size {
+            bail!(
+                "Public key must be {} bytes long, got {} bytes.",
+                2 * params.size,
+                bytes.len()
+            );
+        }
+
+        let (x, y) = bytes.split_at(params.size);
+        let (x, y) = (BigUint::from_bytes_be(x), BigUint::from_bytes_be(y));
+        let rhs = (&x * &x * &x + &params.a * &x + &params.b) % &params.p;
+        if x >= params.p || y >= params.p || (&y * &y) % &params.p != rhs {
+            bail!("Public key point is not on the curve.");
+        }
+
+        Ok(Self {
+            curve,
+            secret: None,
+            public: (x, y),
+        })
+    }
+
+    pub fn encode_private(&self) -> Result<String> {
+        Ok(to_hex(&self.curve.params().to_bytes(self.secret()?)))
+    }
+
+    pub fn encode_public(&self) -> String {
+        let params = self.curve.params();
+        let (x, y) = &self.public;
+
+        to_hex(&[params.to_bytes(x), params.to_bytes(y)].concat())
+    }
+
+    /// Hashes the message with Streebog of the curve size, the hash is read as a little endian
+    /// number as the standard writes vectors with the most significant byte last.
+    pub fn digest(&self, message: &[u8]) -> BigUint {
+        let hash = match self.curve.params().size {
+            32 => Streebog256::digest(message).to_vec(),
+            _ => Streebog512::digest(message).to_vec(),
+        };
+
+        BigUint::from_bytes_le(&hash)
+    }
+
+    /// Returns s and r concatenated, the layout used by RFC 4491 and RFC 7091.
+    pub fn sign(&self, message: &[u8]) -> Result<Vec<u8>> {
+        let (r, s) = self.sign_digest(&self.digest(message))?;
+        let params = self.curve.params();
+
+        Ok([params.to_bytes(&s), params.to_bytes(&r)].concat())
+    }
+
+    pub fn sign_digest(&self, alpha: &BigUint) -> Result<(BigUint, BigUint)> {
+        let secret = self.secret()?;
+        let params = self.curve.params();
+        let e = Self::reduce_digest(alpha, &params.q);
+
+        loop {
+            let k = params.random_scalar();
+            if let Some(signature) = Self::signature(&params, secret, &e, &k) {
+                return Ok(signature);
+            }
+        }
+    }
+
+    /// Signs with the given k instead of a random one, the way the examples of the standard do.
+    #[cfg(test)]
+    fn sign_digest_with_k(&self, alpha: &BigUint, k: &BigUint) -> Result<(BigUint, BigUint)> {
+        let secret = self.secret()?;
+        let params = self.curve.params();
+        let e = Self::reduce_digest(alpha, &params.q);
+
+        if k.is_zero() || k >= &params.q {
+            bail!("k must be a number between 1 and q - 1 of the curve.");
+        }
+
+        match Self::signature(&params, secret, &e, k) {
+            Some(signature) => Ok(signature),
+            None => bail!("k gives a zero r or s, choose another k."),
+        }
+    }
+
+    fn signature(
+        params: &Curve,
+        secret: &BigUint,
+        e: &BigUint,
+        k: &BigUint,
+    ) -> Option<(BigUint, BigUint)> {
+        let (x, _) = params.mul(&params.base, k)?;
+        let r = x % &params.q;
+        let s = (&r * secret + k * e) % &params.q;
+
+        (!r.is_zero() && !s.is_zero()).then_some((r, s))
+    }
+
+    fn secret(&self) -> Result<&BigUint> {
+        match &self.secret {
+            Some(secret) => Ok(secret),
+            None => bail!("Private key is required, got a public key."),
+        }
+    }
+
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> Result<bool> {
+        let params = self.curve.params();
+        if signature.len() != 2 * params.size {
+            bail!(
+                "Signature must be {} bytes long, got {} bytes.",
+                2 * params.size,
+                signature.len()
+            );
+        }
+
+        let (s, r) = signature.split_at(params.size);
+        let (r, s) = (BigUint::from_bytes_be(r), BigUint::from_bytes_be(s));
+
+        Ok(self.verify_digest(&self.digest(message), &r, &s))
+    }
+
+    pub fn verify_digest(&self, alpha: &BigUint, r: &BigUint, s: &BigUint) -> bool {
+        let params = self.curve.params();
+        let q = &params.q;
+
+        if r.is_zero() || s.is_zero() || r >= q || s >= q {
+            return false;
+        }
+
+        let e = Self::reduce_digest(alpha, q);
+        let v = e.modpow(&(q - 2u32), q);
+        let z1 = s * &v % q;
+        let z2 = (q - r) * &v % q;
+
+        let public = Some(self.public.clone());
+        let point = params.add(&params.mul(&params.base, &z1), &params.mul(&public, &z2));
+
+        match point {
+            Some((x, _)) => &(x % q) == r,
+            None => false,
+        }
+    }
+
+    fn reduce_digest(alpha: &BigUint, q: &BigUint) -> BigUint {
+        match alpha % q {
+            e if e.is_zero() => BigUint::one(),
+            e => e,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn number(hex: &str) -> BigUint {
+        BigUint::parse_bytes(hex.as_bytes(), 16).unwrap()
+    }
+
+    /// Checks the key pair and the signature of an example of GOST R 34.10-2012, which are
+    /// repeated in RFC 7091, section 7.
+    fn check_example(curve: GostCurve, [d, x, y, e, k, r, s]: [&str; 7]) {
+        let key = GostKey::parse_private(d, curve).unwrap();
+        assert_eq!(key.public, (number(x), number(y)));
+        assert_eq!(key.encode_public(), format!("{x}{y}"));
+
+        let (e, k) = (number(e), number(k));
+        let signature = key.sign_digest_with_k(&e, &k).unwrap();
+        assert_eq!(signature, (number(r), number(s)));
+
+        let public = GostKey::parse_public(&key.encode_public(), curve).unwrap();
+        let (r, s) = signature;
+        assert!(public.verify_digest(&e, &r, &s));
+        assert!(!public.verify_digest(&e, &(&r + 1u32), &s));
+        assert!(!public.verify_digest(&(&e + 1u32), &r, &s));
+    }
+
+    #[test]
+    fn example_256() {
+        check_example(
+            GostCurve::Test256,
+            [
+                "7a929ade789bb9be10ed359dd39a72c11b60961f49397eee1d19ce9891ec3b28",
+                "7f2b49e270db6d90d8595bec458b50c58585ba1d4e9b788f6689dbd8e56fd80b",
+                "26f1b489d6701dd185c8413a977b3cbbaf64d1c593d26627dffb101a87ff77da",
+                "2dfbc1b372d89a1188c09c52e0eec61fce52032ab1022e8e67ece6672b043ee5",
+                "77105c9b20bcd3122823c8cf6fcc7b956de33814e95b7fe64fed924594dceab3",
+                "41aa28d2f1ab148280cd9ed56feda41974053554a42767b83ad043fd39dc0493",
+                "01456c64ba4642a1653c235a98a60249bcd6d3f746b631df928014f6c5bf9c40",
+            ],
+        );
+    }
+
+    #[test]
+    fn example_512() {
+        check_example(
+            GostCurve::Test512,
+            [
+                "0ba6048aadae241ba40936d47756d7c93091a0e8514669700ee7508e508b102072e8123b2200a0563322dad2827e2714a2636b7bfd18aadfc62967821fa18dd4",
+                "115dc5bc96760c7b48598d8ab9e740d4c4a85a65be33c1815b5c320c854621dd5a515856d13314af69bc5b924c8b4ddff75c45415c1d9dd9dd33612cd530efe1",
+                "37c7c90cd40b0f5621dc3ac1b751cfa0e2634fa0503b3d52639f5d7fb72afd61ea199441d943ffe7f0c70a2759a3cdb84c114e1f9339fdf27f35eca93677beec",
+                "3754f3cfacc9e0615c4f4a7c4d8dab531b09b6f9c170c533a71d147035b0c5917184ee536593f4414339976c647c5d5a407adedb1d560c4fc6777d2972075b8c",
+                "0359e7f4b1410feacc570456c6801496946312120b39d019d455986e364f365886748ed7a44b3e794434006011842286212273a6d14cf70ea3af71bb1ae679f1",
+                "2f86fa60a081091a23dd795e1e3c689ee512a3c82ee0dcc2643c78eea8fcacd35492558486b20f1c9ec197c90699850260c93bcbcd9c5c3317e19344e173ae36",
+                "1081b394696ffe8e6585e7a9362d26b6325f56778aadbc081c0bfbe933d52ff5823ce288e8c4f362526080df7f70ce406a6eeb1f56919cb92a9853bde73e5b4a",
+            ],
+        );
+    }
+
+    #[test]
+    fn signs_and_verifies_messages() {
+        for curve in [GostCurve::Tc26_256A, GostCurve::Tc26_512C] {
+            let key = GostKey::generate(curve);
+            let signature = key.sign(b"message").unwrap();
+            assert!(key.verify(b"message", &signature).unwrap());
+            assert!(!key.verify(b"massage", &signature).unwrap());
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! Hill cipher over an [`Alphabet`], the key is a square matrix modulo the alphabet length that
+//! multiplies blocks of the text written as column vectors.
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+
+use crate::{
+    libs::alphabet::Alphabet,
+    utils::{extended_gcd, get_char_by_index, mod_inv, modulus},
+};
+
+pub type Matrix = Vec<Vec<i64>>;
+
+/// Key matrix filled row by row with the indexes of the key letters, so a key of 9 letters gives
+/// a 3x3 matrix.
+pub fn key_matrix(lang: &Alphabet, key: &str) -> Result<Matrix> {
+    let Some(indexes) = key
+        .chars()
+        .map(|ch| lang.index_of(ch).map(|idx| idx as i64))
+        .collect::<Option<Vec<_>>>()
+    else {
+        bail!("Key must consist only of the alphabet characters.");
+    };
+
+    let size = (indexes.len() as f64).sqrt().round() as usize;
+    if size < 2 || size.checked_mul(size) != Some(indexes.len()) {
+        bail!("Key length must be a square number greater than 1, like 4 for a 2x2 matrix.");
+    }
+
+    Ok(indexes.chunks(size).map(<[i64]>::to_vec).collect())
+}
+
+/// Key letters of the matrix in upper case, row by row.
+pub fn matrix_key(lang: &Alphabet, matrix: &Matrix) -> String {
+    matrix
+        .iter()
+        .flatten()
+        .map(|&idx| get_char_by_index(lang.upper(), idx))
+        .collect()
+}
+
+/// Determinant modulo `module`. The alphabet length is not always prime, so instead of dividing
+/// by the pivot the rows are reduced against each other like in the Euclidean algorithm.
+pub fn determinant(matrix: &Matrix, module: i64) -> i64 {
+    let size = matrix.len();
+    let mut rows: Matrix = matrix
+        .iter()
+        .map(|row| row.iter().map(|&x| modulus(x, module)).collect())
+        .collect();
+    let mut det = 1;
+
+    for col in 0..size {
+        for row in col + 1..size {
+            while rows[row][col] != 0 {
+                let quotient = rows[col][col] / rows[row][col];
+                let (upper, lower) = rows.split_at_mut(row);
+                for (x, y) in upper[col][col..].iter_mut().zip(&lower[0][col..]) {
+                    *x = modulus(*x - quotient * y, module);
+                }
+                rows.swap(col, row);
+                det = -det;
+            }
+        }
+        det = modulus(det * rows[col][col], module);
+    }
+
+    det
+}
+
+/// Inverse matrix modulo `module`, it is the adjugate matrix divided by the determinant.
+pub fn inverse(matrix: &Matrix, module: i64) -> Result<Matrix> {
+    let size = matrix.len();
+    let det = determinant(matrix, module);
+    let (gcd, _, _) = extended_gcd(det, module);
+    if gcd != 1 {
+        bail!(
+            "The key matrix is not invertible, its determinant {} is not coprime to the alphabet length ({}).",
+            det,
+            module
+        );
+    }
+    let det_inv = mod_inv(det, module);
+
+    let minor = |skip_row: usize, skip_col: usize| -> Matrix {
+        (0..size)
+            .filter(|&row| row != skip_row)
+            .map(|row| {
+                (0..size)
+                    .filter(|&col| col != skip_col)
+                    .map(|col| matrix[row][col])
+                    .collect()
+            })
+            .collect()
+    };
+
+    Ok((0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| {
+                    let sign = if (row + col) % 2 == 0 { 1 } else { -1 };
+                    modulus(
+                        sign * det_inv * determinant(&minor(col, row), module),
+                        module,
+                    )
+                })
+                .collect()
+        })
+        .collect())
+}
+
+fn multiply(matrix: &Matrix, block: &[i64], module: i64) -> Vec<i64> {
+    matrix
+        .iter()
+        .map(|row| modulus(row.iter().zip(block).map(|(a, b)| a * b).sum(), module))
+        .collect()
+}
+
+fn product(a: &Matrix, b: &Matrix, module: i64) -> Matrix {
+    a.iter()
+        .map(|row| {
+            (0..b[0].len())
+                .map(|col| modulus(row.iter().zip(b).map(|(x, y)| x * y[col]).sum(), module))
+                .collect()
+        })
+        .collect()
+}
+
+/// Indexes of the alphabet characters of the text, other characters are skipped.
+fn symbol_indexes(lang: &Alphabet, text: &str) -> Vec<i64> {
+    text.chars()
+        .filter_map(|ch| lang.index_of(ch).map(|idx| idx as i64))
+        .collect()
+}
+
+/// Multiplies the blocks of the alphabet characters by the matrix keeping their case, other
+/// characters stay in place. An incomplete last block is completed with the filler when it is
+/// given, otherwise it is an error.
+pub fn hill_cipher(
+    input: &str,
+    lang: &Alphabet,
+    matrix: &Matrix,
+    filler: Option<char>,
+) -> Result<String> {
+    let size = matrix.len();
+    let module = lang.length() as i64;
+    let mut indexes = symbol_indexes(lang, input);
+
+    let padding = (size - indexes.len() % size) % size;
+    match filler {
+        Some(filler) => {
+            let Some(idx) = lang.index_of(filler) else {
+                bail!("The filler must be a character of the alphabet.");
+            };
+            indexes.extend(std::iter::repeat(idx as i64).take(padding));
+        }
+        None if padding != 0 => {
+            bail!("The number of the alphabet characters must be a multiple of the key size.")
+        }
+        None => {}
+    }
+
+    let mut output = indexes
+        .chunks(size)
+        .flat_map(|block| multiply(matrix, block, module))
+        .map(|idx| idx as usize);
+
+    let mut text: String = input
+        .chars()
+        .map(|ch| match lang.contains(ch) {
+            true if ch.is_lowercase() => get_char_by_index(lang.lower(), output.next().unwrap()),
+            true => get_char_by_index(lang.upper(), output.next().unwrap()),
+            false => ch,
+        })
+        .collect();
+    if let Some(filler) = filler {
+        text.extend(output.map(|idx| match filler.is_lowercase() {
+            true => get_char_by_index(lang.lower(), idx),
+            false => get_char_by_index(lang.upper(), idx),
+        }));
+    }
+
+    Ok(text)
+}
+
+/// Most sets of blocks give an invertible matrix, so only this many of them are tried.
+const MAX_BLOCK_SETS: usize = 10_000;
+
+/// Recovers the key of the given size from a known plaintext and its ciphertext. Any `size`
+/// distinct plaintext blocks whose matrix is invertible give the key as C * P^-1, where the
+/// blocks are the columns of P and C, and the key is then checked against the other blocks.
+pub fn recover_key(
+    lang: &Alphabet,
+    plaintext: &str,
+    ciphertext: &str,
+    size: usize,
+) -> Result<Matrix> {
+    if size < 2 {
+        bail!("Key size must be at least 2.");
+    }
+
+    let module = lang.length() as i64;
+    let plain = symbol_indexes(lang, plaintext);
+    let cipher = symbol_indexes(lang, ciphertext);
+    if cipher.len() < plain.len() {
+        bail!("The ciphertext must be at least as long as the plaintext.");
+    }
+    if size > plain.len() || plain.len() < size * size {
+        bail!(
+            "The plaintext must have at least {} alphabet characters for the key size {}.",
+            size as u128 * size as u128,
+            size
+        );
+    }
+
+    let blocks: Vec<(&[i64], &[i64])> = plain
+        .chunks_exact(size)
+        .zip(cipher.chunks_exact(size))
+        .collect();
+
+    let columns = |blocks: &[&[i64]]| -> Matrix {
+        (0..size)
+            .map(|row| blocks.iter().map(|block| block[row]).collect())
+            .collect()
+    };
+
+    let key = blocks
+        .iter()
+        .unique_by(|(plain, _)| *plain)
+        .combinations(size)
+        .take(MAX_BLOCK_SETS)
+        .find_map(|set| {
+            let plain = columns(&set.iter().map(|(plain, _)| *plain).collect::<Vec<_>>());
+            let cipher = columns(&set.iter().map(|(_, cipher)| *cipher).collect::<Vec<_>>());
+            let plain_inv = inverse(&plain, module).ok()?;
+            Some(product(&cipher, &plain_inv, module))
+        });
+
+    let Some(key) = key else {
+        bail!(
+            "The plaintext blocks don't give an invertible matrix, a longer plaintext is needed."
+        );
+    };
+    if blocks
+        .iter()
+        .any(|(plain, cipher)| multiply(&key, plain, module) != *cipher)
+    {
+        bail!(
+            "The plaintext and the ciphertext don't match any key of size {}.",
+            size
+        );
+    }
+
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn en() -> Alphabet {
+        Alphabet::new("en").unwrap()
+    }
+
+    #[test]
+    fn key_of_nine_letters() {
+        let lang = en();
+        let key = key_matrix(&lang, "GYBNQKURP").unwrap();
+        assert_eq!(key, [[6, 24, 1], [13, 16, 10], [20, 17, 15]]);
+        assert_eq!(hill_cipher("ACT", &lang, &key, None).unwrap(), "POH");
+        assert_eq!(hill_cipher("CAT", &lang, &key, None).unwrap(), "FIN");
+
+        let inverse = inverse(&key, 26).unwrap();
+        assert_eq!(inverse, [[8, 5, 10], [21, 8, 21], [21, 12, 8]]);
+        assert_eq!(matrix_key(&lang, &inverse), "IFKVIVVMI");
+        assert_eq!(hill_cipher("POH", &lang, &inverse, None).unwrap(), "ACT");
+    }
+
+    #[test]
+    fn key_of_four_letters() {
+        let lang = en();
+        let key = key_matrix(&lang, "HILL").unwrap();
+        assert_eq!(
+            hill_cipher("short example", &lang, &key, None).unwrap(),
+            "apadj tftwlfj"
+        );
+        assert_eq!(
+            recover_key(&lang, "short example", "apadj tftwlfj", 2).unwrap(),
+            key
+        );
+    }
+
+    #[test]
+    fn huge_key_size() {
+        assert_eq!(
+            recover_key(&en(), "short example", "apadj tftwlfj", usize::MAX)
+                .unwrap_err()
+                .to_string(),
+            format!(
+                "The plaintext must have at least {} alphabet characters for the key size {}.",
+                usize::MAX as u128 * usize::MAX as u128,
+                usize::MAX
+            )
+        );
+        assert!(recover_key(&en(), "short example", "apadj tftwlfj", 4).is_err());
+    }
+
+    #[test]
+    fn determinant_modulo() {
+        let matrix = vec![vec![3, 5, 7], vec![2, 4, 6], vec![1, 8, 9]];
+        assert_eq!(determinant(&matrix, 26), 14);
+        assert_eq!(determinant(&matrix, 33), 21);
+        assert_eq!(determinant(&vec![vec![1, 2], vec![3, 4]], 26), 24);
+        assert_eq!(
+            inverse(&key_matrix(&en(), "GOOD").unwrap(), 26)
+                .unwrap_err()
+                .to_string(),
+            "The key matrix is not invertible, its determinant 4 is not coprime to the alphabet length (26)."
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for (name, key, input) in [
+            (
+                "en",
+                "GYBNQKURP",
+                "Attack at Dawn, 42 men! The Quick brown fox jumps.",
+            ),
+            (
+                "ru_with_yo",
+                "шифр",
+                "Съешь же ещё этих мягких французских булок!",
+            ),
+            ("uk", "воля", "Їжак і ґудзик, 42 рази!"),
+            ("de", "öfen", "Grüße aus Köln!"),
+            ("αβγδεζηθικλμνξοπρστυφχψω", "χρυσ", "Καλημέρα κόσμε"),
+        ] {
+            let lang = Alphabet::new(name).unwrap();
+            let filler = lang.lower().chars().last().unwrap();
+            let key = key_matrix(&lang, key).unwrap();
+            let inverse = inverse(&key, lang.length() as i64).unwrap();
+
+            let encoded = hill_cipher(input, &lang, &key, Some(filler)).unwrap();
+            assert_ne!(encoded, input);
+            let decoded = hill_cipher(&encoded, &lang, &inverse, None).unwrap();
+            assert_eq!(decoded.trim_end_matches(filler), input);
+
+            let size = key.len();
+            assert_eq!(recover_key(&lang, input, &encoded, size).unwrap(), key);
+        }
+    }
+}
@@ -1,2 +0,0 @@
-pub mod akrypt;
-pub mod structs;
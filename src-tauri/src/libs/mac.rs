@@ -0,0 +1,16 @@
+use anyhow::Result;
+use hmac::{digest::KeyInit, Mac};
+
+/// Computes a MAC of `message`, `M` is usually `Hmac<D>` for some digest `D`.
+pub fn hmac<M: Mac + KeyInit>(key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = <M as Mac>::new_from_slice(key)?;
+    mac.update(message);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// Checks `signature` against the MAC of `message` in constant time.
+pub fn verify_hmac<M: Mac + KeyInit>(key: &[u8], message: &[u8], signature: &[u8]) -> Result<bool> {
+    let mut mac = <M as Mac>::new_from_slice(key)?;
+    mac.update(message);
+    Ok(mac.verify_slice(signature).is_ok())
+}
@@ -1,8 +1,24 @@
 pub mod vigenere_trait;
 
+pub mod alphabet;
+pub mod asn1;
 pub mod bacon;
 pub mod base64;
 pub mod bitwise_op;
+pub mod block_cipher;
+pub mod cipher_machines;
 pub mod ciphers;
-
-pub mod libakrypt;
+pub mod cryptanalysis;
+pub mod curve25519_key;
+pub mod ec_key;
+pub mod gost_3410;
+pub mod hill;
+pub mod jwt;
+pub mod mac;
+pub mod openpgp;
+pub mod openssh;
+pub mod rc4;
+pub mod rsa_key;
+pub mod rsa_padding;
+pub mod transposition;
+pub mod x509;
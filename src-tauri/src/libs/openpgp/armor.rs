@@ -0,0 +1,153 @@
+use anyhow::{bail, Result};
+
+use crate::{
+    libs::base64::{from_base64, to_base64},
+    utils::decode_der,
+};
+
+pub const PUBLIC_KEY_BLOCK: &str = "PUBLIC KEY BLOCK";
+pub const PRIVATE_KEY_BLOCK: &str = "PRIVATE KEY BLOCK";
+pub const MESSAGE: &str = "MESSAGE";
+pub const SIGNATURE: &str = "SIGNATURE";
+
+const SIGNED_MESSAGE_HEADER: &str = "-----BEGIN PGP SIGNED MESSAGE-----";
+const SIGNATURE_HEADER: &str = "-----BEGIN PGP SIGNATURE-----";
+
+pub fn armor(kind: &str, data: &[u8]) -> String {
+    let body = to_base64(data);
+    let lines: Vec<&str> = body
+        .as_bytes()
+        .chunks(64)
+        .map(|line| std::str::from_utf8(line).unwrap_or_default())
+        .collect();
+    let checksum = to_base64(&crc24(data).to_be_bytes()[1..]);
+
+    format!(
+        "-----BEGIN PGP {kind}-----\n\n{}\n={checksum}\n-----END PGP {kind}-----",
+        lines.join("\n")
+    )
+}
+
+/// Reads the first armored block and returns its kind, e.g. "MESSAGE". Input without armor is
+/// read as binary data written in hex or base64.
+pub fn dearmor(input: &str) -> Result<(Option<String>, Vec<u8>)> {
+    let input = input.trim();
+    let Some(start) = input.find("-----BEGIN PGP ") else {
+        return Ok((None, decode_der(input)?));
+    };
+
+    let mut lines = input[start..].lines().map(str::trim_end);
+    let header = lines.next().unwrap_or_default();
+    let Some(kind) = header
+        .strip_prefix("-----BEGIN PGP ")
+        .and_then(|rest| rest.strip_suffix("-----"))
+    else {
+        bail!("Invalid armor header line.");
+    };
+
+    // Armor headers such as "Version" or "Comment" end with an empty line.
+    for line in lines.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+        if !line.contains(": ") {
+            bail!("Invalid armor header \"{line}\".");
+        }
+    }
+
+    let mut body = String::new();
+    let mut checksum = None;
+    let mut terminated = false;
+    for line in lines {
+        if line.starts_with("-----END PGP ") {
+            terminated = true;
+            break;
+        }
+        match line.strip_prefix('=') {
+            Some(crc) if line.len() == 5 => checksum = Some(crc.to_string()),
+            _ => body.push_str(line.trim()),
+        }
+    }
+    if !terminated {
+        bail!("Armor tail line is missing.");
+    }
+
+    let Ok(data) = from_base64(&body) else {
+        bail!("Invalid base64 in armored data.");
+    };
+    if let Some(checksum) = checksum {
+        if from_base64(&checksum).ok().as_deref() != Some(&crc24(&data).to_be_bytes()[1..]) {
+            bail!("Armor checksum mismatch.");
+        }
+    }
+
+    Ok((Some(kind.to_string()), data))
+}
+
+pub fn is_cleartext_signed(input: &str) -> bool {
+    input.trim_start().starts_with(SIGNED_MESSAGE_HEADER)
+}
+
+/// Splits a cleartext signed message into the signed text and the armored signature. Dash
+/// escaping is removed and lines are joined with CRLF as they are hashed.
+pub fn read_cleartext(input: &str) -> Result<(String, String)> {
+    let input = input.trim_start();
+    let Some(end) = input.find(SIGNATURE_HEADER) else {
+        bail!("Cleartext signed message has no signature.");
+    };
+
+    let mut lines = input[..end].lines().skip(1);
+    for line in lines.by_ref() {
+        if line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let text: Vec<&str> = lines
+        .map(|line| {
+            let line = line.strip_prefix("- ").unwrap_or(line);
+            line.trim_end_matches([' ', '\t', '\r'])
+        })
+        .collect();
+
+    Ok((text.join("\r\n"), input[end..].to_string()))
+}
+
+pub fn write_cleartext(text: &str, hash: &str, signature: &[u8]) -> String {
+    let escaped: Vec<String> = text
+        .lines()
+        .map(|line| match line.starts_with('-') {
+            true => format!("- {line}"),
+            false => line.to_string(),
+        })
+        .collect();
+
+    format!(
+        "{SIGNED_MESSAGE_HEADER}\nHash: {hash}\n\n{}\n{}",
+        escaped.join("\n"),
+        armor(SIGNATURE, signature)
+    )
+}
+
+/// Canonical form of a text hashed by signatures: trailing whitespace removed and lines joined
+/// with CRLF.
+pub fn canonical_text(text: &str) -> String {
+    text.lines()
+        .map(|line| line.trim_end_matches([' ', '\t', '\r']))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc = 0xb704ce_u32;
+    for &byte in data {
+        crc ^= (byte as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x1000000 != 0 {
+                crc ^= 0x1864cfb;
+            }
+        }
+    }
+    crc & 0xffffff
+}
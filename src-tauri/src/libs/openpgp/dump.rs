@@ -0,0 +1,121 @@
+use anyhow::Result;
+
+use super::{
+    compression_algorithm_name, format_time, hash_algorithm_name,
+    key::PublicKey,
+    message::decompress,
+    packet::{self, packet_name, read_packets, Reader},
+    public_key_algorithm_name,
+    s2k::S2k,
+    signature::{signature_type_name, Signature},
+    symmetric_algorithm_name, upper_hex,
+};
+
+/// Lists packets with their main fields, compressed data is listed nested.
+pub fn dump(data: &[u8]) -> Result<String> {
+    let mut lines = Vec::new();
+    dump_packets(data, 0, &mut lines)?;
+    Ok(lines.join("\n"))
+}
+
+fn dump_packets(data: &[u8], depth: usize, lines: &mut Vec<String>) -> Result<()> {
+    let indent = "    ".repeat(depth);
+
+    for packet in read_packets(data)? {
+        lines.push(format!(
+            "{indent}{} (tag {}), {} bytes",
+            packet_name(packet.tag),
+            packet.tag,
+            packet.body.len()
+        ));
+        let fields = match describe(packet.tag, &packet.body) {
+            Ok(fields) => fields,
+            Err(e) => vec![format!("Error: {e}")],
+        };
+        lines.extend(fields.iter().map(|field| format!("{indent}    {field}")));
+
+        if packet.tag == packet::COMPRESSED_DATA {
+            dump_packets(&decompress(&packet.body)?, depth + 1, lines)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn describe(tag: u8, body: &[u8]) -> Result<Vec<String>> {
+    let mut reader = Reader::new(body);
+
+    Ok(match tag {
+        packet::PUBLIC_KEY | packet::PUBLIC_SUBKEY | packet::SECRET_KEY | packet::SECRET_SUBKEY => {
+            let (key, _) = PublicKey::parse(body)?;
+            vec![
+                format!("Algorithm: {}", key.describe()),
+                format!("Created: {}", format_time(key.created)),
+                format!("Key ID: {}", upper_hex(&key.key_id())),
+                format!("Fingerprint: {}", upper_hex(&key.fingerprint())),
+            ]
+        }
+        packet::USER_ID => vec![format!("User ID: {}", String::from_utf8_lossy(body))],
+        packet::SIGNATURE => Signature::parse(body)?
+            .describe()
+            .lines()
+            .map(str::to_string)
+            .collect(),
+        packet::ONE_PASS_SIGNATURE => {
+            reader.u8()?;
+            let sig_type = reader.u8()?;
+            let hash = reader.u8()?;
+            let algorithm = reader.u8()?;
+            vec![
+                format!(
+                    "Signature type: {} (0x{sig_type:02x})",
+                    signature_type_name(sig_type)
+                ),
+                format!("Hash algorithm: {}", hash_algorithm_name(hash)),
+                format!(
+                    "Public key algorithm: {}",
+                    public_key_algorithm_name(algorithm)
+                ),
+                format!("Issuer: {}", upper_hex(reader.take(8)?)),
+            ]
+        }
+        packet::PUBLIC_KEY_ENCRYPTED_SESSION_KEY => {
+            reader.u8()?;
+            let key_id = upper_hex(reader.take(8)?);
+            vec![
+                format!("Key ID: {key_id}"),
+                format!(
+                    "Public key algorithm: {}",
+                    public_key_algorithm_name(reader.u8()?)
+                ),
+            ]
+        }
+        packet::SYMMETRIC_KEY_ENCRYPTED_SESSION_KEY => {
+            reader.u8()?;
+            let algorithm = symmetric_algorithm_name(reader.u8()?);
+            vec![
+                format!("Symmetric algorithm: {algorithm}"),
+                format!("S2K: {}", S2k::read(&mut reader)?.describe()),
+            ]
+        }
+        packet::COMPRESSED_DATA => vec![format!(
+            "Compression algorithm: {}",
+            compression_algorithm_name(reader.u8()?)
+        )],
+        packet::LITERAL_DATA => {
+            let format = reader.u8()? as char;
+            let name = String::from_utf8_lossy(reader.short_field()?).to_string();
+            let date = reader.u32()?;
+            vec![
+                format!("Format: {format}"),
+                format!("File name: {name}"),
+                format!("Date: {}", format_time(date)),
+                format!("Data: {} bytes", reader.remaining()),
+            ]
+        }
+        packet::SYMMETRICALLY_ENCRYPTED_INTEGRITY_PROTECTED_DATA => {
+            vec![format!("Version: {}", reader.u8()?)]
+        }
+        _ => Vec::new(),
+    })
+}
@@ -0,0 +1,815 @@
+use anyhow::{bail, Result};
+use ed25519_dalek::SigningKey;
+use rsa::{traits::PrivateKeyParts, traits::PublicKeyParts, BigUint, RsaPrivateKey};
+use serde::Deserialize;
+use sha1::{Digest, Sha1};
+use x25519_dalek::{PublicKey as X25519PublicKey, StaticSecret};
+
+use super::{
+    armor::dearmor,
+    block_size, cfb_decrypt, cfb_encrypt, checksum16, curve_name, format_time, new_cipher, now,
+    packet::{self, read_packets, write_mpi, write_packet, Reader},
+    public_key_algorithm_name,
+    s2k::S2k,
+    signature::{subpacket, Signature},
+    symmetric_key_size, upper_hex, AES128, AES256, CURVE25519_OID, ECDH, ED25519_OID, EDDSA, RSA,
+    RSA_ENCRYPT_ONLY, RSA_SIGN_ONLY, SHA256,
+};
+
+const UNPROTECTED: u8 = 0;
+const CHECKSUM_PROTECTED: u8 = 255;
+const SHA1_PROTECTED: u8 = 254;
+
+const CERTIFY: u8 = 0x01;
+const SIGN: u8 = 0x02;
+const ENCRYPT_COMMUNICATIONS: u8 = 0x04;
+const ENCRYPT_STORAGE: u8 = 0x08;
+
+const POSITIVE_CERTIFICATION: u8 = 0x13;
+const SUBKEY_BINDING: u8 = 0x18;
+
+const UNLOCK_ERROR: &str = "Unable to unlock the private key, the passphrase is probably wrong.";
+
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum PgpKeyType {
+    Ed25519,
+    Rsa2048,
+    Rsa3072,
+    Rsa4096,
+}
+
+pub enum PublicMaterial {
+    Rsa {
+        n: BigUint,
+        e: BigUint,
+    },
+    Ed25519([u8; 32]),
+    Cv25519 {
+        public: [u8; 32],
+        kdf_hash: u8,
+        kdf_cipher: u8,
+    },
+    /// Keys that are parsed and listed but can't be used, e.g. DSA, Elgamal or ECDSA.
+    Unsupported {
+        curve: Option<Vec<u8>>,
+    },
+}
+
+pub enum SecretMaterial {
+    Rsa(Box<RsaPrivateKey>),
+    Ed25519([u8; 32]),
+    /// X25519 scalar in its native little-endian form, OpenPGP stores it reversed.
+    Cv25519([u8; 32]),
+}
+
+pub struct PublicKey {
+    pub created: u32,
+    pub algorithm: u8,
+    pub material: PublicMaterial,
+    body: Vec<u8>,
+}
+
+impl PublicKey {
+    fn new(created: u32, algorithm: u8, material: PublicMaterial) -> Self {
+        let mut body = vec![4];
+        body.extend_from_slice(&created.to_be_bytes());
+        body.push(algorithm);
+
+        match &material {
+            PublicMaterial::Rsa { n, e } => {
+                write_mpi(&mut body, &n.to_bytes_be());
+                write_mpi(&mut body, &e.to_bytes_be());
+            }
+            PublicMaterial::Ed25519(public) => {
+                write_curve_point(&mut body, ED25519_OID, public);
+            }
+            PublicMaterial::Cv25519 {
+                public,
+                kdf_hash,
+                kdf_cipher,
+            } => {
+                write_curve_point(&mut body, CURVE25519_OID, public);
+                body.extend_from_slice(&[3, 1, *kdf_hash, *kdf_cipher]);
+            }
+            PublicMaterial::Unsupported { .. } => {}
+        }
+
+        Self {
+            created,
+            algorithm,
+            material,
+            body,
+        }
+    }
+
+    /// Reads the public part of a key packet and returns how many bytes it takes.
+    pub fn parse(data: &[u8]) -> Result<(Self, usize)> {
+        let mut reader = Reader::new(data);
+
+        let version = reader.u8()?;
+        if version != 4 {
+            bail!("Unsupported key version {version}, only version 4 keys are supported.");
+        }
+        let created = reader.u32()?;
+        let algorithm = reader.u8()?;
+
+        let material = match algorithm {
+            RSA | RSA_ENCRYPT_ONLY | RSA_SIGN_ONLY => PublicMaterial::Rsa {
+                n: BigUint::from_bytes_be(reader.mpi()?),
+                e: BigUint::from_bytes_be(reader.mpi()?),
+            },
+            EDDSA => {
+                let oid = reader.short_field()?;
+                match (oid, native_point(reader.mpi()?)) {
+                    (ED25519_OID, Some(public)) => PublicMaterial::Ed25519(public),
+                    _ => PublicMaterial::Unsupported {
+                        curve: Some(oid.to_vec()),
+                    },
+                }
+            }
+            ECDH => {
+                let oid = reader.short_field()?;
+                let point = native_point(reader.mpi()?);
+                let kdf = reader.short_field()?;
+                match (oid, point, kdf) {
+                    (CURVE25519_OID, Some(public), [1, kdf_hash, kdf_cipher]) => {
+                        PublicMaterial::Cv25519 {
+                            public,
+                            kdf_hash: *kdf_hash,
+                            kdf_cipher: *kdf_cipher,
+                        }
+                    }
+                    _ => PublicMaterial::Unsupported {
+                        curve: Some(oid.to_vec()),
+                    },
+                }
+            }
+            19 => {
+                let oid = reader.short_field()?;
+                reader.mpi()?;
+                PublicMaterial::Unsupported {
+                    curve: Some(oid.to_vec()),
+                }
+            }
+            16 | 17 => {
+                let count = if algorithm == 16 { 3 } else { 4 };
+                for _ in 0..count {
+                    reader.mpi()?;
+                }
+                PublicMaterial::Unsupported { curve: None }
+            }
+            _ => bail!("Unsupported public key algorithm {algorithm}."),
+        };
+
+        let length = reader.position();
+        Ok((
+            Self {
+                created,
+                algorithm,
+                material,
+                body: data[..length].to_vec(),
+            },
+            length,
+        ))
+    }
+
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    pub fn fingerprint(&self) -> [u8; 20] {
+        Sha1::digest(self.hash_prefix()).into()
+    }
+
+    pub fn key_id(&self) -> [u8; 8] {
+        let fingerprint = self.fingerprint();
+        let mut key_id = [0; 8];
+        key_id.copy_from_slice(&fingerprint[12..]);
+        key_id
+    }
+
+    /// The key as it is hashed by fingerprints and key signatures.
+    pub fn hash_prefix(&self) -> Vec<u8> {
+        let mut prefix = vec![0x99];
+        prefix.extend_from_slice(&(self.body.len() as u16).to_be_bytes());
+        prefix.extend_from_slice(&self.body);
+        prefix
+    }
+
+    pub fn can_sign(&self) -> bool {
+        matches!(self.algorithm, RSA | RSA_SIGN_ONLY | EDDSA)
+    }
+
+    pub fn can_encrypt(&self) -> bool {
+        matches!(self.algorithm, RSA | RSA_ENCRYPT_ONLY | ECDH)
+    }
+
+    pub fn describe(&self) -> String {
+        let algorithm = public_key_algorithm_name(self.algorithm);
+        match &self.material {
+            PublicMaterial::Rsa { n, .. } => format!("{algorithm} {} bits", n.bits()),
+            PublicMaterial::Ed25519(_) => format!("{algorithm} {}", curve_name(ED25519_OID)),
+            PublicMaterial::Cv25519 { .. } => format!("{algorithm} {}", curve_name(CURVE25519_OID)),
+            PublicMaterial::Unsupported { curve: Some(oid) } => {
+                format!("{algorithm} {}", curve_name(oid))
+            }
+            PublicMaterial::Unsupported { curve: None } => algorithm.to_string(),
+        }
+    }
+}
+
+enum SecretPart {
+    /// Secret MPIs without checksum.
+    Plain(Vec<u8>),
+    Protected {
+        usage: u8,
+        cipher: u8,
+        s2k: S2k,
+        iv: Vec<u8>,
+        data: Vec<u8>,
+    },
+}
+
+pub struct Key {
+    pub public: PublicKey,
+    secret: Option<SecretPart>,
+}
+
+impl Key {
+    fn parse_public(body: &[u8]) -> Result<Self> {
+        Ok(Self {
+            public: PublicKey::parse(body)?.0,
+            secret: None,
+        })
+    }
+
+    fn parse_secret(body: &[u8]) -> Result<Self> {
+        let (public, length) = PublicKey::parse(body)?;
+        let mut reader = Reader::new(&body[length..]);
+
+        let secret = match reader.u8()? {
+            UNPROTECTED => {
+                let data = reader.rest();
+                if data.len() < 2 {
+                    bail!("Unexpected end of OpenPGP packet.");
+                }
+                let (mpis, checksum) = data.split_at(data.len() - 2);
+                if checksum16(mpis) != checksum {
+                    bail!("Secret key checksum mismatch.");
+                }
+                SecretPart::Plain(mpis.to_vec())
+            }
+            usage @ (SHA1_PROTECTED | CHECKSUM_PROTECTED) => {
+                let cipher = reader.u8()?;
+                let s2k = S2k::read(&mut reader)?;
+                let iv = reader.take(block_size(cipher)?)?.to_vec();
+                SecretPart::Protected {
+                    usage,
+                    cipher,
+                    s2k,
+                    iv,
+                    data: reader.rest().to_vec(),
+                }
+            }
+            usage => bail!("Unsupported secret key protection {usage}."),
+        };
+
+        Ok(Self {
+            public,
+            secret: Some(secret),
+        })
+    }
+
+    pub fn has_secret(&self) -> bool {
+        self.secret.is_some()
+    }
+
+    pub fn is_protected(&self) -> bool {
+        matches!(self.secret, Some(SecretPart::Protected { .. }))
+    }
+
+    pub fn protection(&self) -> Option<String> {
+        match &self.secret {
+            Some(SecretPart::Protected { cipher, s2k, .. }) => Some(format!(
+                "{}, S2K {}",
+                super::symmetric_algorithm_name(*cipher),
+                s2k.describe()
+            )),
+            _ => None,
+        }
+    }
+
+    /// Decrypts the secret key material, the passphrase is required only for protected keys.
+    pub fn unlock(&self, passphrase: Option<&str>) -> Result<SecretMaterial> {
+        let mpis = match &self.secret {
+            None => bail!("Private key is required, got a public key."),
+            Some(SecretPart::Plain(mpis)) => mpis.clone(),
+            Some(SecretPart::Protected {
+                usage,
+                cipher,
+                s2k,
+                iv,
+                data,
+            }) => {
+                let Some(passphrase) = passphrase else {
+                    bail!("Private key is protected, passphrase is required.");
+                };
+                let key = s2k.derive_key(passphrase, symmetric_key_size(*cipher)?)?;
+                let plaintext = cfb_decrypt(new_cipher(*cipher, &key)?.as_ref(), iv, data)?;
+
+                let check_length = if *usage == SHA1_PROTECTED { 20 } else { 2 };
+                if plaintext.len() < check_length {
+                    bail!(UNLOCK_ERROR);
+                }
+                let (mpis, check) = plaintext.split_at(plaintext.len() - check_length);
+                let valid = match usage {
+                    &SHA1_PROTECTED => Sha1::digest(mpis).as_slice() == check,
+                    _ => checksum16(mpis) == check,
+                };
+                if !valid {
+                    bail!(UNLOCK_ERROR);
+                }
+                mpis.to_vec()
+            }
+        };
+
+        self.secret_material(&mpis)
+    }
+
+    fn secret_material(&self, mpis: &[u8]) -> Result<SecretMaterial> {
+        let mut reader = Reader::new(mpis);
+        let mismatch = "Secret key doesn't match the public key.";
+
+        Ok(match &self.public.material {
+            PublicMaterial::Rsa { n, e } => {
+                let d = BigUint::from_bytes_be(reader.mpi()?);
+                let p = BigUint::from_bytes_be(reader.mpi()?);
+                let q = BigUint::from_bytes_be(reader.mpi()?);
+                let key = RsaPrivateKey::from_components(n.clone(), e.clone(), d, vec![p, q])?;
+                if key.validate().is_err() {
+                    bail!(mismatch);
+                }
+                SecretMaterial::Rsa(Box::new(key))
+            }
+            PublicMaterial::Ed25519(public) => {
+                let seed = left_pad(reader.mpi()?)?;
+                if SigningKey::from_bytes(&seed).verifying_key().as_bytes() != public {
+                    bail!(mismatch);
+                }
+                SecretMaterial::Ed25519(seed)
+            }
+            PublicMaterial::Cv25519 { public, .. } => {
+                let mut secret = left_pad(reader.mpi()?)?;
+                secret.reverse();
+                if X25519PublicKey::from(&StaticSecret::from(secret)).as_bytes() != public {
+                    bail!(mismatch);
+                }
+                SecretMaterial::Cv25519(secret)
+            }
+            PublicMaterial::Unsupported { .. } => bail!(
+                "Unsupported public key algorithm {}.",
+                self.public.describe()
+            ),
+        })
+    }
+
+    fn from_secret(created: u32, secret: SecretMaterial) -> Self {
+        let mut mpis = Vec::new();
+        let (algorithm, material) = match &secret {
+            SecretMaterial::Rsa(key) => {
+                let (p, q) = match key.primes() {
+                    [p, q] if p < q => (p, q),
+                    [q, p] => (p, q),
+                    _ => unreachable!("generated RSA keys have two primes"),
+                };
+                // u = p^-1 mod q, q is prime so Fermat's little theorem gives the inverse.
+                let u = p.modpow(&(q - 2u32), q);
+                for value in [key.d(), p, q, &u] {
+                    write_mpi(&mut mpis, &value.to_bytes_be());
+                }
+                (
+                    RSA,
+                    PublicMaterial::Rsa {
+                        n: key.n().clone(),
+                        e: key.e().clone(),
+                    },
+                )
+            }
+            SecretMaterial::Ed25519(seed) => {
+                write_mpi(&mut mpis, seed);
+                (
+                    EDDSA,
+                    PublicMaterial::Ed25519(
+                        SigningKey::from_bytes(seed).verifying_key().to_bytes(),
+                    ),
+                )
+            }
+            SecretMaterial::Cv25519(secret) => {
+                let mut reversed = *secret;
+                reversed.reverse();
+                write_mpi(&mut mpis, &reversed);
+                (
+                    ECDH,
+                    PublicMaterial::Cv25519 {
+                        public: X25519PublicKey::from(&StaticSecret::from(*secret)).to_bytes(),
+                        kdf_hash: SHA256,
+                        kdf_cipher: AES128,
+                    },
+                )
+            }
+        };
+
+        Self {
+            public: PublicKey::new(created, algorithm, material),
+            secret: Some(SecretPart::Plain(mpis)),
+        }
+    }
+
+    fn secret_body(&self, passphrase: Option<&str>) -> Result<Vec<u8>> {
+        let Some(SecretPart::Plain(mpis)) = &self.secret else {
+            bail!("Private key is required, got a public key.");
+        };
+
+        let mut body = self.public.body.clone();
+        match passphrase {
+            None => {
+                body.push(UNPROTECTED);
+                body.extend_from_slice(mpis);
+                body.extend_from_slice(&checksum16(mpis));
+            }
+            Some(passphrase) => {
+                let s2k = S2k::new(SHA256);
+                let iv: [u8; 16] = rand::random();
+                let key = s2k.derive_key(passphrase, symmetric_key_size(AES256)?)?;
+                let plaintext = [mpis.as_slice(), Sha1::digest(mpis).as_slice()].concat();
+
+                body.extend_from_slice(&[SHA1_PROTECTED, AES256]);
+                s2k.write(&mut body);
+                body.extend_from_slice(&iv);
+                body.extend(cfb_encrypt(
+                    new_cipher(AES256, &key)?.as_ref(),
+                    &iv,
+                    &plaintext,
+                )?);
+            }
+        }
+
+        Ok(body)
+    }
+}
+
+pub struct Subkey {
+    pub key: Key,
+    pub flags: Option<u8>,
+    signatures: Vec<Signature>,
+}
+
+/// Transferable public or secret key: primary key, user IDs and subkeys with their signatures.
+pub struct Cert {
+    pub primary: Key,
+    pub flags: Option<u8>,
+    pub user_ids: Vec<String>,
+    pub subkeys: Vec<Subkey>,
+    user_id_signatures: Vec<Vec<Signature>>,
+}
+
+impl Cert {
+    /// Reads a key from packets. Self-signatures that verify set key flags, signatures that don't
+    /// are ignored.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        let packets = read_packets(data)?;
+        let mut packets = packets.iter().filter(|p| p.tag != packet::TRUST).peekable();
+
+        let primary = match packets.next() {
+            Some(p) if p.tag == packet::PUBLIC_KEY => Key::parse_public(&p.body)?,
+            Some(p) if p.tag == packet::SECRET_KEY => Key::parse_secret(&p.body)?,
+            _ => bail!("OpenPGP key must start with a public or secret key packet."),
+        };
+
+        let mut cert = Self {
+            primary,
+            flags: None,
+            user_ids: Vec::new(),
+            subkeys: Vec::new(),
+            user_id_signatures: Vec::new(),
+        };
+
+        let mut in_user_attribute = false;
+        for packet in packets {
+            match packet.tag {
+                packet::USER_ID => {
+                    in_user_attribute = false;
+                    cert.user_ids
+                        .push(String::from_utf8_lossy(&packet.body).to_string());
+                    cert.user_id_signatures.push(Vec::new());
+                }
+                packet::USER_ATTRIBUTE => in_user_attribute = true,
+                packet::PUBLIC_SUBKEY | packet::SECRET_SUBKEY => {
+                    in_user_attribute = false;
+                    let key = match packet.tag {
+                        packet::PUBLIC_SUBKEY => Key::parse_public(&packet.body)?,
+                        _ => Key::parse_secret(&packet.body)?,
+                    };
+                    cert.subkeys.push(Subkey {
+                        key,
+                        flags: None,
+                        signatures: Vec::new(),
+                    });
+                }
+                packet::SIGNATURE if !in_user_attribute => {
+                    let signature = Signature::parse(&packet.body)?;
+                    match (cert.subkeys.last_mut(), cert.user_id_signatures.last_mut()) {
+                        (Some(subkey), _) => subkey.signatures.push(signature),
+                        (None, Some(signatures)) => signatures.push(signature),
+                        (None, None) => {}
+                    }
+                }
+                packet::SIGNATURE => {}
+                tag => bail!("Unexpected {} in OpenPGP key.", packet::packet_name(tag)),
+            }
+        }
+
+        cert.check_self_signatures();
+        Ok(cert)
+    }
+
+    /// Reads an armored or binary key.
+    pub fn read(input: &str) -> Result<Self> {
+        Self::parse(&dearmor(input)?.1)
+    }
+
+    pub fn generate(key_type: PgpKeyType, user_id: &str) -> Result<Self> {
+        let created = now();
+        let mut rng = rand::thread_rng();
+
+        let (primary, subkey) = match key_type {
+            PgpKeyType::Ed25519 => {
+                let mut secret: [u8; 32] = rand::random();
+                secret[0] &= 248;
+                secret[31] &= 127;
+                secret[31] |= 64;
+                (
+                    SecretMaterial::Ed25519(rand::random()),
+                    SecretMaterial::Cv25519(secret),
+                )
+            }
+            _ => {
+                let bits = match key_type {
+                    PgpKeyType::Rsa2048 => 2048,
+                    PgpKeyType::Rsa3072 => 3072,
+                    _ => 4096,
+                };
+                (
+                    SecretMaterial::Rsa(Box::new(RsaPrivateKey::new(&mut rng, bits)?)),
+                    SecretMaterial::Rsa(Box::new(RsaPrivateKey::new(&mut rng, bits)?)),
+                )
+            }
+        };
+        let primary = Key::from_secret(created, primary);
+        let subkey = Key::from_secret(created, subkey);
+        let secret = primary.unlock(None)?;
+
+        let mut hashed = key_signature_subpackets(created, &primary.public);
+        hashed.extend(subpacket(27, &[CERTIFY | SIGN]));
+        hashed.extend(subpacket(11, &[AES256, 8, AES128]));
+        hashed.extend(subpacket(21, &[10, 9, SHA256]));
+        hashed.extend(subpacket(22, &[2, 1, 0]));
+        hashed.extend(subpacket(30, &[0x01]));
+        let certification = Signature::create(
+            POSITIVE_CERTIFICATION,
+            &primary.public,
+            &secret,
+            SHA256,
+            hashed,
+            &user_id_hash_data(&primary.public, user_id),
+        )?;
+
+        let mut hashed = key_signature_subpackets(created, &primary.public);
+        hashed.extend(subpacket(27, &[ENCRYPT_COMMUNICATIONS | ENCRYPT_STORAGE]));
+        let binding = Signature::create(
+            SUBKEY_BINDING,
+            &primary.public,
+            &secret,
+            SHA256,
+            hashed,
+            &subkey_hash_data(&primary.public, &subkey.public),
+        )?;
+
+        Ok(Self {
+            primary,
+            flags: Some(CERTIFY | SIGN),
+            user_ids: vec![user_id.to_string()],
+            subkeys: vec![Subkey {
+                key: subkey,
+                flags: Some(ENCRYPT_COMMUNICATIONS | ENCRYPT_STORAGE),
+                signatures: vec![binding],
+            }],
+            user_id_signatures: vec![vec![certification]],
+        })
+    }
+
+    /// Writes the transferable public key, or the secret key protected with the passphrase.
+    pub fn to_bytes(&self, secret: bool, passphrase: Option<&str>) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+
+        match secret {
+            true => write_packet(
+                &mut out,
+                packet::SECRET_KEY,
+                &self.primary.secret_body(passphrase)?,
+            ),
+            false => write_packet(&mut out, packet::PUBLIC_KEY, self.primary.public.body()),
+        }
+        for (user_id, signatures) in self.user_ids.iter().zip(&self.user_id_signatures) {
+            write_packet(&mut out, packet::USER_ID, user_id.as_bytes());
+            for signature in signatures {
+                write_packet(&mut out, packet::SIGNATURE, &signature.serialize());
+            }
+        }
+        for subkey in &self.subkeys {
+            match secret {
+                true => write_packet(
+                    &mut out,
+                    packet::SECRET_SUBKEY,
+                    &subkey.key.secret_body(passphrase)?,
+                ),
+                false => write_packet(&mut out, packet::PUBLIC_SUBKEY, subkey.key.public.body()),
+            }
+            for signature in &subkey.signatures {
+                write_packet(&mut out, packet::SIGNATURE, &signature.serialize());
+            }
+        }
+
+        Ok(out)
+    }
+
+    pub fn is_secret(&self) -> bool {
+        self.primary.has_secret()
+    }
+
+    /// All keys with their key flags, the primary key goes first.
+    pub fn keys(&self) -> impl Iterator<Item = (&Key, Option<u8>)> {
+        std::iter::once((&self.primary, self.flags)).chain(
+            self.subkeys
+                .iter()
+                .map(|subkey| (&subkey.key, subkey.flags)),
+        )
+    }
+
+    /// Prefers a signing subkey like GnuPG does and falls back to the primary key.
+    pub fn signing_key(&self) -> Result<&Key> {
+        let usable = |key: &Key, flags: Option<u8>, default: bool| {
+            key.public.can_sign()
+                && key.has_secret()
+                && flags.map_or(default, |flags| flags & SIGN != 0)
+        };
+
+        self.subkeys
+            .iter()
+            .find(|subkey| usable(&subkey.key, subkey.flags, false))
+            .map(|subkey| &subkey.key)
+            .or_else(|| usable(&self.primary, self.flags, true).then_some(&self.primary))
+            .ok_or_else(|| anyhow::anyhow!("Private key has no signing capable key."))
+    }
+
+    pub fn encryption_key(&self) -> Result<&Key> {
+        let usable = |key: &Key, flags: Option<u8>, default: bool| {
+            key.public.can_encrypt()
+                && flags.map_or(default, |flags| {
+                    flags & (ENCRYPT_COMMUNICATIONS | ENCRYPT_STORAGE) != 0
+                })
+        };
+
+        self.subkeys
+            .iter()
+            .find(|subkey| usable(&subkey.key, subkey.flags, true))
+            .map(|subkey| &subkey.key)
+            .or_else(|| usable(&self.primary, self.flags, true).then_some(&self.primary))
+            .ok_or_else(|| anyhow::anyhow!("Public key has no encryption capable key."))
+    }
+
+    /// Text summary of the key with fingerprints and user IDs.
+    pub fn describe(&self) -> String {
+        let mut lines = vec![format!(
+            "Type: {}",
+            match (self.is_secret(), self.primary.is_protected()) {
+                (true, true) => "private key, protected with a passphrase",
+                (true, false) => "private key",
+                _ => "public key",
+            }
+        )];
+        lines.extend(describe_key("Primary key", &self.primary, self.flags));
+        if let Some(protection) = self.primary.protection() {
+            lines.push(format!("    Protection: {protection}"));
+        }
+        lines.push("User IDs:".to_string());
+        lines.extend(self.user_ids.iter().map(|user_id| format!("    {user_id}")));
+        for subkey in &self.subkeys {
+            lines.extend(describe_key("Subkey", &subkey.key, subkey.flags));
+        }
+        lines.join("\n")
+    }
+
+    fn check_self_signatures(&mut self) {
+        let primary = &self.primary.public;
+        let key_id = primary.key_id();
+        let is_self_signature =
+            |signature: &&Signature| signature.issuer().map_or(true, |issuer| issuer == key_id);
+
+        for (user_id, signatures) in self.user_ids.iter().zip(&self.user_id_signatures) {
+            let data = user_id_hash_data(primary, user_id);
+            let flags = signatures
+                .iter()
+                .filter(is_self_signature)
+                .filter(|signature| (0x10..=0x13).contains(&signature.sig_type))
+                .filter(|signature| signature.verify(primary, &data).unwrap_or(false))
+                .max_by_key(|signature| signature.created())
+                .and_then(Signature::key_flags);
+            self.flags = self.flags.or(flags);
+        }
+
+        for subkey in &mut self.subkeys {
+            let data = subkey_hash_data(primary, &subkey.key.public);
+            subkey.flags = subkey
+                .signatures
+                .iter()
+                .filter(is_self_signature)
+                .filter(|signature| signature.sig_type == SUBKEY_BINDING)
+                .filter(|signature| signature.verify(primary, &data).unwrap_or(false))
+                .max_by_key(|signature| signature.created())
+                .and_then(Signature::key_flags);
+        }
+    }
+}
+
+fn describe_key(title: &str, key: &Key, flags: Option<u8>) -> Vec<String> {
+    let public = &key.public;
+    let mut usage = Vec::new();
+    for (flag, name) in [
+        (CERTIFY, "certify"),
+        (SIGN, "sign"),
+        (ENCRYPT_COMMUNICATIONS | ENCRYPT_STORAGE, "encrypt"),
+        (0x20, "authenticate"),
+    ] {
+        if flags.is_some_and(|flags| flags & flag != 0) {
+            usage.push(name);
+        }
+    }
+
+    let mut lines = vec![
+        format!("{title}: {}", public.describe()),
+        format!("    Created: {}", format_time(public.created)),
+        format!("    Key ID: {}", upper_hex(&public.key_id())),
+        format!("    Fingerprint: {}", upper_hex(&public.fingerprint())),
+    ];
+    if !usage.is_empty() {
+        lines.push(format!("    Usage: {}", usage.join(", ")));
+    }
+    lines
+}
+
+fn key_signature_subpackets(created: u32, primary: &PublicKey) -> Vec<u8> {
+    let mut hashed = subpacket(2, &created.to_be_bytes());
+    hashed.extend(subpacket(
+        33,
+        &[&[4], primary.fingerprint().as_slice()].concat(),
+    ));
+    hashed
+}
+
+pub fn user_id_hash_data(primary: &PublicKey, user_id: &str) -> Vec<u8> {
+    let mut data = primary.hash_prefix();
+    data.push(0xb4);
+    data.extend_from_slice(&(user_id.len() as u32).to_be_bytes());
+    data.extend_from_slice(user_id.as_bytes());
+    data
+}
+
+pub fn subkey_hash_data(primary: &PublicKey, subkey: &PublicKey) -> Vec<u8> {
+    [primary.hash_prefix(), subkey.hash_prefix()].concat()
+}
+
+fn write_curve_point(out: &mut Vec<u8>, oid: &[u8], public: &[u8; 32]) {
+    out.push(oid.len() as u8);
+    out.extend_from_slice(oid);
+    write_mpi(out, &[&[0x40], public.as_slice()].concat());
+}
+
+/// Curve points of Ed25519 and Curve25519 keys are stored with 0x40 prefix.
+fn native_point(point: &[u8]) -> Option<[u8; 32]> {
+    match point {
+        [0x40, rest @ ..] => rest.try_into().ok(),
+        _ => None,
+    }
+}
+
+/// Restores leading zero bytes stripped from a 32 byte MPI.
+fn left_pad(value: &[u8]) -> Result<[u8; 32]> {
+    if value.len() > 32 {
+        bail!("Invalid secret key length.");
+    }
+    let mut padded = [0; 32];
+    padded[32 - value.len()..].copy_from_slice(value);
+    Ok(padded)
+}
@@ -0,0 +1,198 @@
+//! OpenPGP (RFC 4880) keys, messages and signatures. Only v4 keys are handled, public key
+//! algorithms are limited to RSA, EdDSA over Ed25519 and ECDH over Curve25519.
+
+use aes::{Aes128, Aes192, Aes256};
+use anyhow::{bail, Result};
+use cipher::KeyInit;
+use x509_parser::time::ASN1Time;
+
+use crate::{
+    libs::block_cipher::{
+        modes::{self, Mode},
+        BlockCipherFunction, BlockPrimitive, Padding,
+    },
+    utils::SupportedMessageDigestAlgorithm,
+};
+
+pub mod armor;
+pub mod dump;
+pub mod key;
+pub mod message;
+pub mod packet;
+pub mod s2k;
+pub mod signature;
+
+pub const RSA: u8 = 1;
+pub const RSA_ENCRYPT_ONLY: u8 = 2;
+pub const RSA_SIGN_ONLY: u8 = 3;
+pub const ECDH: u8 = 18;
+pub const EDDSA: u8 = 22;
+
+pub const AES128: u8 = 7;
+pub const AES256: u8 = 9;
+
+pub const SHA1: u8 = 2;
+pub const SHA256: u8 = 8;
+
+pub const ED25519_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0xda, 0x47, 0x0f, 0x01];
+pub const CURVE25519_OID: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0x97, 0x55, 0x01, 0x05, 0x01];
+
+pub fn public_key_algorithm_name(algorithm: u8) -> &'static str {
+    match algorithm {
+        RSA => "RSA",
+        RSA_ENCRYPT_ONLY => "RSA Encrypt-Only",
+        RSA_SIGN_ONLY => "RSA Sign-Only",
+        16 => "Elgamal",
+        17 => "DSA",
+        ECDH => "ECDH",
+        19 => "ECDSA",
+        EDDSA => "EdDSA",
+        25 => "X25519",
+        26 => "X448",
+        27 => "Ed25519",
+        28 => "Ed448",
+        _ => "Unknown",
+    }
+}
+
+pub fn symmetric_algorithm_name(algorithm: u8) -> &'static str {
+    match algorithm {
+        0 => "Plaintext",
+        1 => "IDEA",
+        2 => "TripleDES",
+        3 => "CAST5",
+        4 => "Blowfish",
+        AES128 => "AES-128",
+        8 => "AES-192",
+        AES256 => "AES-256",
+        10 => "Twofish",
+        11 => "Camellia-128",
+        12 => "Camellia-192",
+        13 => "Camellia-256",
+        _ => "Unknown",
+    }
+}
+
+pub fn hash_algorithm_name(algorithm: u8) -> &'static str {
+    match algorithm {
+        1 => "MD5",
+        SHA1 => "SHA-1",
+        3 => "RIPEMD-160",
+        SHA256 => "SHA-256",
+        9 => "SHA-384",
+        10 => "SHA-512",
+        11 => "SHA-224",
+        12 => "SHA3-256",
+        14 => "SHA3-512",
+        _ => "Unknown",
+    }
+}
+
+pub fn compression_algorithm_name(algorithm: u8) -> &'static str {
+    match algorithm {
+        0 => "Uncompressed",
+        1 => "ZIP",
+        2 => "ZLIB",
+        3 => "BZip2",
+        _ => "Unknown",
+    }
+}
+
+pub fn curve_name(oid: &[u8]) -> &'static str {
+    match oid {
+        ED25519_OID => "Ed25519",
+        CURVE25519_OID => "Curve25519",
+        [0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07] => "NIST P-256",
+        [0x2b, 0x81, 0x04, 0x00, 0x22] => "NIST P-384",
+        [0x2b, 0x81, 0x04, 0x00, 0x23] => "NIST P-521",
+        [0x2b, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x07] => "brainpoolP256r1",
+        [0x2b, 0x81, 0x04, 0x00, 0x0a] => "secp256k1",
+        _ => "unknown curve",
+    }
+}
+
+pub fn digest_algorithm(algorithm: u8) -> Result<SupportedMessageDigestAlgorithm> {
+    Ok(match algorithm {
+        SHA1 => SupportedMessageDigestAlgorithm::SHA1,
+        SHA256 => SupportedMessageDigestAlgorithm::SHA2_256,
+        9 => SupportedMessageDigestAlgorithm::SHA2_384,
+        10 => SupportedMessageDigestAlgorithm::SHA2_512,
+        11 => SupportedMessageDigestAlgorithm::SHA2_224,
+        12 => SupportedMessageDigestAlgorithm::SHA3_256,
+        14 => SupportedMessageDigestAlgorithm::SHA3_512,
+        _ => bail!(
+            "Unsupported hash algorithm {} ({algorithm}).",
+            hash_algorithm_name(algorithm)
+        ),
+    })
+}
+
+pub fn symmetric_key_size(algorithm: u8) -> Result<usize> {
+    Ok(match algorithm {
+        4 | AES128 | 11 => 16,
+        2 | 8 | 12 => 24,
+        AES256 | 10 | 13 => 32,
+        _ => bail!(
+            "Unsupported symmetric algorithm {} ({algorithm}).",
+            symmetric_algorithm_name(algorithm)
+        ),
+    })
+}
+
+pub fn block_size(algorithm: u8) -> Result<usize> {
+    symmetric_key_size(algorithm)?;
+    Ok(match algorithm {
+        2 | 4 => 8,
+        _ => 16,
+    })
+}
+
+pub fn new_cipher(algorithm: u8, key: &[u8]) -> Result<Box<dyn BlockPrimitive>> {
+    if key.len() != symmetric_key_size(algorithm)? {
+        bail!("Invalid session key length.");
+    }
+
+    Ok(match algorithm {
+        AES128 => Box::new(Aes128::new_from_slice(key)?),
+        8 => Box::new(Aes192::new_from_slice(key)?),
+        AES256 => Box::new(Aes256::new_from_slice(key)?),
+        2 => BlockCipherFunction::TripleDES.new_cipher(key)?,
+        4 => BlockCipherFunction::Blowfish.new_cipher(key)?,
+        10 => BlockCipherFunction::Twofish.new_cipher(key)?,
+        _ => BlockCipherFunction::Camellia.new_cipher(key)?,
+    })
+}
+
+/// OpenPGP uses plain CFB mode without padding.
+pub fn cfb_encrypt(cipher: &dyn BlockPrimitive, iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    modes::encrypt(cipher, Mode::CFB, Padding::None, iv, data)
+}
+
+pub fn cfb_decrypt(cipher: &dyn BlockPrimitive, iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    modes::decrypt(cipher, Mode::CFB, Padding::None, iv, data)
+}
+
+pub fn format_time(timestamp: u32) -> String {
+    match ASN1Time::from_timestamp(timestamp as i64) {
+        Ok(time) => time.to_string(),
+        Err(_) => timestamp.to_string(),
+    }
+}
+
+pub fn now() -> u32 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as u32)
+        .unwrap_or_default()
+}
+
+/// Two byte sum used to check session keys and unprotected secret keys.
+pub fn checksum16(data: &[u8]) -> [u8; 2] {
+    data.iter()
+        .fold(0u16, |sum, &byte| sum.wrapping_add(byte as u16))
+        .to_be_bytes()
+}
+
+pub fn upper_hex(data: &[u8]) -> String {
+    data.iter().map(|byte| format!("{byte:02X}")).collect()
+}
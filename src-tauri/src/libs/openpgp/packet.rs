@@ -0,0 +1,218 @@
+use anyhow::{bail, Result};
+
+pub const PUBLIC_KEY_ENCRYPTED_SESSION_KEY: u8 = 1;
+pub const SIGNATURE: u8 = 2;
+pub const SYMMETRIC_KEY_ENCRYPTED_SESSION_KEY: u8 = 3;
+pub const ONE_PASS_SIGNATURE: u8 = 4;
+pub const SECRET_KEY: u8 = 5;
+pub const PUBLIC_KEY: u8 = 6;
+pub const SECRET_SUBKEY: u8 = 7;
+pub const COMPRESSED_DATA: u8 = 8;
+pub const SYMMETRICALLY_ENCRYPTED_DATA: u8 = 9;
+pub const MARKER: u8 = 10;
+pub const LITERAL_DATA: u8 = 11;
+pub const TRUST: u8 = 12;
+pub const USER_ID: u8 = 13;
+pub const PUBLIC_SUBKEY: u8 = 14;
+pub const USER_ATTRIBUTE: u8 = 17;
+pub const SYMMETRICALLY_ENCRYPTED_INTEGRITY_PROTECTED_DATA: u8 = 18;
+pub const MODIFICATION_DETECTION_CODE: u8 = 19;
+pub const AEAD_ENCRYPTED_DATA: u8 = 20;
+
+pub struct Packet {
+    pub tag: u8,
+    pub body: Vec<u8>,
+}
+
+pub fn packet_name(tag: u8) -> &'static str {
+    match tag {
+        PUBLIC_KEY_ENCRYPTED_SESSION_KEY => "Public-Key Encrypted Session Key Packet",
+        SIGNATURE => "Signature Packet",
+        SYMMETRIC_KEY_ENCRYPTED_SESSION_KEY => "Symmetric-Key Encrypted Session Key Packet",
+        ONE_PASS_SIGNATURE => "One-Pass Signature Packet",
+        SECRET_KEY => "Secret-Key Packet",
+        PUBLIC_KEY => "Public-Key Packet",
+        SECRET_SUBKEY => "Secret-Subkey Packet",
+        COMPRESSED_DATA => "Compressed Data Packet",
+        SYMMETRICALLY_ENCRYPTED_DATA => "Symmetrically Encrypted Data Packet",
+        MARKER => "Marker Packet",
+        LITERAL_DATA => "Literal Data Packet",
+        TRUST => "Trust Packet",
+        USER_ID => "User ID Packet",
+        PUBLIC_SUBKEY => "Public-Subkey Packet",
+        USER_ATTRIBUTE => "User Attribute Packet",
+        SYMMETRICALLY_ENCRYPTED_INTEGRITY_PROTECTED_DATA => {
+            "Symmetrically Encrypted Integrity Protected Data Packet"
+        }
+        MODIFICATION_DETECTION_CODE => "Modification Detection Code Packet",
+        AEAD_ENCRYPTED_DATA => "AEAD Encrypted Data Packet",
+        _ => "Unknown Packet",
+    }
+}
+
+/// Splits data into packets, both old and new header formats are accepted and partial body
+/// lengths are joined.
+pub fn read_packets(data: &[u8]) -> Result<Vec<Packet>> {
+    let mut reader = Reader::new(data);
+    let mut packets = Vec::new();
+
+    while !reader.is_empty() {
+        let header = reader.u8()?;
+        if header & 0x80 == 0 {
+            bail!("Invalid OpenPGP packet header.");
+        }
+
+        let packet = match header & 0x40 {
+            0 => {
+                let tag = (header >> 2) & 0x0f;
+                let length = match header & 0x03 {
+                    0 => reader.u8()? as usize,
+                    1 => reader.u16()? as usize,
+                    2 => reader.u32()? as usize,
+                    _ => reader.remaining(),
+                };
+                Packet {
+                    tag,
+                    body: reader.take(length)?.to_vec(),
+                }
+            }
+            _ => {
+                let mut body = Vec::new();
+                loop {
+                    match reader.u8()? {
+                        length @ 0..=191 => {
+                            body.extend_from_slice(reader.take(length as usize)?);
+                            break;
+                        }
+                        first @ 192..=223 => {
+                            let length =
+                                ((first as usize - 192) << 8) + reader.u8()? as usize + 192;
+                            body.extend_from_slice(reader.take(length)?);
+                            break;
+                        }
+                        255 => {
+                            let length = reader.u32()? as usize;
+                            body.extend_from_slice(reader.take(length)?);
+                            break;
+                        }
+                        partial => body.extend_from_slice(reader.take(1 << (partial & 0x1f))?),
+                    }
+                }
+                Packet {
+                    tag: header & 0x3f,
+                    body,
+                }
+            }
+        };
+        packets.push(packet);
+    }
+
+    Ok(packets)
+}
+
+/// Writes a packet with a new format header and a definite length.
+pub fn write_packet(out: &mut Vec<u8>, tag: u8, body: &[u8]) {
+    out.push(0xc0 | tag);
+    write_length(out, body.len());
+    out.extend_from_slice(body);
+}
+
+/// New format body length, also used by signature subpackets.
+pub fn write_length(out: &mut Vec<u8>, length: usize) {
+    match length {
+        0..=191 => out.push(length as u8),
+        192..=8383 => {
+            let length = length - 192;
+            out.extend_from_slice(&[(length >> 8) as u8 + 192, length as u8]);
+        }
+        _ => {
+            out.push(255);
+            out.extend_from_slice(&(length as u32).to_be_bytes());
+        }
+    }
+}
+
+/// Writes a multiprecision integer, leading zero bytes are stripped.
+pub fn write_mpi(out: &mut Vec<u8>, value: &[u8]) {
+    let start = value
+        .iter()
+        .position(|&byte| byte != 0)
+        .unwrap_or(value.len());
+    let value = &value[start..];
+    let bits = match value.first() {
+        Some(first) => value.len() * 8 - first.leading_zeros() as usize,
+        None => 0,
+    };
+    out.extend_from_slice(&(bits as u16).to_be_bytes());
+    out.extend_from_slice(value);
+}
+
+pub struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn take(&mut self, length: usize) -> Result<&'a [u8]> {
+        if length > self.remaining() {
+            bail!("Unexpected end of OpenPGP packet.");
+        }
+        let bytes = &self.data[self.position..self.position + length];
+        self.position += length;
+        Ok(bytes)
+    }
+
+    pub fn rest(&mut self) -> &'a [u8] {
+        let bytes = &self.data[self.position..];
+        self.position = self.data.len();
+        bytes
+    }
+
+    pub fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into()?))
+    }
+
+    pub fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into()?))
+    }
+
+    pub fn mpi(&mut self) -> Result<&'a [u8]> {
+        let bits = self.u16()? as usize;
+        self.take((bits + 7) / 8)
+    }
+
+    /// Length prefixed field such as a curve OID or KDF parameters.
+    pub fn short_field(&mut self) -> Result<&'a [u8]> {
+        let length = self.u8()? as usize;
+        self.take(length)
+    }
+
+    /// New format length as used by signature subpackets.
+    pub fn subpacket_length(&mut self) -> Result<usize> {
+        Ok(match self.u8()? {
+            length @ 0..=191 => length as usize,
+            first @ 192..=254 => ((first as usize - 192) << 8) + self.u8()? as usize + 192,
+            255 => self.u32()? as usize,
+        })
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::{bail, Result};
+
+use super::{digest_algorithm, hash_algorithm_name, packet::Reader};
+
+const SIMPLE: u8 = 0;
+const SALTED: u8 = 1;
+const ITERATED_AND_SALTED: u8 = 3;
+const GNU_EXTENSION: u8 = 101;
+
+/// Iteration count byte for 65011712 hashed bytes, the highest count. GnuPG calibrates its count
+/// to the machine instead, so it is an upper bound rather than a common value.
+const DEFAULT_COUNT: u8 = 0xff;
+
+/// String-to-key specifier, turns a passphrase into a symmetric key.
+pub struct S2k {
+    kind: u8,
+    hash: u8,
+    salt: [u8; 8],
+    count: u8,
+}
+
+impl S2k {
+    pub fn new(hash: u8) -> Self {
+        Self {
+            kind: ITERATED_AND_SALTED,
+            hash,
+            salt: rand::random(),
+            count: DEFAULT_COUNT,
+        }
+    }
+
+    pub fn read(reader: &mut Reader) -> Result<Self> {
+        let kind = reader.u8()?;
+        let hash = reader.u8()?;
+        let mut s2k = Self {
+            kind,
+            hash,
+            salt: [0; 8],
+            count: 0,
+        };
+
+        match kind {
+            SIMPLE => {}
+            SALTED => s2k.salt.copy_from_slice(reader.take(8)?),
+            ITERATED_AND_SALTED => {
+                s2k.salt.copy_from_slice(reader.take(8)?);
+                s2k.count = reader.u8()?;
+            }
+            GNU_EXTENSION => {
+                if reader.take(3)? != b"GNU" {
+                    bail!("Unsupported S2K extension.");
+                }
+                s2k.count = reader.u8()?;
+            }
+            _ => bail!("Unsupported S2K specifier type {kind}."),
+        }
+
+        Ok(s2k)
+    }
+
+    pub fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&[self.kind, self.hash]);
+        match self.kind {
+            SALTED => out.extend_from_slice(&self.salt),
+            ITERATED_AND_SALTED => {
+                out.extend_from_slice(&self.salt);
+                out.push(self.count);
+            }
+            _ => {}
+        }
+    }
+
+    /// Hashes salt and passphrase into a key, longer keys use more hash contexts each preloaded
+    /// with one more zero byte.
+    pub fn derive_key(&self, passphrase: &str, key_size: usize) -> Result<Vec<u8>> {
+        if self.kind == GNU_EXTENSION {
+            bail!("Secret key is not available, the key file holds only a stub.");
+        }
+
+        let digest = digest_algorithm(self.hash)?;
+        let data = match self.kind {
+            SIMPLE => passphrase.as_bytes().to_vec(),
+            _ => [&self.salt[..], passphrase.as_bytes()].concat(),
+        };
+        let total = match self.kind {
+            ITERATED_AND_SALTED => self.iterations().max(data.len()),
+            _ => data.len(),
+        };
+        // Salt and passphrase are repeated, so whole repetitions are fed in large chunks.
+        let chunk = data.repeat(8192 / data.len().max(1) + 1);
+
+        let mut key = Vec::with_capacity(key_size);
+        let mut preload = 0;
+        while key.len() < key_size {
+            let mut hasher = digest.hasher();
+            hasher.update(&vec![0; preload]);
+            let mut remaining = total;
+            while remaining > 0 {
+                let length = remaining.min(chunk.len());
+                hasher.update(&chunk[..length]);
+                remaining -= length;
+            }
+            key.extend_from_slice(&hasher.finalize());
+            preload += 1;
+        }
+        key.truncate(key_size);
+
+        Ok(key)
+    }
+
+    pub fn describe(&self) -> String {
+        let hash = hash_algorithm_name(self.hash);
+        match self.kind {
+            SIMPLE => format!("simple, {hash}"),
+            SALTED => format!("salted, {hash}"),
+            ITERATED_AND_SALTED => {
+                format!("iterated and salted, {hash}, {} bytes", self.iterations())
+            }
+            _ => match self.count {
+                1 => "GNU dummy, secret key is not available".to_string(),
+                2 => "GNU divert to card, secret key is on a smartcard".to_string(),
+                _ => "GNU extension".to_string(),
+            },
+        }
+    }
+
+    fn iterations(&self) -> usize {
+        (16 + (self.count as usize & 15)) << ((self.count >> 4) + 6)
+    }
+}
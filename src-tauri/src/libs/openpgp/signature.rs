@@ -0,0 +1,365 @@
+use anyhow::{bail, Result};
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use rsa::{traits::PublicKeyParts, RsaPublicKey};
+
+use super::{
+    digest_algorithm, hash_algorithm_name,
+    key::{PublicKey, PublicMaterial, SecretMaterial},
+    packet::{write_length, write_mpi, Reader},
+    public_key_algorithm_name, upper_hex, EDDSA,
+};
+use crate::libs::rsa_padding::pkcs1v15_sign;
+
+pub const BINARY: u8 = 0x00;
+pub const TEXT: u8 = 0x01;
+
+const CREATION_TIME: u8 = 2;
+const ISSUER: u8 = 16;
+const KEY_FLAGS: u8 = 27;
+const ISSUER_FINGERPRINT: u8 = 33;
+
+/// Subpackets this implementation understands: the ones it reads plus the algorithm preferences
+/// and features, which only inform the sender. A signature with any other subpacket marked
+/// critical is invalid, see RFC 4880, section 5.2.3.1.
+const KNOWN_SUBPACKETS: [u8; 8] = [
+    CREATION_TIME,
+    11,
+    ISSUER,
+    21,
+    22,
+    KEY_FLAGS,
+    30,
+    ISSUER_FINGERPRINT,
+];
+
+pub struct Subpacket {
+    pub kind: u8,
+    pub critical: bool,
+    pub data: Vec<u8>,
+}
+
+/// Version 4 signature packet.
+pub struct Signature {
+    pub sig_type: u8,
+    pub algorithm: u8,
+    pub hash: u8,
+    hashed: Vec<u8>,
+    unhashed: Vec<u8>,
+    left16: [u8; 2],
+    mpis: Vec<Vec<u8>>,
+}
+
+impl Signature {
+    pub fn parse(body: &[u8]) -> Result<Self> {
+        let mut reader = Reader::new(body);
+
+        let version = reader.u8()?;
+        if version != 4 {
+            bail!(
+                "Unsupported signature version {version}, only version 4 signatures are supported."
+            );
+        }
+        let sig_type = reader.u8()?;
+        let algorithm = reader.u8()?;
+        let hash = reader.u8()?;
+        let length = reader.u16()? as usize;
+        let hashed = reader.take(length)?.to_vec();
+        let length = reader.u16()? as usize;
+        let unhashed = reader.take(length)?.to_vec();
+        let mut left16 = [0; 2];
+        left16.copy_from_slice(reader.take(2)?);
+
+        let mut mpis = Vec::new();
+        while !reader.is_empty() {
+            mpis.push(reader.mpi()?.to_vec());
+        }
+
+        Ok(Self {
+            sig_type,
+            algorithm,
+            hash,
+            hashed,
+            unhashed,
+            left16,
+            mpis,
+        })
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut body = vec![4, self.sig_type, self.algorithm, self.hash];
+        body.extend_from_slice(&(self.hashed.len() as u16).to_be_bytes());
+        body.extend_from_slice(&self.hashed);
+        body.extend_from_slice(&(self.unhashed.len() as u16).to_be_bytes());
+        body.extend_from_slice(&self.unhashed);
+        body.extend_from_slice(&self.left16);
+        for mpi in &self.mpis {
+            write_mpi(&mut body, mpi);
+        }
+        body
+    }
+
+    /// Signs the data, the creation time and the issuer fingerprint must already be among the
+    /// hashed subpackets. The issuer key ID goes to the unhashed area.
+    pub fn create(
+        sig_type: u8,
+        key: &PublicKey,
+        secret: &SecretMaterial,
+        hash: u8,
+        hashed: Vec<u8>,
+        data: &[u8],
+    ) -> Result<Self> {
+        let mut signature = Self {
+            sig_type,
+            algorithm: key.algorithm,
+            hash,
+            hashed,
+            unhashed: subpacket(ISSUER, &key.key_id()),
+            left16: [0; 2],
+            mpis: Vec::new(),
+        };
+
+        let digest = signature.digest(data)?;
+        signature.left16.copy_from_slice(&digest[..2]);
+        signature.mpis = match secret {
+            SecretMaterial::Rsa(key) => {
+                vec![key.sign(pkcs1v15_sign(digest_algorithm(hash)?), &digest)?]
+            }
+            SecretMaterial::Ed25519(seed) => {
+                let bytes = SigningKey::from_bytes(seed).sign(&digest).to_bytes();
+                vec![bytes[..32].to_vec(), bytes[32..].to_vec()]
+            }
+            SecretMaterial::Cv25519(_) => bail!("Curve25519 keys can't be used for signing."),
+        };
+
+        Ok(signature)
+    }
+
+    pub fn verify(&self, key: &PublicKey, data: &[u8]) -> Result<bool> {
+        if key.algorithm != self.algorithm || self.has_unknown_critical_subpacket()? {
+            return Ok(false);
+        }
+
+        let digest = self.digest(data)?;
+        if digest[..2] != self.left16 {
+            return Ok(false);
+        }
+
+        Ok(match (&key.material, self.mpis.as_slice()) {
+            (PublicMaterial::Rsa { n, e }, [signature]) => {
+                let key = RsaPublicKey::new(n.clone(), e.clone())?;
+                let mut padded = vec![0; key.size().saturating_sub(signature.len())];
+                padded.extend_from_slice(signature);
+                key.verify(
+                    pkcs1v15_sign(digest_algorithm(self.hash)?),
+                    &digest,
+                    &padded,
+                )
+                .is_ok()
+            }
+            (PublicMaterial::Ed25519(public), [r, s]) if self.algorithm == EDDSA => {
+                if r.len() > 32 || s.len() > 32 {
+                    return Ok(false);
+                }
+                let mut signature = [0; 64];
+                signature[32 - r.len()..32].copy_from_slice(r);
+                signature[64 - s.len()..].copy_from_slice(s);
+                VerifyingKey::from_bytes(public)?
+                    .verify(&digest, &ed25519_dalek::Signature::from_bytes(&signature))
+                    .is_ok()
+            }
+            (PublicMaterial::Unsupported { .. }, _) => bail!(
+                "Unsupported public key algorithm {}.",
+                public_key_algorithm_name(self.algorithm)
+            ),
+            _ => false,
+        })
+    }
+
+    /// Hash over the signed data followed by the signature fields and the v4 trailer.
+    fn digest(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let mut hasher = digest_algorithm(self.hash)?.hasher();
+        hasher.update(data);
+
+        let mut fields = vec![4, self.sig_type, self.algorithm, self.hash];
+        fields.extend_from_slice(&(self.hashed.len() as u16).to_be_bytes());
+        fields.extend_from_slice(&self.hashed);
+        hasher.update(&fields);
+        hasher.update(&[4, 0xff]);
+        hasher.update(&(fields.len() as u32).to_be_bytes());
+
+        Ok(hasher.finalize().to_vec())
+    }
+
+    pub fn hashed_subpackets(&self) -> Result<Vec<Subpacket>> {
+        read_subpackets(&self.hashed)
+    }
+
+    pub fn unhashed_subpackets(&self) -> Result<Vec<Subpacket>> {
+        read_subpackets(&self.unhashed)
+    }
+
+    fn has_unknown_critical_subpacket(&self) -> Result<bool> {
+        Ok(self
+            .hashed_subpackets()?
+            .iter()
+            .chain(&self.unhashed_subpackets()?)
+            .any(|subpacket| subpacket.critical && !KNOWN_SUBPACKETS.contains(&subpacket.kind)))
+    }
+
+    pub fn created(&self) -> Option<u32> {
+        self.find_hashed(CREATION_TIME)
+            .and_then(|data| Some(u32::from_be_bytes(data.try_into().ok()?)))
+    }
+
+    pub fn key_flags(&self) -> Option<u8> {
+        self.find_hashed(KEY_FLAGS)
+            .and_then(|data| data.first().copied())
+    }
+
+    /// Issuer key ID from the issuer subpacket or the tail of the issuer fingerprint.
+    pub fn issuer(&self) -> Option<[u8; 8]> {
+        let subpackets = self.hashed_subpackets().ok()?;
+        let unhashed = self.unhashed_subpackets().ok()?;
+        subpackets.iter().chain(&unhashed).find_map(|subpacket| {
+            match (subpacket.kind, subpacket.data.as_slice()) {
+                (ISSUER, data) => data.try_into().ok(),
+                (ISSUER_FINGERPRINT, [4, fingerprint @ ..]) if fingerprint.len() == 20 => {
+                    fingerprint[12..].try_into().ok()
+                }
+                _ => None,
+            }
+        })
+    }
+
+    pub fn describe(&self) -> String {
+        let mut lines = vec![
+            format!(
+                "Signature type: {} (0x{:02x})",
+                signature_type_name(self.sig_type),
+                self.sig_type
+            ),
+            format!(
+                "Public key algorithm: {} ({})",
+                public_key_algorithm_name(self.algorithm),
+                self.algorithm
+            ),
+            format!(
+                "Hash algorithm: {} ({})",
+                hash_algorithm_name(self.hash),
+                self.hash
+            ),
+        ];
+        if let Some(created) = self.created() {
+            lines.push(format!("Created: {}", super::format_time(created)));
+        }
+        if let Some(issuer) = self.issuer() {
+            lines.push(format!("Issuer: {}", upper_hex(&issuer)));
+        }
+        lines.join("\n")
+    }
+
+    fn find_hashed(&self, kind: u8) -> Option<Vec<u8>> {
+        self.hashed_subpackets()
+            .ok()?
+            .into_iter()
+            .find(|subpacket| subpacket.kind == kind)
+            .map(|subpacket| subpacket.data)
+    }
+}
+
+pub fn signature_type_name(sig_type: u8) -> &'static str {
+    match sig_type {
+        BINARY => "binary document",
+        TEXT => "text document",
+        0x02 => "standalone",
+        0x10 => "generic certification",
+        0x11 => "persona certification",
+        0x12 => "casual certification",
+        0x13 => "positive certification",
+        0x18 => "subkey binding",
+        0x19 => "primary key binding",
+        0x1f => "direct key",
+        0x20 => "key revocation",
+        0x28 => "subkey revocation",
+        0x30 => "certification revocation",
+        0x40 => "timestamp",
+        0x50 => "third-party confirmation",
+        _ => "unknown",
+    }
+}
+
+pub fn subpacket(kind: u8, data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_length(&mut out, data.len() + 1);
+    out.push(kind);
+    out.extend_from_slice(data);
+    out
+}
+
+fn read_subpackets(data: &[u8]) -> Result<Vec<Subpacket>> {
+    let mut reader = Reader::new(data);
+    let mut subpackets = Vec::new();
+
+    while !reader.is_empty() {
+        let length = reader.subpacket_length()?;
+        if length == 0 {
+            bail!("Invalid signature subpacket length.");
+        }
+        let kind = reader.u8()?;
+        subpackets.push(Subpacket {
+            kind: kind & 0x7f,
+            critical: kind & 0x80 != 0,
+            data: reader.take(length - 1)?.to_vec(),
+        });
+    }
+
+    Ok(subpackets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::libs::openpgp::{
+        key::{Cert, PgpKeyType},
+        SHA256,
+    };
+
+    fn sign(cert: &Cert, hashed: Vec<u8>) -> Signature {
+        let key = cert.signing_key().unwrap();
+        let secret = key.unlock(None).unwrap();
+        Signature::create(BINARY, &key.public, &secret, SHA256, hashed, b"data").unwrap()
+    }
+
+    fn critical(kind: u8, data: &[u8]) -> Vec<u8> {
+        let mut packet = subpacket(kind, data);
+        let kind_idx = packet.len() - data.len() - 1;
+        packet[kind_idx] |= 0x80;
+        packet
+    }
+
+    #[test]
+    fn unknown_critical_subpacket_invalidates_signature() {
+        let cert = Cert::generate(PgpKeyType::Ed25519, "Alice <alice@example.com>").unwrap();
+        let public = &cert.signing_key().unwrap().public;
+        let created = subpacket(CREATION_TIME, &1_700_000_000u32.to_be_bytes());
+
+        let signature = sign(&cert, created.clone());
+        assert!(signature.verify(public, b"data").unwrap());
+        assert!(!signature.verify(public, b"date").unwrap());
+
+        let known = [created.clone(), critical(KEY_FLAGS, &[0x02])].concat();
+        assert!(sign(&cert, known).verify(public, b"data").unwrap());
+
+        let unknown = [created.clone(), subpacket(100, b"note")].concat();
+        assert!(sign(&cert, unknown).verify(public, b"data").unwrap());
+
+        let unknown = [created.clone(), critical(100, b"note")].concat();
+        let signature = sign(&cert, unknown);
+        assert!(signature.hashed_subpackets().unwrap()[1].critical);
+        assert!(!signature.verify(public, b"data").unwrap());
+
+        let mut signature = sign(&cert, created);
+        signature.unhashed.extend(critical(100, b"note"));
+        assert!(!signature.verify(public, b"data").unwrap());
+    }
+}
@@ -0,0 +1,58 @@
+use anyhow::{bail, Result};
+
+const END_OF_DATA: &str = "Invalid OpenSSH key, unexpected end of data.";
+
+pub fn read_ssh_u32(data: &mut &[u8]) -> Result<u32> {
+    if data.len() < 4 {
+        bail!(END_OF_DATA);
+    }
+    let (value, rest) = data.split_at(4);
+    *data = rest;
+    Ok(u32::from_be_bytes([value[0], value[1], value[2], value[3]]))
+}
+
+pub fn read_ssh_string<'a>(data: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let len = read_ssh_u32(data)? as usize;
+    if data.len() < len {
+        bail!(END_OF_DATA);
+    }
+    let (value, rest) = data.split_at(len);
+    *data = rest;
+    Ok(value)
+}
+
+pub fn write_ssh_string(out: &mut Vec<u8>, value: &[u8]) {
+    out.extend((value.len() as u32).to_be_bytes());
+    out.extend(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ssh_strings() {
+        let mut data = Vec::new();
+        write_ssh_string(&mut data, b"ssh-ed25519");
+        write_ssh_string(&mut data, b"");
+        assert_eq!(
+            crate::utils::to_hex(&data),
+            "0000000b7373682d6564323535313900000000"
+        );
+
+        let data = &mut data.as_slice();
+        assert_eq!(read_ssh_string(data).unwrap(), b"ssh-ed25519");
+        assert_eq!(read_ssh_string(data).unwrap(), b"");
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn truncated_data() {
+        for data in [&[0, 0, 1][..], &[0, 0, 0, 2, 1][..]] {
+            assert_eq!(
+                read_ssh_string(&mut &data[..]).unwrap_err().to_string(),
+                END_OF_DATA
+            );
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use anyhow::{bail, Result};
+
+pub struct Rc4 {
+    state: [u8; 256],
+    i: u8,
+    j: u8,
+}
+
+impl Rc4 {
+    pub fn new(key: &[u8]) -> Result<Self> {
+        if key.is_empty() || key.len() > 256 {
+            bail!(
+                "Invalid key length for RC4: expected from 1 to 256 bytes, got {} bytes.",
+                key.len()
+            );
+        }
+
+        let mut state = [0u8; 256];
+        state
+            .iter_mut()
+            .enumerate()
+            .for_each(|(idx, x)| *x = idx as u8);
+
+        let mut j = 0u8;
+        for i in 0..256 {
+            j = j.wrapping_add(state[i]).wrapping_add(key[i % key.len()]);
+            state.swap(i, j as usize);
+        }
+
+        Ok(Self { state, i: 0, j: 0 })
+    }
+
+    pub fn discard(&mut self, count: usize) {
+        (0..count).for_each(|_| {
+            self.next_byte();
+        });
+    }
+
+    pub fn apply_keystream(&mut self, data: &mut [u8]) {
+        data.iter_mut().for_each(|x| *x ^= self.next_byte());
+    }
+
+    fn next_byte(&mut self) -> u8 {
+        self.i = self.i.wrapping_add(1);
+        self.j = self.j.wrapping_add(self.state[self.i as usize]);
+        self.state.swap(self.i as usize, self.j as usize);
+
+        let idx = self.state[self.i as usize].wrapping_add(self.state[self.j as usize]);
+        self.state[idx as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{from_hex, to_hex};
+
+    /// Keystream at some offsets of RFC 6229 section 2.
+    const VECTORS: [(&str, [(usize, &str); 5]); 2] = [
+        (
+            "0102030405",
+            [
+                (0, "b2396305f03dc027ccc3524a0a1118a8"),
+                (16, "6982944f18fc82d589c403a47a0d0919"),
+                (768, "eb62638d4f0ba1fe9fca20e05bf8ff2b"),
+                (1536, "d8729db41882259bee4f825325f5a130"),
+                (4080, "068326a2118416d21f9d04b2cd1ca050"),
+            ],
+        ),
+        (
+            "0102030405060708090a0b0c0d0e0f10",
+            [
+                (0, "9ac7cc9a609d1ef7b2932899cde41b97"),
+                (16, "5248c4959014126a6e8a84f11d1a9e1c"),
+                (768, "eccbe13de1fcc91c11a0b26c0bc8fa4d"),
+                (1536, "ffa0b514647ec04f6306b892ae661181"),
+                (4080, "ff38265c1642c1abe8d3c2fe5e572bf8"),
+            ],
+        ),
+    ];
+
+    #[test]
+    fn rfc6229() {
+        for (key, offsets) in VECTORS {
+            let key = from_hex(key).unwrap();
+            for (offset, expected) in offsets {
+                let mut rc4 = Rc4::new(&key).unwrap();
+                rc4.discard(offset);
+                let mut keystream = [0u8; 16];
+                rc4.apply_keystream(&mut keystream);
+                assert_eq!(to_hex(&keystream), expected, "offset {offset}");
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut data = b"Attack at dawn".to_vec();
+        Rc4::new(b"Secret").unwrap().apply_keystream(&mut data);
+        assert_eq!(to_hex(&data), "45a01f645fc35b383552544b9bf5");
+        Rc4::new(b"Secret").unwrap().apply_keystream(&mut data);
+        assert_eq!(data, b"Attack at dawn");
+    }
+
+    #[test]
+    fn key_length() {
+        assert!(Rc4::new(&[]).is_err());
+        assert!(Rc4::new(&[0; 257]).is_err());
+        assert!(Rc4::new(&[0; 256]).is_ok());
+    }
+}
@@ -0,0 +1,250 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+/// Every transposition cipher here is a reading order: the ciphertext is the plaintext read at
+/// the positions of the order, decoding puts the characters back to these positions.
+pub fn transpose(input: &str, order: &[usize]) -> String {
+    let chars: Vec<char> = input.chars().collect();
+    order.iter().map(|&idx| chars[idx]).collect()
+}
+
+pub fn untranspose(input: &str, order: &[usize]) -> String {
+    let mut chars = vec!['\0'; order.len()];
+    for (ch, &idx) in input.chars().zip(order) {
+        chars[idx] = ch;
+    }
+    chars.into_iter().collect()
+}
+
+/// Plaintext is written in a zigzag over the rails, starting `offset` steps into the zigzag, and
+/// read rail by rail.
+pub fn rail_fence_order(len: usize, rails: usize, offset: usize) -> Result<Vec<usize>> {
+    if rails < 2 {
+        bail!("Number of rails must be at least 2.");
+    }
+    if rails > len.max(2) {
+        bail!("Number of rails must not exceed the input length.");
+    }
+
+    let cycle = (rails - 1) * 2;
+    let offset = offset % cycle;
+    let rail = |pos: usize| (rails - 1).abs_diff((rails - 1).abs_diff((pos + offset) % cycle));
+
+    let mut order: Vec<usize> = (0..len).collect();
+    order.sort_by_key(|&pos| rail(pos));
+    Ok(order)
+}
+
+/// Plaintext is written in rows under the key and read column by column in the alphabetical
+/// order of the key letters, equal letters are read from left to right.
+pub fn columnar_order(len: usize, key: &str) -> Result<Vec<usize>> {
+    let key: Vec<char> = key.to_lowercase().chars().collect();
+    if key.is_empty() {
+        bail!("Key must not be empty.");
+    }
+
+    let mut columns: Vec<usize> = (0..key.len()).collect();
+    columns.sort_by_key(|&column| key[column]);
+
+    Ok(read_columns(len, key.len(), &columns))
+}
+
+/// Plaintext is written along the rod in rows, `diameter` letters fit around the rod, so the text
+/// is read from a grid of `diameter` rows column by column.
+pub fn scytale_order(len: usize, diameter: usize) -> Result<Vec<usize>> {
+    if diameter == 0 {
+        bail!("Diameter must be a positive number.");
+    }
+    if diameter > len.max(1) {
+        bail!("Diameter must not exceed the input length.");
+    }
+
+    let width = ((len + diameter - 1) / diameter).max(1);
+    Ok(read_columns(len, width, &(0..width).collect::<Vec<_>>()))
+}
+
+fn read_columns(len: usize, width: usize, columns: &[usize]) -> Vec<usize> {
+    columns
+        .iter()
+        .flat_map(|&column| (column..len).step_by(width))
+        .collect()
+}
+
+#[derive(Deserialize, Copy, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum Route {
+    SpiralClockwise,
+    SpiralCounterclockwise,
+    SnakeRows,
+    SnakeColumns,
+}
+
+/// Plaintext is written in rows of `columns` characters and read along the route starting at the
+/// top left corner, cells of the incomplete last row are skipped.
+pub fn route_order(len: usize, columns: usize, route: Route) -> Result<Vec<usize>> {
+    if columns == 0 {
+        bail!("Number of columns must be a positive number.");
+    }
+    if columns > len.max(1) {
+        bail!("Number of columns must not exceed the input length.");
+    }
+
+    let rows = (len + columns - 1) / columns;
+    let cells: Vec<(usize, usize)> = match route {
+        Route::SpiralClockwise => spiral(rows, columns),
+        Route::SpiralCounterclockwise => spiral(columns, rows)
+            .into_iter()
+            .map(|(column, row)| (row, column))
+            .collect(),
+        Route::SnakeRows => (0..rows)
+            .flat_map(|row| {
+                (0..columns).map(move |column| match row % 2 {
+                    0 => (row, column),
+                    _ => (row, columns - 1 - column),
+                })
+            })
+            .collect(),
+        Route::SnakeColumns => (0..columns)
+            .flat_map(|column| {
+                (0..rows).map(move |row| match column % 2 {
+                    0 => (row, column),
+                    _ => (rows - 1 - row, column),
+                })
+            })
+            .collect(),
+    };
+
+    Ok(cells
+        .into_iter()
+        .map(|(row, column)| row * columns + column)
+        .filter(|&idx| idx < len)
+        .collect())
+}
+
+/// Cells of the grid walked clockwise from the top left corner to the center.
+fn spiral(rows: usize, columns: usize) -> Vec<(usize, usize)> {
+    let mut cells = Vec::with_capacity(rows * columns);
+    let (mut top, mut left) = (0, 0);
+    let (mut bottom, mut right) = (rows, columns);
+
+    while top < bottom && left < right {
+        cells.extend((left..right).map(|column| (top, column)));
+        cells.extend((top + 1..bottom).map(|row| (row, right - 1)));
+        if top + 1 < bottom {
+            cells.extend((left..right - 1).rev().map(|column| (bottom - 1, column)));
+        }
+        if left + 1 < right {
+            cells.extend((top + 1..bottom - 1).rev().map(|row| (row, left)));
+        }
+        top += 1;
+        left += 1;
+        bottom -= 1;
+        right -= 1;
+    }
+
+    cells
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAINTEXT: &str = "WEAREDISCOVEREDFLEEATONCE";
+    const ROUTES: [Route; 4] = [
+        Route::SpiralClockwise,
+        Route::SpiralCounterclockwise,
+        Route::SnakeRows,
+        Route::SnakeColumns,
+    ];
+
+    fn assert_round_trip(input: &str, order: Vec<usize>) {
+        assert_eq!(order.len(), input.chars().count());
+        assert_eq!(untranspose(&transpose(input, &order), &order), input);
+    }
+
+    #[test]
+    fn rail_fence() {
+        let order = rail_fence_order(PLAINTEXT.len(), 3, 0).unwrap();
+        assert_eq!(transpose(PLAINTEXT, &order), "WECRLTEERDSOEEFEAOCAIVDEN");
+    }
+
+    #[test]
+    fn columnar() {
+        let order = columnar_order(PLAINTEXT.len(), "ZEBRAS").unwrap();
+        assert_eq!(transpose(PLAINTEXT, &order), "EVLNACDTESEAROFODEECWIREE");
+    }
+
+    #[test]
+    fn scytale() {
+        let input = "IAMHURTVERYBADLYHELP";
+        let order = scytale_order(input.len(), 4).unwrap();
+        assert_eq!(transpose(input, &order), "IRYYATBHMVAEHEDLURLP");
+    }
+
+    #[test]
+    fn route() {
+        let order = route_order(12, 4, Route::SpiralClockwise).unwrap();
+        assert_eq!(order, [0, 1, 2, 3, 7, 11, 10, 9, 8, 4, 5, 6]);
+        let order = route_order(12, 4, Route::SpiralCounterclockwise).unwrap();
+        assert_eq!(order, [0, 4, 8, 9, 10, 11, 7, 3, 2, 1, 5, 6]);
+        let order = route_order(10, 4, Route::SnakeRows).unwrap();
+        assert_eq!(order, [0, 1, 2, 3, 7, 6, 5, 4, 8, 9]);
+        let order = route_order(10, 4, Route::SnakeColumns).unwrap();
+        assert_eq!(order, [0, 4, 8, 9, 5, 1, 2, 6, 7, 3]);
+    }
+
+    #[test]
+    fn round_trips() {
+        for input in [
+            "",
+            "A",
+            "We are discovered, flee at once!",
+            "Встречаемся завтра в полдень, 42 гостя.",
+        ] {
+            let len = input.chars().count();
+            for size in 1..=len.max(2) {
+                if size > 1 {
+                    for offset in 0..size * 2 {
+                        assert_round_trip(input, rail_fence_order(len, size, offset).unwrap());
+                    }
+                }
+                if size <= len.max(1) {
+                    assert_round_trip(input, scytale_order(len, size).unwrap());
+                    for route in ROUTES {
+                        assert_round_trip(input, route_order(len, size, route).unwrap());
+                    }
+                }
+            }
+            for key in ["k", "zebras", "Кабала", "banana 42"] {
+                assert_round_trip(input, columnar_order(len, key).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn huge_sizes() {
+        let len = PLAINTEXT.len();
+        assert_eq!(
+            rail_fence_order(len, 3, usize::MAX).unwrap(),
+            rail_fence_order(len, 3, usize::MAX % 4).unwrap()
+        );
+
+        assert_eq!(
+            rail_fence_order(len, usize::MAX, 0)
+                .unwrap_err()
+                .to_string(),
+            "Number of rails must not exceed the input length."
+        );
+        assert_eq!(
+            scytale_order(len, usize::MAX).unwrap_err().to_string(),
+            "Diameter must not exceed the input length."
+        );
+        for route in ROUTES {
+            assert_eq!(
+                route_order(len, usize::MAX, route).unwrap_err().to_string(),
+                "Number of columns must not exceed the input length."
+            );
+        }
+        assert!(route_order(len, len + 1, Route::SpiralClockwise).is_err());
+    }
+}
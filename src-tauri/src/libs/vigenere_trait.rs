@@ -1,68 +1,84 @@
 use anyhow::{bail, Result};
-use regex::Regex;
-use std::collections::HashMap;
+use std::collections::VecDeque;
 
 use crate::{
-    traits::CharTrait,
-    utils::{get_alphabet, get_char_by_index, modulus, validate_lang, SupportedLanguages},
+    libs::alphabet::Alphabet,
+    utils::{get_char_by_index, modulus},
 };
 
 pub trait VigenereCipher {
-    fn cipher<F>(lang: SupportedLanguages, key: &str, input: &str, f: F) -> Result<String>
+    fn cipher<F>(lang: &Alphabet, key: &str, input: &str, f: F) -> Result<String>
     where
         F: Fn(i16, i16) -> i16,
     {
-        <Self as VigenereCipher>::validate_language(&lang, key, input)?;
+        <Self as VigenereCipher>::validate_language(lang, key, input)?;
 
-        let (alp, _, _, _, _, reg) = get_alphabet(&lang);
-
-        let map: HashMap<char, usize> =
-            HashMap::from_iter(alp.chars().enumerate().map(|(idx, elem)| (elem, idx)));
-
-        let key = key.to_lowercase();
-        let rg = Regex::new(reg).unwrap();
+        let key = <Self as VigenereCipher>::key_indexes(lang, key);
         let mut index = 0usize;
-        let mut cipher_text = String::new();
 
-        let key_len = key.chars().count();
-        let alp_len = alp.chars().count() as i16;
+        Ok(<Self as VigenereCipher>::walk(lang, input, |text_idx| {
+            let idx = f(text_idx, key[index % key.len()]);
+            index += 1;
+            idx
+        }))
+    }
 
-        for c in input.chars() {
-            if !rg.is_match(&c.to_string()) {
-                cipher_text.push(c);
-                continue;
-            }
+    /// Autokey variant of the cipher: the keyword is followed by the plaintext itself, so the
+    /// key never repeats. `decode` tells which of the texts is the plaintext.
+    fn autokey<F>(lang: &Alphabet, key: &str, input: &str, f: F, decode: bool) -> Result<String>
+    where
+        F: Fn(i16, i16) -> i16,
+    {
+        <Self as VigenereCipher>::validate_language(lang, key, input)?;
 
-            let key_idx = map
-                .get(&get_char_by_index(&key, index % key_len))
-                .unwrap()
-                .to_owned() as i16;
+        let alp_len = lang.length();
+        let mut key: VecDeque<i16> = <Self as VigenereCipher>::key_indexes(lang, key).into();
 
-            let text_idx = match c.is_lowercase() {
-                true => map.get(&c).unwrap(),
-                false => map.get(&c.to_lower_case()).unwrap(),
-            }
-            .to_owned() as i16;
+        Ok(<Self as VigenereCipher>::walk(lang, input, |text_idx| {
+            let idx = f(text_idx, key.pop_front().unwrap());
+            key.push_back(match decode {
+                true => modulus(idx, alp_len as i16),
+                false => text_idx,
+            });
+            idx
+        }))
+    }
 
-            let idx = f(text_idx, key_idx);
+    /// Replaces every letter of the alphabet with the letter at the index returned by `f` for
+    /// its index, keeping the case, other characters are left as is.
+    fn walk<F>(lang: &Alphabet, input: &str, mut f: F) -> String
+    where
+        F: FnMut(i16) -> i16,
+    {
+        let alp_len = lang.length() as i16;
 
-            let plain_char = get_char_by_index(alp, modulus(idx, alp_len));
-            cipher_text.push(match c.is_lowercase() {
-                true => plain_char,
-                false => plain_char.to_upper_case(),
-            });
+        input
+            .chars()
+            .map(|c| match lang.index_of(c) {
+                Some(text_idx) => {
+                    let idx = modulus(f(text_idx as i16), alp_len);
+                    match c.is_lowercase() {
+                        true => get_char_by_index(lang.lower(), idx),
+                        false => get_char_by_index(lang.upper(), idx),
+                    }
+                }
+                None => c,
+            })
+            .collect()
+    }
 
-            index += 1;
-        }
-        Ok(cipher_text)
+    fn key_indexes(lang: &Alphabet, key: &str) -> Vec<i16> {
+        key.chars()
+            .map(|c| lang.index_of(c).unwrap() as i16)
+            .collect()
     }
 
-    fn validate_language(lang: &SupportedLanguages, key: &str, input: &str) -> Result<()> {
+    fn validate_language(lang: &Alphabet, key: &str, input: &str) -> Result<()> {
         if input.is_empty() {
             bail!("Input is empty");
         };
 
-        if !validate_lang(key, lang) {
+        if !lang.is_word(key) {
             bail!("Invalid key");
         };
 
@@ -0,0 +1,503 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use x509_parser::{
+    certification_request::X509CertificationRequest,
+    cri_attributes::ParsedCriAttribute,
+    der_parser::asn1_rs::Tag,
+    extensions::{ExtendedKeyUsage, GeneralName, ParsedExtension, X509Extension},
+    objects::{oid2sn, oid_registry},
+    oid_registry::Oid,
+    pem::Pem,
+    prelude::{FromDer, X509Certificate},
+    public_key::PublicKey,
+    x509::{AlgorithmIdentifier, SubjectPublicKeyInfo},
+};
+
+use crate::utils::decode_der;
+
+#[derive(Deserialize, Copy, Clone, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum X509OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+pub struct CertificateInfo {
+    version: u32,
+    serial_number: String,
+    signature_algorithm: String,
+    issuer: String,
+    subject: String,
+    validity: ValidityInfo,
+    public_key: PublicKeyInfo,
+    extensions: ExtensionsInfo,
+    fingerprints: FingerprintsInfo,
+}
+
+#[derive(Serialize)]
+pub struct CsrInfo {
+    version: u32,
+    subject: String,
+    signature_algorithm: String,
+    public_key: PublicKeyInfo,
+    extensions: ExtensionsInfo,
+}
+
+#[derive(Serialize)]
+struct ValidityInfo {
+    not_before: String,
+    not_after: String,
+}
+
+#[derive(Serialize)]
+struct PublicKeyInfo {
+    algorithm: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    curve: Option<String>,
+    size: usize,
+}
+
+#[derive(Serialize, Default)]
+struct ExtensionsInfo {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    subject_alternative_names: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    key_usage: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    extended_key_usage: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    basic_constraints: Option<BasicConstraintsInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject_key_identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authority_key_identifier: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    other: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct BasicConstraintsInfo {
+    certificate_authority: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path_length: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct FingerprintsInfo {
+    #[serde(rename = "SHA-1")]
+    sha1: String,
+    #[serde(rename = "SHA-256")]
+    sha256: String,
+}
+
+/// Parses every certificate of a PEM bundle or of concatenated DER certificates.
+pub fn parse_certificates(input: &str) -> Result<Vec<CertificateInfo>> {
+    let mut certificates = Vec::new();
+
+    for der in read_der_blocks(input, &["CERTIFICATE", "X509 CERTIFICATE"])? {
+        let mut rest = der.as_slice();
+        while !rest.is_empty() {
+            let Ok((next, certificate)) = X509Certificate::from_der(rest) else {
+                bail!("Invalid X.509 certificate.");
+            };
+            certificates.push(certificate_info(
+                &certificate,
+                &rest[..rest.len() - next.len()],
+            ));
+            rest = next;
+        }
+    }
+
+    Ok(certificates)
+}
+
+pub fn parse_csr(input: &str) -> Result<CsrInfo> {
+    let blocks = read_der_blocks(input, &["CERTIFICATE REQUEST", "NEW CERTIFICATE REQUEST"])?;
+    let [der] = blocks.as_slice() else {
+        bail!(
+            "Expected a single certificate request, got {}.",
+            blocks.len()
+        );
+    };
+    let Ok((_, csr)) = X509CertificationRequest::from_der(der) else {
+        bail!("Invalid PKCS#10 certificate request.");
+    };
+    let info = &csr.certification_request_info;
+    let extensions = info
+        .iter_attributes()
+        .find_map(|attribute| match attribute.parsed_attribute() {
+            ParsedCriAttribute::ExtensionRequest(request) => Some(request.extensions.as_slice()),
+            _ => None,
+        })
+        .unwrap_or_default();
+
+    Ok(CsrInfo {
+        version: info.version.0 + 1,
+        subject: info.subject.to_string(),
+        signature_algorithm: algorithm_name(&csr.signature_algorithm),
+        public_key: public_key_info(&info.subject_pki),
+        extensions: extensions_info(extensions),
+    })
+}
+
+/// Writes JSON as is, text output lists the same fields one per line with nested values indented.
+pub fn render<T: Serialize>(value: &T, format: X509OutputFormat) -> Result<String> {
+    let value = serde_json::to_value(value)?;
+
+    Ok(match format {
+        X509OutputFormat::Json => serde_json::to_string_pretty(&value)?,
+        X509OutputFormat::Text => {
+            let mut out = String::new();
+            match &value {
+                Value::Array(items) => {
+                    for (i, item) in items.iter().enumerate() {
+                        out.push_str(&format!("Certificate {}:\n", i + 1));
+                        write_text(&mut out, item, 1);
+                    }
+                }
+                _ => write_text(&mut out, &value, 0),
+            }
+            out.trim_end().to_string()
+        }
+    })
+}
+
+fn read_der_blocks(input: &str, labels: &[&str]) -> Result<Vec<Vec<u8>>> {
+    let input = input.trim();
+    if !input.starts_with("-----BEGIN ") {
+        return Ok(vec![decode_der(input)?]);
+    }
+
+    let mut blocks = Vec::new();
+    for pem in Pem::iter_from_buffer(input.as_bytes()) {
+        let Ok(pem) = pem else {
+            bail!("Invalid PEM block.");
+        };
+        if !labels.contains(&pem.label.as_str()) {
+            bail!("Unexpected PEM label \"{}\".", pem.label);
+        }
+        blocks.push(pem.contents);
+    }
+
+    Ok(blocks)
+}
+
+fn certificate_info(certificate: &X509Certificate, der: &[u8]) -> CertificateInfo {
+    let validity = certificate.validity();
+
+    CertificateInfo {
+        version: certificate.version().0 + 1,
+        serial_number: colon_hex(certificate.raw_serial()),
+        signature_algorithm: algorithm_name(&certificate.signature_algorithm),
+        issuer: certificate.issuer().to_string(),
+        subject: certificate.subject().to_string(),
+        validity: ValidityInfo {
+            not_before: validity.not_before.to_string(),
+            not_after: validity.not_after.to_string(),
+        },
+        public_key: public_key_info(certificate.public_key()),
+        extensions: extensions_info(certificate.extensions()),
+        fingerprints: FingerprintsInfo {
+            sha1: colon_hex(&Sha1::digest(der)),
+            sha256: colon_hex(&Sha256::digest(der)),
+        },
+    }
+}
+
+fn public_key_info(spki: &SubjectPublicKeyInfo) -> PublicKeyInfo {
+    let curve = spki
+        .algorithm
+        .parameters
+        .as_ref()
+        .filter(|parameters| parameters.tag() == Tag::Oid)
+        .and_then(|parameters| parameters.as_oid().ok())
+        .map(|oid| oid_name(&oid));
+
+    let size = match spki.parsed() {
+        Ok(PublicKey::GostR3410_2012(key)) => key.len() * 4,
+        Ok(key) if key.key_size() > 0 => key.key_size(),
+        _ => spki.subject_public_key.data.len() * 8,
+    };
+
+    PublicKeyInfo {
+        algorithm: oid_name(&spki.algorithm.algorithm),
+        curve,
+        size,
+    }
+}
+
+fn extensions_info(extensions: &[X509Extension]) -> ExtensionsInfo {
+    let mut info = ExtensionsInfo::default();
+
+    for extension in extensions {
+        match extension.parsed_extension() {
+            ParsedExtension::SubjectAlternativeName(san) => {
+                info.subject_alternative_names =
+                    san.general_names.iter().map(general_name).collect();
+            }
+            ParsedExtension::KeyUsage(usage) => {
+                info.key_usage = usage.to_string().split(", ").map(String::from).collect();
+            }
+            ParsedExtension::ExtendedKeyUsage(usage) => {
+                info.extended_key_usage = extended_key_usage(usage);
+            }
+            ParsedExtension::BasicConstraints(constraints) => {
+                info.basic_constraints = Some(BasicConstraintsInfo {
+                    certificate_authority: constraints.ca,
+                    path_length: constraints.path_len_constraint,
+                });
+            }
+            ParsedExtension::SubjectKeyIdentifier(id) => {
+                info.subject_key_identifier = Some(colon_hex(id.0));
+            }
+            ParsedExtension::AuthorityKeyIdentifier(aki) => {
+                info.authority_key_identifier =
+                    aki.key_identifier.as_ref().map(|id| colon_hex(id.0));
+            }
+            _ => info.other.push(oid_name(&extension.oid)),
+        }
+    }
+
+    info
+}
+
+fn extended_key_usage(usage: &ExtendedKeyUsage) -> Vec<String> {
+    let known = [
+        (usage.any, "Any Extended Key Usage"),
+        (usage.server_auth, "TLS Web Server Authentication"),
+        (usage.client_auth, "TLS Web Client Authentication"),
+        (usage.code_signing, "Code Signing"),
+        (usage.email_protection, "E-mail Protection"),
+        (usage.time_stamping, "Time Stamping"),
+        (usage.ocsp_signing, "OCSP Signing"),
+    ];
+
+    known
+        .iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, name)| name.to_string())
+        .chain(usage.other.iter().map(oid_name))
+        .collect()
+}
+
+fn general_name(name: &GeneralName) -> String {
+    match name {
+        GeneralName::DNSName(dns) => format!("DNS:{dns}"),
+        GeneralName::RFC822Name(email) => format!("email:{email}"),
+        GeneralName::URI(uri) => format!("URI:{uri}"),
+        GeneralName::DirectoryName(dn) => format!("DirName:{dn}"),
+        GeneralName::RegisteredID(oid) => format!("Registered ID:{}", oid_name(oid)),
+        GeneralName::IPAddress(ip) => match <[u8; 4]>::try_from(*ip) {
+            Ok(v4) => format!("IP Address:{}", Ipv4Addr::from(v4)),
+            Err(_) => match <[u8; 16]>::try_from(*ip) {
+                Ok(v6) => format!("IP Address:{}", Ipv6Addr::from(v6)),
+                Err(_) => format!("IP Address:{}", colon_hex(ip)),
+            },
+        },
+        other => other.to_string(),
+    }
+}
+
+fn algorithm_name(algorithm: &AlgorithmIdentifier) -> String {
+    oid_name(&algorithm.algorithm)
+}
+
+pub fn oid_name(oid: &Oid) -> String {
+    match oid2sn(oid, oid_registry()) {
+        Ok(name) => name.to_string(),
+        Err(_) => oid.to_id_string(),
+    }
+}
+
+fn colon_hex(data: &[u8]) -> String {
+    data.iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+fn write_text(out: &mut String, value: &Value, depth: usize) {
+    let Value::Object(fields) = value else {
+        return;
+    };
+    let indent = "    ".repeat(depth);
+
+    for (key, value) in fields {
+        let mut label = key.replace('_', " ");
+        label[..1].make_ascii_uppercase();
+
+        match value {
+            Value::Object(_) => {
+                out.push_str(&format!("{indent}{label}:\n"));
+                write_text(out, value, depth + 1);
+            }
+            Value::Array(items) => {
+                out.push_str(&format!("{indent}{label}:\n"));
+                for item in items {
+                    out.push_str(&format!("{indent}    {}\n", scalar(item)));
+                }
+            }
+            _ => out.push_str(&format!("{indent}{label}: {}\n", scalar(value))),
+        }
+    }
+}
+
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Self-signed P-256 certificate and request made with OpenSSL 3.5.
+    const CERT_PEM: &str = r"-----BEGIN CERTIFICATE-----
+MIICGTCCAb6gAwIBAgICEjQwCgYIKoZIzj0EAwIwOTELMAkGA1UEBhMCR0IxFDAS
+BgNVBAoMC0V4YW1wbGUgTHRkMRQwEgYDVQQDDAtleGFtcGxlLmNvbTAeFw0yNjEw
+MTkxMTA3MDJaFw0zNjEwMTYxMTA3MDJaMDkxCzAJBgNVBAYTAkdCMRQwEgYDVQQK
+DAtFeGFtcGxlIEx0ZDEUMBIGA1UEAwwLZXhhbXBsZS5jb20wWTATBgcqhkjOPQIB
+BggqhkjOPQMBBwNCAAQKIr8nRA7ZLE7yi4xKVGt6OUSZuizxhiDXFknkdk+D7FYs
+Y1WPD1j0TI0fRgN9xpqXaybG4w3vilbCHwrNCqQ0o4G1MIGyMB0GA1UdDgQWBBTr
+N5oxqE+ZVRrLbKL0Zx5nengHLzAfBgNVHSMEGDAWgBTrN5oxqE+ZVRrLbKL0Zx5n
+engHLzAtBgNVHREEJjAkggtleGFtcGxlLmNvbYIPd3d3LmV4YW1wbGUuY29thwTA
+AAIBMA4GA1UdDwEB/wQEAwIChDAdBgNVHSUEFjAUBggrBgEFBQcDAQYIKwYBBQUH
+AwIwEgYDVR0TAQH/BAgwBgEB/wIBADAKBggqhkjOPQQDAgNJADBGAiEA2Ost5M4v
+5LB3+swiZWLq9w1SvJiGR++3w0risDzvhVACIQCc6TCcYAi1AxG076IvFvSPC5Nw
+GUJnUTxec0A/WBuLqg==
+-----END CERTIFICATE-----";
+
+    const CSR_PEM: &str = r"-----BEGIN CERTIFICATE REQUEST-----
+MIIBPTCB4wIBADA5MQswCQYDVQQGEwJHQjEUMBIGA1UECgwLRXhhbXBsZSBMdGQx
+FDASBgNVBAMMC2V4YW1wbGUuY29tMFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAE
+CiK/J0QO2SxO8ouMSlRrejlEmbos8YYg1xZJ5HZPg+xWLGNVjw9Y9EyNH0YDfcaa
+l2smxuMN74pWwh8KzQqkNKBIMEYGCSqGSIb3DQEJDjE5MDcwKAYDVR0RBCEwH4IL
+ZXhhbXBsZS5jb22HECABDbgAAAAAAAAAAAAAAAEwCwYDVR0PBAQDAgeAMAoGCCqG
+SM49BAMCA0kAMEYCIQDKdZGnh2K/Zqv58a4vaQsN3sy414aQi1NozCCLLWkQ8gIh
+AMgFcHD83BM8xFhYxnbBI9RBYhOWJzN0n+KKikPoDTiw
+-----END CERTIFICATE REQUEST-----";
+
+    #[test]
+    fn certificate() {
+        let certificates = parse_certificates(CERT_PEM).unwrap();
+        assert_eq!(
+            render(&certificates, X509OutputFormat::Text).unwrap(),
+            "\
+Certificate 1:
+    Version: 3
+    Serial number: 12:34
+    Signature algorithm: ecdsa-with-SHA256
+    Issuer: C=GB, O=Example Ltd, CN=example.com
+    Subject: C=GB, O=Example Ltd, CN=example.com
+    Validity:
+        Not before: Oct 19 11:07:02 2026 +00:00
+        Not after: Oct 16 11:07:02 2036 +00:00
+    Public key:
+        Algorithm: id-ecPublicKey
+        Curve: prime256v1
+        Size: 256
+    Extensions:
+        Subject alternative names:
+            DNS:example.com
+            DNS:www.example.com
+            IP Address:192.0.2.1
+        Key usage:
+            Digital Signature
+            Key Cert Sign
+        Extended key usage:
+            TLS Web Server Authentication
+            TLS Web Client Authentication
+        Basic constraints:
+            Certificate authority: true
+            Path length: 0
+        Subject key identifier: eb:37:9a:31:a8:4f:99:55:1a:cb:6c:a2:f4:67:1e:67:7a:78:07:2f
+        Authority key identifier: eb:37:9a:31:a8:4f:99:55:1a:cb:6c:a2:f4:67:1e:67:7a:78:07:2f
+    Fingerprints:
+        SHA-1: b6:a7:34:48:30:50:15:70:ce:21:75:c6:70:d1:b3:fc:8b:48:5d:e0
+        SHA-256: 9d:4d:96:09:e4:16:a9:1a:8f:f6:f6:0e:e5:4c:98:a3:3a:48:b0:da:0e:ba:eb:a0:ff:69:9e:d8:55:02:33:e2"
+        );
+
+        let json: Value =
+            serde_json::from_str(&render(&certificates, X509OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(json[0]["serial_number"], "12:34");
+        assert_eq!(json[0]["extensions"]["basic_constraints"]["path_length"], 0);
+        assert_eq!(
+            json[0]["fingerprints"]["SHA-256"],
+            "9d:4d:96:09:e4:16:a9:1a:8f:f6:f6:0e:e5:4c:98:a3:3a:48:b0:da:0e:ba:eb:a0:ff:69:9e:d8:55:02:33:e2"
+        );
+    }
+
+    #[test]
+    fn certificate_bundle() {
+        let base64: String = CERT_PEM
+            .lines()
+            .filter(|x| !x.starts_with("-----"))
+            .collect();
+        let der = decode_der(&base64).unwrap();
+        let concatenated = crate::utils::to_hex(&[der.clone(), der].concat());
+        let bundle = format!("{CERT_PEM}\n{CERT_PEM}");
+
+        for (input, count) in [(base64.as_str(), 1), (&concatenated, 2), (&bundle, 2)] {
+            let certificates = parse_certificates(input).unwrap();
+            assert_eq!(certificates.len(), count);
+            assert_eq!(
+                render(&certificates[count - 1], X509OutputFormat::Json).unwrap(),
+                render(
+                    &parse_certificates(CERT_PEM).unwrap()[0],
+                    X509OutputFormat::Json
+                )
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn certificate_request() {
+        assert_eq!(
+            render(&parse_csr(CSR_PEM).unwrap(), X509OutputFormat::Text).unwrap(),
+            "\
+Version: 1
+Subject: C=GB, O=Example Ltd, CN=example.com
+Signature algorithm: ecdsa-with-SHA256
+Public key:
+    Algorithm: id-ecPublicKey
+    Curve: prime256v1
+    Size: 256
+Extensions:
+    Subject alternative names:
+        DNS:example.com
+        IP Address:2001:db8::1
+    Key usage:
+        Digital Signature"
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let truncated =
+            "-----BEGIN CERTIFICATE-----\nMIICGTCCAb6gAwIBAgICEjQw\n-----END CERTIFICATE-----";
+        let requests = format!("{CSR_PEM}\n{CSR_PEM}");
+        for (input, error) in [
+            (truncated, "Invalid X.509 certificate."),
+            (CSR_PEM, "Unexpected PEM label \"CERTIFICATE REQUEST\"."),
+        ] {
+            assert_eq!(parse_certificates(input).err().unwrap().to_string(), error);
+        }
+        assert_eq!(
+            parse_csr(&requests).err().unwrap().to_string(),
+            "Expected a single certificate request, got 2."
+        );
+        assert_eq!(
+            parse_csr(CERT_PEM).err().unwrap().to_string(),
+            "Unexpected PEM label \"CERTIFICATE\"."
+        );
+    }
+}
@@ -1,13 +1,9 @@
 use crate::{
-    create_info_struct, run_op,
-    traits::{CharTrait, IntegerTrait},
-    utils::{
-        get_alphabet, get_char_by_index, get_index_by_char, mod_inv, modulus, validate_lang,
-        SupportedLanguages,
-    },
-    Operation, DOCS_URL,
+    create_info_struct,
+    libs::{alphabet::Alphabet, ciphers::affine_cipher_decode},
+    run_op, Operation, DOCS_URL,
 };
-use anyhow::{anyhow, bail, Result};
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
 run_op!(run_affinecipherdecode, AffineCipherDecode);
@@ -16,50 +12,15 @@ impl Operation<'_, DeserializeMeDaddy> for AffineCipherDecode {
     fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
         let request = self.validate(request)?;
 
-        if !validate_lang(input, &request.lang) {
-            bail!("Wrong language.");
-        };
+        let DeserializeMeDaddy { lang, a, b } = request;
 
-        let (a, b) = (request.a as i16, request.b as i16);
-
-        let (alp_lower, alp_upper, _, _, alp_length, _) = get_alphabet(&request.lang);
-        if a.gcd(&(alp_length as i16)) != 1 {
-            return Err(anyhow!(
-                "The value of `a` must be coprime to alphabet length({}).",
-                alp_length
-            ));
-        }
-
-        let mut output = String::with_capacity(alp_length as usize);
-
-        for c in input.chars() {
-            if !c.is_alphabetic() {
-                output.push(c);
-                continue;
-            }
-
-            let y = match c.is_lowercase() {
-                true => get_index_by_char(alp_lower, c),
-                false => get_index_by_char(alp_upper, c),
-            } as i16;
-
-            let inv_a = mod_inv(a, alp_length as i16);
-
-            let x = modulus(inv_a * (y - b), alp_length as i16);
-
-            output.push(match c.is_lowercase() {
-                true => get_char_by_index(alp_lower, x),
-                false => get_char_by_index(alp_upper, x).to_upper_case(),
-            });
-        }
-
-        Ok(output)
+        affine_cipher_decode(input, &lang, a as i16, b as i16)
     }
 }
 
 #[derive(Deserialize)]
 struct DeserializeMeDaddy {
-    lang: SupportedLanguages,
+    lang: Alphabet,
     a: u8,
     b: u8,
 }
@@ -75,7 +36,7 @@ struct DeserializeMeDaddy {
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages
+///         "lang": Alphabet
 ///         "a": u8,
 ///         "b": u8,
 ///     }
@@ -83,7 +44,7 @@ struct DeserializeMeDaddy {
 /// ```
 /// #### where
 ///     - u8 is unsigned 8-bit integer (digit between 0 and 255)
-///     - SupportedLanguages is enum of en, ru, ru_with_yo
+///     - Alphabet is one of the presets en, ru, ru_with_yo, uk, de or a custom string of the alphabet symbols
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
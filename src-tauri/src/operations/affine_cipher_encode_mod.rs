@@ -1,7 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    create_info_struct, libs::ciphers::affine_cipher_encode as ace, utils::SupportedLanguages,
+    create_info_struct,
+    libs::{alphabet::Alphabet, ciphers::affine_cipher_encode as ace},
     Operation, DOCS_URL,
 };
 use anyhow::Result;
@@ -21,13 +22,13 @@ impl Operation<'_, DeserializeMeDaddy> for AffineCipherEncode {
 
         let DeserializeMeDaddy { lang, a, b } = request;
 
-        ace(input, lang, a, b)
+        ace(input, &lang, a, b)
     }
 }
 
 #[derive(Deserialize)]
 struct DeserializeMeDaddy {
-    lang: SupportedLanguages,
+    lang: Alphabet,
     a: i16,
     b: i16,
 }
@@ -43,7 +44,7 @@ struct DeserializeMeDaddy {
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages
+///         "lang": Alphabet
 ///         "a": u8,
 ///         "b": u8,
 ///     }
@@ -51,7 +52,7 @@ struct DeserializeMeDaddy {
 /// ```
 /// #### where
 ///     - u8 is unsigned 8-bit integer (digit between 0 and 255)
-///     - SupportedLanguages is enum of en, ru, ru_with_yo
+///     - Alphabet is one of the presets en, ru, ru_with_yo, uk, de or a custom string of the alphabet symbols
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::block_cipher::{decode_input, InputFormat},
+    run_op,
+    utils::to_hex,
+    Operation, DOCS_URL,
+};
+
+run_op!(run_analyseblocks, AnalyseBlocks);
+
+impl Operation<'_, DeserializeMeDaddy> for AnalyseBlocks {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            block_size,
+            input_format,
+        } = request;
+
+        let data = decode_input(input, input_format)?;
+        if data.is_empty() {
+            bail!("Input is empty.");
+        }
+
+        let mut first_seen: HashMap<&[u8], usize> = HashMap::new();
+        let mut repeated = 0;
+        let mut lines = Vec::new();
+
+        for (idx, block) in data.chunks(block_size).enumerate() {
+            let mut line = format!("{idx}: {}", to_hex(block));
+            if block.len() != block_size {
+                line.push_str(" (incomplete)");
+            } else if let Some(first) = first_seen.get(block) {
+                repeated += 1;
+                line.push_str(&format!(" (repeats block {first})"));
+            } else {
+                first_seen.insert(block, idx);
+            }
+            lines.push(line);
+        }
+
+        let ecb = match repeated {
+            0 => "no",
+            _ => "yes",
+        };
+
+        Ok(format!(
+            "Block size: {block_size} bytes\nTotal blocks: {}\nUnique blocks: {}\nRepeated blocks: {repeated}\nECB mode likely: {ecb}\n\n{}",
+            lines.len(),
+            lines.len() - repeated,
+            lines.join("\n")
+        ))
+    }
+
+    fn validate(&self, request: &'_ str) -> Result<DeserializeMeDaddy> {
+        let request = self.deserialize(request)?;
+
+        if request.block_size == 0 {
+            bail!("Block size must be greater than zero.");
+        }
+
+        Ok(request)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    block_size: usize,
+    input_format: InputFormat,
+}
+
+/// Splits the input into blocks of the given size and looks for identical blocks. Repeated ciphertext blocks mean that identical plaintext blocks were encrypted with the same key, which is the distinctive trait of ECB mode.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Block_cipher_mode_of_operation#Electronic_codebook_(ECB)).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/AnalyseBlocks with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "block_size": usize,
+///         "input_format": InputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - usize is block size in bytes, 8 for DES-like and 16 for AES-like ciphers
+///     - InputFormat is enum of "hex", "base64", "raw"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/AnalyseBlocks
+///
+/// {
+///     "input": "66e43480bc9810be2a263e6a5a69104666e43480bc9810be2a263e6a5a691046086f9a1d74c94d4e",
+///     "params": {
+///         "block_size": 8,
+///         "input_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Block size: 8 bytes\nTotal blocks: 5\nUnique blocks: 3\nRepeated blocks: 2\nECB mode likely: yes\n\n0: 66e43480bc9810be\n1: 2a263e6a5a691046\n2: 66e43480bc9810be (repeats block 0)\n3: 2a263e6a5a691046 (repeats block 1)\n4: 086f9a1d74c94d4e"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/AnalyseBlocks
+///
+/// {
+///     "input": "0011223344556677",
+///     "params": {
+///         "block_size": 0,
+///         "input_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Block size must be greater than zero."
+/// }
+/// ```
+pub struct AnalyseBlocks;
+
+const NAME: &str = "AnalyseBlocks";
+const DESCRIPTION_EN: &str = "Splits the input into blocks of the given size and looks for identical blocks. Repeated ciphertext blocks mean that identical plaintext blocks were encrypted with the same key, which is the distinctive trait of ECB mode.";
+const DESCRIPTION_RU: &str = "Разбивает входные данные на блоки заданного размера и ищет одинаковые блоки. Повторяющиеся блоки шифртекста означают, что одинаковые блоки открытого текста были зашифрованы на одном ключе, что характерно для режима ECB.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Block_cipher_mode_of_operation#Electronic_codebook_(ECB)");
+
+create_info_struct!(
+    AnalyseBlocksInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::block_cipher::{
+        BlockCipher, BlockCipherFunction, InputFormat, Mode, OutputFormat, Padding,
+    },
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_ariadecrypt, ARIADecrypt);
+
+impl Operation<'_, DeserializeMeDaddy> for ARIADecrypt {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            key,
+            key_format,
+            iv,
+            iv_format,
+            mode,
+            padding,
+            input_format,
+            output_format,
+        } = request;
+
+        let cipher = BlockCipher::new(BlockCipherFunction::ARIA)
+            .set_input(input, input_format)?
+            .set_iv(&convert_to_byte_array(&iv, &iv_format)?)?
+            .set_key(&convert_to_byte_array(&key, &key_format)?)?
+            .set_mode(mode)
+            .set_padding(padding);
+
+        cipher.decrypt(output_format)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    key_format: SupportedFormats,
+    iv: String,
+    iv_format: SupportedFormats,
+    mode: Mode,
+    padding: Padding,
+    input_format: InputFormat,
+    output_format: OutputFormat,
+}
+
+/// ARIA is a block cipher designed in 2003 by a large group of South Korean researchers and established as a Korean standard (KS X 1213). It has a block size of 128 bits and key sizes of 128, 192 and 256 bits.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/ARIA_(cipher)).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ARIADecrypt with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "iv": string,
+///         "iv_format": SupportedFormats,
+///         "mode": Mode,
+///         "padding": Padding,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Mode is enum of "CBC", "CTR", "OFB", "CFB", "CFB8", "ECB", "XTS"
+///     - Padding is enum of "pkcs7", "iso7816", "x923", "iso10126" (alias "random"), "zero", "none" (used only in "CBC" and "ECB" modes)
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw"
+///     - key must be 16, 24 or 32 bytes long
+///     - iv must be 16 bytes long, it is ignored in "ECB" mode
+///     - in "XTS" mode key is a concatenation of data and tweak keys and iv is the tweak
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ARIADecrypt
+///
+/// {
+///     "input": "7460828e40b973a6cd141eb999112cbf",
+///     "params": {
+///         "key": "0123456789abcdeffedcba9876543210",
+///         "key_format": "hex",
+///         "iv": "00112233445566778899aabbccddeeff",
+///         "iv_format": "hex",
+///         "mode": "CBC",
+///         "padding": "pkcs7",
+///         "input_format": "hex",
+///         "output_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hello world"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ARIADecrypt
+///
+/// {
+///     "input": "7460828e40b973a6cd141eb999112cbf",
+///     "params": {
+///         "key": "0011",
+///         "key_format": "hex",
+///         "iv": "00112233445566778899aabbccddeeff",
+///         "iv_format": "hex",
+///         "mode": "CBC",
+///         "padding": "pkcs7",
+///         "input_format": "hex",
+///         "output_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid key length for ARIA: expected 16, 24 or 32 bytes, got 2 bytes."
+/// }
+/// ```
+pub struct ARIADecrypt;
+
+const NAME: &str = "ARIADecrypt";
+const DESCRIPTION_EN: &str = "ARIA is a block cipher designed in 2003 by a large group of South Korean researchers and established as a Korean standard (KS X 1213). It has a block size of 128 bits and key sizes of 128, 192 and 256 bits.";
+const DESCRIPTION_RU: &str = "ARIA — блочный шифр, разработанный в 2003 году большой группой южнокорейских исследователей и утверждённый в качестве корейского стандарта (KS X 1213). Размер блока составляет 128 бит, размер ключа — 128, 192 или 256 бит.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/ARIA_(cipher)");
+
+create_info_struct!(
+    ARIADecryptInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    create_info_struct, libs::ciphers::affine_cipher_encode, run_op, utils::SupportedLanguages,
-    Operation, DOCS_URL,
+    create_info_struct,
+    libs::{alphabet::Alphabet, ciphers::affine_cipher_encode},
+    run_op, Operation, DOCS_URL,
 };
 use anyhow::Result;
 
@@ -13,17 +14,14 @@ impl Operation<'_, DeserializeMeDaddy> for AtbashCipher {
         let request = self.validate(request)?;
         let lang = request.lang;
 
-        match lang {
-            SupportedLanguages::EN => affine_cipher_encode(input, lang, 25, 25),
-            SupportedLanguages::RU => affine_cipher_encode(input, lang, 31, 31),
-            SupportedLanguages::RU_WITH_YO => affine_cipher_encode(input, lang, 32, 32),
-        }
+        let last = lang.length() as i16 - 1;
+        affine_cipher_encode(input, &lang, last, last)
     }
 }
 
 #[derive(Deserialize)]
 struct DeserializeMeDaddy {
-    lang: SupportedLanguages,
+    lang: Alphabet,
 }
 
 /// Atbash is a mono-alphabetic substitution cipher originally used to encode the Hebrew alphabet. It has been modified here for use with the Latin alphabet and Cyrillic.
@@ -38,12 +36,12 @@ struct DeserializeMeDaddy {
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguage,
+///         "lang": Alphabet,
 ///     }
 /// }
 /// ```
 /// #### where
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -0,0 +1,175 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, vigenere_trait::VigenereCipher},
+    run_op,
+    utils::sub,
+    Operation, DOCS_URL,
+};
+
+impl VigenereCipher for AutokeyCipherDecode {}
+
+run_op!(run_autokeycipherdecode, AutokeyCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for AutokeyCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, key } = request;
+
+        <Self as VigenereCipher>::autokey(&lang, &key, input, sub, true)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    key: String,
+}
+
+/// The Autokey cipher is a Vigenère cipher whose key is the keyword followed by the plaintext itself, so unlike the Vigenère cipher the key never repeats. Only the letters of the alphabet are encrypted and used as the key.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Autokey_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/AutokeyCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "key": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/AutokeyCipherDecode
+///
+/// {
+///     "input": "Qnxepv yt wtwp",
+///     "params": {
+///         "lang": "en",
+///         "key": "queenly"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Attack at dawn"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/AutokeyCipherDecode
+///
+/// {
+///     "input": "Ъщжазитхсия тм бяюстегц",
+///     "params": {
+///         "lang": "ru",
+///         "key": "шифр"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Встречаемся на рассвете"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/AutokeyCipherDecode
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "lang": "en",
+///         "key": "queenly"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Input is empty"
+/// }
+/// ```
+pub struct AutokeyCipherDecode;
+
+const NAME: &str = "AutokeyCipherDecode";
+const DESCRIPTION_EN: &str = "The Autokey cipher is a Vigenère cipher whose key is the keyword followed by the plaintext itself, so unlike the Vigenère cipher the key never repeats. Only the letters of the alphabet are encrypted and used as the key.";
+const DESCRIPTION_RU: &str = "Шифр с автоключом — это шифр Виженера, ключ которого состоит из ключевого слова, за которым следует сам открытый текст, поэтому, в отличие от шифра Виженера, ключ никогда не повторяется. Шифруются и используются в качестве ключа только буквы алфавита.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Autokey_cipher");
+
+create_info_struct!(
+    AutokeyCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_autokeycipherencode, AutokeyCipherEncode};
+
+    fn request(lang: &str, key: &str) -> String {
+        format!(r#"{{"lang": "{}", "key": "{}"}}"#, lang, key)
+    }
+
+    #[test]
+    fn known_ciphertext() {
+        let request = request("en", "QUEENLY");
+        assert_eq!(
+            run_autokeycipherencode(AutokeyCipherEncode, "ATTACKATDAWN", &request).unwrap(),
+            "QNXEPVYTWTWP"
+        );
+        assert_eq!(
+            run_autokeycipherdecode(AutokeyCipherDecode, "QNXEPVYTWTWP", &request).unwrap(),
+            "ATTACKATDAWN"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for (input, lang, key) in [
+            ("Attack at Dawn, 42 men!", "en", "Queenly"),
+            (
+                "Съешь же ещё этих мягких французских булок, 42 раза!",
+                "ru_with_yo",
+                "Шифр",
+            ),
+        ] {
+            let request = request(lang, key);
+            let encoded = run_autokeycipherencode(AutokeyCipherEncode, input, &request).unwrap();
+            assert_ne!(encoded, input);
+            assert_eq!(
+                run_autokeycipherdecode(AutokeyCipherDecode, &encoded, &request).unwrap(),
+                input
+            );
+        }
+    }
+}
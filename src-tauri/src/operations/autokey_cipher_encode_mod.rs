@@ -0,0 +1,132 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, vigenere_trait::VigenereCipher},
+    run_op,
+    utils::add,
+    Operation, DOCS_URL,
+};
+
+impl VigenereCipher for AutokeyCipherEncode {}
+
+run_op!(run_autokeycipherencode, AutokeyCipherEncode);
+
+impl Operation<'_, DeserializeMeDaddy> for AutokeyCipherEncode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, key } = request;
+
+        <Self as VigenereCipher>::autokey(&lang, &key, input, add, false)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    key: String,
+}
+
+/// The Autokey cipher is a Vigenère cipher whose key is the keyword followed by the plaintext itself, so unlike the Vigenère cipher the key never repeats. Only the letters of the alphabet are encrypted and used as the key.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Autokey_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/AutokeyCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "key": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/AutokeyCipherEncode
+///
+/// {
+///     "input": "Attack at dawn",
+///     "params": {
+///         "lang": "en",
+///         "key": "queenly"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Qnxepv yt wtwp"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/AutokeyCipherEncode
+///
+/// {
+///     "input": "Встречаемся на рассвете",
+///     "params": {
+///         "lang": "ru",
+///         "key": "шифр"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Ъщжазитхсия тм бяюстегц"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/AutokeyCipherEncode
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "lang": "en",
+///         "key": "queenly"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Input is empty"
+/// }
+/// ```
+pub struct AutokeyCipherEncode;
+
+const NAME: &str = "AutokeyCipherEncode";
+const DESCRIPTION_EN: &str = "The Autokey cipher is a Vigenère cipher whose key is the keyword followed by the plaintext itself, so unlike the Vigenère cipher the key never repeats. Only the letters of the alphabet are encrypted and used as the key.";
+const DESCRIPTION_RU: &str = "Шифр с автоключом — это шифр Виженера, ключ которого состоит из ключевого слова, за которым следует сам открытый текст, поэтому, в отличие от шифра Виженера, ключ никогда не повторяется. Шифруются и используются в качестве ключа только буквы алфавита.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Autokey_cipher");
+
+create_info_struct!(
+    AutokeyCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,9 +1,10 @@
 use crate::{
     create_info_struct,
-    libs::bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
-    run_op,
-    utils::SupportedLanguages,
-    Operation, DOCS_URL,
+    libs::{
+        alphabet::Alphabet,
+        bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
+    },
+    run_op, Operation, DOCS_URL,
 };
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -42,7 +43,7 @@ struct DeserializeMeDaddy {
     bacon_alphabet: SupportedBaconAlphabet,
     translation: SupportedBaconTranslation,
     invert_translation: bool,
-    lang: SupportedLanguages,
+    lang: Alphabet,
 }
 
 /// Bacon's cipher or the Baconian cipher is a method of steganography devised by Francis Bacon in 1605. A message is concealed in the presentation of text, rather than its content.
@@ -61,14 +62,14 @@ struct DeserializeMeDaddy {
 ///         bacon_alphabet: SupportedBaconAlphabet,
 ///         translation: SupportedBaconTranslation,
 ///         invert_translation: bool,
-///         lang: SupportedLanguages,
+///         lang: Alphabet,
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - SupportedBaconAlphabet is enum of "Standard", "Complete"
 ///     - SupportedBaconTranslation is enum of "0/1", "A/B"
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo"
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
@@ -157,3 +158,71 @@ create_info_struct!(
     DESCRIPTION_RU,
     INFO_URL
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_baconcipherencode, BaconCipherEncode};
+
+    fn encode(input: &str, lang: &str, alphabet: &str, translation: &str) -> String {
+        let request = format!(
+            r#"{{"bacon_alphabet": "{}", "translation": "{}", "keep_extra_character": false, "invert_translation": false, "lang": "{}"}}"#,
+            alphabet, translation, lang
+        );
+        run_baconcipherencode(BaconCipherEncode, input, &request).unwrap()
+    }
+
+    fn decode(input: &str, lang: &str, alphabet: &str, translation: &str) -> String {
+        let request = format!(
+            r#"{{"bacon_alphabet": "{}", "translation": "{}", "invert_translation": false, "lang": "{}"}}"#,
+            alphabet, translation, lang
+        );
+        run_baconcipherdecode(BaconCipherDecode, input, &request).unwrap()
+    }
+
+    #[test]
+    fn preset_codes() {
+        assert_eq!(
+            encode("Hello", "en", "Standard", "A/B"),
+            "AABBB AABAA ABABA ABABA ABBAB"
+        );
+        assert_eq!(
+            encode("Привет, Мир!", "ru", "Standard", "A/B"),
+            "ABBBBA BAAAAA ABAAAA AAABAA AABABA BAABAA ABBAAA ABAAAA BAAAAA"
+        );
+        assert_eq!(
+            encode("Привет, Мир!", "ru_with_yo", "Complete", "0/1"),
+            "100000 100010 010010 000100 001010 100110 011010 010010 100010"
+        );
+        assert_eq!(
+            encode("Мягких", "ru_with_yo", "Standard", "A/B"),
+            "ABBABA BAAAAAA AAABBA ABABBA ABAABA BABBAA"
+        );
+        assert_eq!(
+            decode(
+                "ABBBBA BAAAAA ABAAAA AAABAA AABABA BAABAA",
+                "ru",
+                "Standard",
+                "A/B"
+            ),
+            "привет"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for (input, lang, expected) in [
+            ("Hello, World!", "en", "helloworld"),
+            ("Съешь же ещё", "ru_with_yo", "съешьжеещё"),
+            ("Їжак і ґудзик", "uk", "їжакіґудзик"),
+            ("Grüße aus Köln", "de", "grüßeausköln"),
+            ("ΑΒΓΔ αβγδ", "αβγδ", "αβγδαβγδ"),
+        ] {
+            for translation in ["A/B", "0/1"] {
+                let encoded = encode(input, lang, "Complete", translation);
+                assert_eq!(decode(&encoded, lang, "Complete", translation), expected);
+            }
+        }
+        assert_eq!(encode("αδ", "αβγδ", "Complete", "0/1"), "00 11");
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     create_info_struct,
-    libs::bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
-    run_op,
-    utils::SupportedLanguages,
-    Operation, DOCS_URL,
+    libs::{
+        alphabet::Alphabet,
+        bacon::{BaconCipher, SupportedBaconAlphabet, SupportedBaconTranslation},
+    },
+    run_op, Operation, DOCS_URL,
 };
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -57,7 +58,7 @@ struct DeserializeMeDaddy {
     translation: SupportedBaconTranslation,
     keep_extra_character: bool,
     invert_translation: bool,
-    lang: SupportedLanguages,
+    lang: Alphabet,
 }
 
 /// Bacon's cipher or the Baconian cipher is a method of steganography devised by Francis Bacon in 1605. A message is concealed in the presentation of text, rather than its content.
@@ -77,14 +78,14 @@ struct DeserializeMeDaddy {
 ///         translation: SupportedBaconTranslation,
 ///         keep_extra_character: bool,
 ///         invert_translation: bool,
-///         lang: SupportedLanguages,
+///         lang: Alphabet,
 ///     }
 /// }
 /// ```
 /// #### where
 ///     - SupportedBaconAlphabet is enum of "Standard", "Complete"
 ///     - SupportedBaconTranslation is enum of "0/1", "A/B"
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo"
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
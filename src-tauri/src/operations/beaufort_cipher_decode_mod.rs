@@ -0,0 +1,196 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, vigenere_trait::VigenereCipher},
+    run_op,
+    utils::add,
+    Operation, DOCS_URL,
+};
+
+impl VigenereCipher for BeaufortCipherDecode {}
+
+run_op!(run_beaufortcipherdecode, BeaufortCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for BeaufortCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, key, variant } = request;
+
+        match variant {
+            true => <Self as VigenereCipher>::cipher(&lang, &key, input, add),
+            false => <Self as VigenereCipher>::cipher(&lang, &key, input, |text, key| key - text),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    key: String,
+    variant: bool,
+}
+
+/// The Beaufort cipher is a polyalphabetic substitution cipher similar to the Vigenère cipher, but the plaintext letter is subtracted from the key letter instead of being added to it, so the same operation both encrypts and decrypts. The variant Beaufort subtracts the key letter from the plaintext letter, it is the Vigenère decryption used for encryption.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Beaufort_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/BeaufortCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "key": string,
+///         "variant": bool
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+///     - variant chooses the variant Beaufort cipher instead of the classic one
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/BeaufortCipherDecode
+///
+/// {
+///     "input": "Danzq, Cwnnh!",
+///     "params": {
+///         "lang": "en",
+///         "key": "key",
+///         "variant": false
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Hello, World!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/BeaufortCipherDecode
+///
+/// {
+///     "input": "Ееклыз, Осж!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "ключ",
+///         "variant": true
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Привет, Мир!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/BeaufortCipherDecode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "my key",
+///         "variant": false
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid key"
+/// }
+/// ```
+pub struct BeaufortCipherDecode;
+
+const NAME: &str = "BeaufortCipherDecode";
+const DESCRIPTION_EN: &str = "The Beaufort cipher is a polyalphabetic substitution cipher similar to the Vigenère cipher, but the plaintext letter is subtracted from the key letter instead of being added to it, so the same operation both encrypts and decrypts. The variant Beaufort subtracts the key letter from the plaintext letter, it is the Vigenère decryption used for encryption.";
+const DESCRIPTION_RU: &str = "Шифр Бофорта — это шифр полиалфавитной замены, похожий на шифр Виженера, но буква открытого текста вычитается из буквы ключа, а не прибавляется к ней, поэтому одна и та же операция и шифрует, и расшифровывает. Вариант Бофорта вычитает букву ключа из буквы открытого текста, это расшифровка Виженера, используемая для шифрования.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Beaufort_cipher");
+
+create_info_struct!(
+    BeaufortCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_beaufortcipherencode, BeaufortCipherEncode};
+
+    fn request(lang: &str, key: &str, variant: bool) -> String {
+        format!(
+            r#"{{"lang": "{}", "key": "{}", "variant": {}}}"#,
+            lang, key, variant
+        )
+    }
+
+    #[test]
+    fn known_ciphertext() {
+        let request = request("en", "FORTIFICATION", false);
+        let plaintext = "DEFENDTHEEASTWALLOFTHECASTLE";
+        let ciphertext = "CKMPVCPVWPIWUJOGIUAPVWRIWUUK";
+        assert_eq!(
+            run_beaufortcipherencode(BeaufortCipherEncode, plaintext, &request).unwrap(),
+            ciphertext
+        );
+        assert_eq!(
+            run_beaufortcipherdecode(BeaufortCipherDecode, ciphertext, &request).unwrap(),
+            plaintext
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for (input, lang, key) in [
+            (
+                "Defend the East Wall of the Castle, 42 men!",
+                "en",
+                "Fortification",
+            ),
+            (
+                "Съешь же ещё этих мягких французских булок, 42 раза!",
+                "ru_with_yo",
+                "Шифр",
+            ),
+        ] {
+            for variant in [false, true] {
+                let request = request(lang, key, variant);
+                let encoded =
+                    run_beaufortcipherencode(BeaufortCipherEncode, input, &request).unwrap();
+                assert_ne!(encoded, input);
+                assert_eq!(
+                    run_beaufortcipherdecode(BeaufortCipherDecode, &encoded, &request).unwrap(),
+                    input
+                );
+            }
+        }
+    }
+}
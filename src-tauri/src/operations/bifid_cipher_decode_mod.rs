@@ -0,0 +1,225 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, ciphers::gen_polybius_square},
+    run_op, Operation, DOCS_URL,
+};
+use anyhow::{bail, Result};
+
+run_op!(run_bifidcipherdecode, BifidCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for BifidCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let (lang, keyword) = (request.lang, request.keyword);
+        let keyword_str = lang.polybius_normalize(&keyword);
+        let keyword: String = keyword_str.chars().dedup().collect();
+
+        if !lang.is_word(&keyword_str) || keyword.is_empty() {
+            bail!("The key must consist only of your alphabets characters");
+        }
+
+        let (polybius, size) = gen_polybius_square(&lang, &keyword_str)?;
+        let position = |letter: char| {
+            polybius
+                .iter()
+                .position(|&x| x == lang.polybius_letter(letter))
+        };
+
+        // Coordinates of the ciphertext read in pairs give the rows of the plaintext letters
+        // followed by their columns.
+        let mut coords: Vec<usize> = Vec::new();
+        for idx in input.chars().filter_map(position) {
+            coords.push(idx / size);
+            coords.push(idx % size);
+        }
+        let (rows, columns) = coords.split_at(coords.len() / 2);
+        let mut letters = rows
+            .iter()
+            .zip(columns)
+            .map(|(row, column)| polybius[row * size + column]);
+
+        Ok(input
+            .chars()
+            .map(|ch| match position(ch).and_then(|_| letters.next()) {
+                Some(letter) if ch.is_lowercase() => lang.to_lower(letter),
+                Some(letter) => letter,
+                None => ch,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    keyword: String,
+}
+
+/// Decodes text encoded with the Bifid cipher. The Polybius square is built from the same keyword and alphabet as in BifidCipherEncode, letter case and characters outside of the square are kept in place.
+/// <br><br/>
+/// For more information about cipher/hash_function go [here](https://wikipedia.org/wiki/Bifid_cipher)
+/// For more information about this function go [here](DOCS_URL)
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/BifidCipherDecode with your data using json payload with this structure
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "keyword": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ## Server response have two possible formats
+///
+/// ### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// ### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/BifidCipherDecode
+///
+/// {
+///     "input": "cmodh",
+///     "params": {
+///         "lang": "en",
+///         "keyword": "key"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hello"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/BifidCipherDecode
+///
+/// {
+///     "input": "суййтишм дчурмндцъ жь нруцсвщ",
+///     "params": {
+///         "lang": "ru",
+///         "keyword": "ключ"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "тестовое сообщение на русском"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/BifidCipherDecode
+///
+/// {
+///     "input": "cmodh",
+///     "params": {
+///         "lang": "en"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Missing field `keyword`."
+/// }
+/// ```
+pub struct BifidCipherDecode;
+
+const NAME: &str = "BifidCipherDecode";
+const DESCRIPTION_EN: &str = "Decodes text encoded with the Bifid cipher. The Polybius square is built from the same keyword and alphabet as in BifidCipherEncode, letter case and characters outside of the square are kept in place.";
+const DESCRIPTION_RU: &str = "Декодирует текст, закодированный шифром Бифида. Квадрат Полибия строится из того же ключевого слова и алфавита, что и в BifidCipherEncode, регистр букв и символы вне квадрата остаются на своих местах.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Bifid_cipher");
+
+create_info_struct!(
+    BifidCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_bifidcipherencode, BifidCipherEncode};
+
+    fn request(lang: &str, keyword: &str) -> String {
+        format!(r#"{{"lang": "{}", "keyword": "{}"}}"#, lang, keyword)
+    }
+
+    fn round_trip(input: &str, lang: &str, keyword: &str) -> String {
+        let request = request(lang, keyword);
+        let encoded = run_bifidcipherencode(BifidCipherEncode, input, &request).unwrap();
+        assert_ne!(encoded, input);
+        run_bifidcipherdecode(BifidCipherDecode, &encoded, &request).unwrap()
+    }
+
+    #[test]
+    fn known_ciphertext() {
+        let request = request("en", "BGWKZQPNDSIOAXEFCLUMTHYVR");
+        assert_eq!(
+            run_bifidcipherencode(BifidCipherEncode, "FLEEATONCE", &request).unwrap(),
+            "UAEOLWRINS"
+        );
+        assert_eq!(
+            run_bifidcipherdecode(BifidCipherDecode, "UAEOLWRINS", &request).unwrap(),
+            "FLEEATONCE"
+        );
+    }
+
+    #[test]
+    fn round_trip_en() {
+        let input = "Attack at Dawn, the Quick Brown Fox; 123 PUVWZ abcde!";
+        assert_eq!(round_trip(input, "en", "key"), input);
+        assert_eq!(round_trip(input, "en", "Playfair"), input);
+
+        // J shares the square cell with I.
+        assert_eq!(round_trip("Jolly Jumper", "en", "key"), "Iolly Iumper");
+    }
+
+    #[test]
+    fn round_trip_ru() {
+        // The 6x6 square has punctuation cells, a letter encrypted to them can't keep its case.
+        let input = "Привет, Мир! Съешь же ещё этих мягких французских булок 42 раза.";
+        let output = round_trip(input, "ru_with_yo", "шифр");
+        assert_eq!(output.to_lowercase(), input.to_lowercase());
+
+        let input = input.replace('ё', "е");
+        let output = round_trip(&input, "ru", "ключ");
+        assert_eq!(output.to_lowercase(), input.to_lowercase());
+    }
+
+    #[test]
+    fn invalid_keyword() {
+        for keyword in ["", "two words", "ключ"] {
+            assert_eq!(
+                run_bifidcipherdecode(BifidCipherDecode, "text", &request("en", keyword))
+                    .unwrap_err(),
+                "The key must consist only of your alphabets characters"
+            );
+        }
+    }
+}
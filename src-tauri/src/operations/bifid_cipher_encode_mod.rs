@@ -4,11 +4,8 @@ use std::collections::HashMap;
 
 use crate::{
     create_info_struct,
-    libs::ciphers::gen_polybius_square,
-    regex_check, run_op,
-    traits::CharTrait,
-    utils::{get_alphabet, SupportedLanguages},
-    Operation, DOCS_URL,
+    libs::{alphabet::Alphabet, ciphers::gen_polybius_square},
+    run_op, Operation, DOCS_URL,
 };
 use anyhow::{bail, Result};
 
@@ -18,36 +15,27 @@ impl Operation<'_, DeserializeMeDaddy> for BifidCipherEncode {
     fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
         let request = self.validate(request)?;
         let (lang, keyword) = (request.lang, request.keyword);
-        let keyword_str = match lang {
-            SupportedLanguages::EN => keyword.to_uppercase().replace('J', "I"),
-            SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => keyword.to_uppercase(),
-        };
+        let keyword_str = lang.polybius_normalize(&keyword);
         let keyword: String = keyword_str.chars().dedup().collect();
-        let (_, _, _, _, _, reg) = get_alphabet(&lang);
 
-        if !regex_check!(reg => &keyword_str) && keyword.is_empty() {
+        if !lang.is_word(&keyword_str) || keyword.is_empty() {
             bail!("The key must consist only of your alphabets characters");
         }
 
-        let (input, size) = match lang {
-            SupportedLanguages::EN => (input.replace('J', "I").replace('j', "i"), 5),
-            SupportedLanguages::RU | SupportedLanguages::RU_WITH_YO => (input.to_string(), 6),
-        };
-
         let mut x_cord: Vec<usize> = Vec::new();
         let mut y_cord: Vec<usize> = Vec::new();
         let mut other: HashMap<usize, char> = HashMap::new();
         let mut case: Vec<bool> = Vec::new();
         let mut output = String::new();
 
-        let polybius = gen_polybius_square(&lang, &keyword_str);
+        let (polybius, size) = gen_polybius_square(&lang, &keyword_str)?;
 
         for (i, letter) in input.chars().enumerate() {
             case.push(letter.is_lowercase());
 
             let idx = polybius
                 .iter()
-                .position(|&x| x == letter.to_upper_case())
+                .position(|&x| x == lang.polybius_letter(letter))
                 .unwrap_or(usize::MAX);
 
             if idx == usize::MAX {
@@ -55,7 +43,7 @@ impl Operation<'_, DeserializeMeDaddy> for BifidCipherEncode {
                 continue;
             }
 
-            x_cord.push((idx as f64 / size as f64).ceil() as usize - 1);
+            x_cord.push(idx / size);
             y_cord.push(idx % size);
         }
 
@@ -68,7 +56,7 @@ impl Operation<'_, DeserializeMeDaddy> for BifidCipherEncode {
         while output.chars().count() != input_len {
             if let Some(ch) = other.get(&output.chars().count()) {
                 output.push(match case[case_idx] {
-                    true => ch.to_lower_case(),
+                    true => lang.to_lower(*ch),
                     false => *ch,
                 });
                 case_idx += 1;
@@ -83,7 +71,7 @@ impl Operation<'_, DeserializeMeDaddy> for BifidCipherEncode {
             let letter = polybius[x_cord[i] * size + x_cord[i + 1]];
             output.push({
                 let out = match case[case_idx] {
-                    true => letter.to_lower_case(),
+                    true => lang.to_lower(letter),
                     false => letter,
                 };
                 case_idx += 1;
@@ -99,7 +87,7 @@ impl Operation<'_, DeserializeMeDaddy> for BifidCipherEncode {
 
 #[derive(Deserialize)]
 struct DeserializeMeDaddy {
-    lang: SupportedLanguages,
+    lang: Alphabet,
     keyword: String,
 }
 
@@ -116,13 +104,13 @@ struct DeserializeMeDaddy {
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages,
+///         "lang": Alphabet,
 ///         "keyword": string
 ///     }
 /// }
 /// ```
 /// #### where
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
 /// <br/><br/>
 ///
 /// ## Server response have two possible formats
@@ -168,7 +156,7 @@ struct DeserializeMeDaddy {
 /// ```
 /// ```http
 /// {
-///   "Ok": "ртйксйцк еатслсм,э оя чънрчкю"
+///   "Ok": "суййтишм дчурмндцъ жь нруцсвщ"
 /// }
 /// ```
 /// ## №3
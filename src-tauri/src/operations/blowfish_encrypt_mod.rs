@@ -0,0 +1,160 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::block_cipher::{
+        BlockCipher, BlockCipherFunction, InputFormat, Mode, OutputFormat, Padding,
+    },
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_blowfishencrypt, BlowfishEncrypt);
+
+impl Operation<'_, DeserializeMeDaddy> for BlowfishEncrypt {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            key,
+            key_format,
+            iv,
+            iv_format,
+            mode,
+            padding,
+            input_format,
+            output_format,
+        } = request;
+
+        let cipher = BlockCipher::new(BlockCipherFunction::Blowfish)
+            .set_input(input, input_format)?
+            .set_iv(&convert_to_byte_array(&iv, &iv_format)?)?
+            .set_key(&convert_to_byte_array(&key, &key_format)?)?
+            .set_mode(mode)
+            .set_padding(padding);
+
+        cipher.encrypt(output_format)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    key_format: SupportedFormats,
+    iv: String,
+    iv_format: SupportedFormats,
+    mode: Mode,
+    padding: Padding,
+    input_format: InputFormat,
+    output_format: OutputFormat,
+}
+
+/// Blowfish is a symmetric-key block cipher designed in 1993 by Bruce Schneier and included in a large number of cipher suites and encryption products. AES now receives more attention.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Blowfish_(cipher)).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/BlowfishEncrypt with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "iv": string,
+///         "iv_format": SupportedFormats,
+///         "mode": Mode,
+///         "padding": Padding,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Mode is enum of "CBC", "CTR", "OFB", "CFB", "CFB8", "ECB", "XTS"
+///     - Padding is enum of "pkcs7", "iso7816", "x923", "iso10126" (alias "random"), "zero", "none" (used only in "CBC" and "ECB" modes)
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw"
+///     - key must be from 4 to 56 bytes long
+///     - iv must be 8 bytes long, it is ignored in "ECB" mode
+///     - "XTS" mode is not supported for 64-bit block ciphers
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/BlowfishEncrypt
+///
+/// {
+///     "input": "hello world",
+///     "params": {
+///         "key": "0123456789abcdeffedcba9876543210",
+///         "key_format": "hex",
+///         "iv": "1122334455667788",
+///         "iv_format": "hex",
+///         "mode": "CBC",
+///         "padding": "pkcs7",
+///         "input_format": "raw",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "24d49cc8c4f172e76dc0e6225ea0c1ce"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/BlowfishEncrypt
+///
+/// {
+///     "input": "hello world",
+///     "params": {
+///         "key": "abcdef",
+///         "key_format": "hex",
+///         "iv": "1122334455667788",
+///         "iv_format": "hex",
+///         "mode": "CBC",
+///         "padding": "pkcs7",
+///         "input_format": "raw",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid key length for Blowfish: expected from 4 to 56 bytes, got 3 bytes."
+/// }
+/// ```
+pub struct BlowfishEncrypt;
+
+const NAME: &str = "BlowfishEncrypt";
+const DESCRIPTION_EN: &str = "Blowfish is a symmetric-key block cipher designed in 1993 by Bruce Schneier and included in a large number of cipher suites and encryption products. AES now receives more attention.";
+const DESCRIPTION_RU: &str = "Blowfish — симметричный блочный шифр, разработанный в 1993 году Брюсом Шнайером и включённый в большое количество наборов шифров и продуктов шифрования. Сейчас больше внимания уделяется AES.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Blowfish_(cipher)");
+
+create_info_struct!(
+    BlowfishEncryptInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
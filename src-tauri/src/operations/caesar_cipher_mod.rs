@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, ciphers::caesar_cipher},
+    run_op, Operation, DOCS_URL,
+};
+use anyhow::Result;
+
+run_op!(run_caesarcipher, CaesarCipher);
+
+impl Operation<'_, DeserializeMeDaddy> for CaesarCipher {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, shift } = request;
+
+        Ok(caesar_cipher(input, &lang, shift))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    shift: i16,
+}
+
+/// Caesar cipher (ROT-N) shifts every letter of the chosen alphabet by a fixed number of positions, wrapping around at the end of the alphabet. Negative shift decodes the text, letter case is kept and all other characters are left as is.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Caesar_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/CaesarCipher with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "shift": i16
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/CaesarCipher
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "shift": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Khoor, Zruog!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CaesarCipher
+///
+/// {
+///     "input": "Съешь же ещё этих мягких французских булок",
+///     "params": {
+///         "lang": "ru_with_yo",
+///         "shift": -7
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Куюсх аю ютя цлво ёшьдво нйщжпмбкдво ъмезд"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CaesarCipher
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "abca",
+///         "shift": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid value: string \"abca\", expected an alphabet preset or a string of at least two distinct symbols."
+/// }
+/// ```
+pub struct CaesarCipher;
+
+const NAME: &str = "CaesarCipher";
+const DESCRIPTION_EN: &str = "Caesar cipher (ROT-N) shifts every letter of the chosen alphabet by a fixed number of positions, wrapping around at the end of the alphabet. Negative shift decodes the text, letter case is kept and all other characters are left as is.";
+const DESCRIPTION_RU: &str = "Шифр Цезаря (ROT-N) сдвигает каждую букву выбранного алфавита на фиксированное число позиций, переходя в начало алфавита после его конца. Отрицательный сдвиг декодирует текст, регистр букв сохраняется, а остальные символы остаются без изменений.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Caesar_cipher");
+
+create_info_struct!(
+    CaesarCipherInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
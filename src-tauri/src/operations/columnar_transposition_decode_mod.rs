@@ -0,0 +1,134 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{columnar_order, untranspose},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_columnartranspositiondecode, ColumnarTranspositionDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for ColumnarTranspositionDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { key, second_key } = request;
+        let len = input.chars().count();
+
+        let mut output = input.to_string();
+        if let Some(second_key) = second_key {
+            output = untranspose(&output, &columnar_order(len, &second_key)?);
+        }
+
+        Ok(untranspose(&output, &columnar_order(len, &key)?))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    second_key: Option<String>,
+}
+
+/// Decodes text encrypted with the Columnar transposition or, when the second key is given, with the double columnar transposition. The keys are the same as for encoding, the second transposition is undone first.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Transposition_cipher#Columnar_transposition).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ColumnarTranspositionDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "second_key": Option<string>
+///     }
+/// }
+/// ```
+/// #### where
+///     - key defines the order of the columns by the alphabetical order of its letters
+///     - second_key is the key of the second transposition for the double columnar transposition
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ColumnarTranspositionDecode
+///
+/// {
+///     "input": "EVLNACDTESEAROFODEECWIREE",
+///     "params": {
+///         "key": "zebras"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WEAREDISCOVEREDFLEEATONCE"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ColumnarTranspositionDecode
+///
+/// {
+///     "input": "DOWCRCNEEEAAELSDEETFIVEOR",
+///     "params": {
+///         "key": "zebras",
+///         "second_key": "striped"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WEAREDISCOVEREDFLEEATONCE"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ColumnarTranspositionDecode
+///
+/// {
+///     "input": "EVLNACDTESEAROFODEECWIREE",
+///     "params": {
+///         "second_key": "striped"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Missing field `key`."
+/// }
+/// ```
+pub struct ColumnarTranspositionDecode;
+
+const NAME: &str = "ColumnarTranspositionDecode";
+const DESCRIPTION_EN: &str = "Decodes text encrypted with the Columnar transposition or, when the second key is given, with the double columnar transposition. The keys are the same as for encoding, the second transposition is undone first.";
+const DESCRIPTION_RU: &str = "Расшифровывает текст, зашифрованный столбцовой перестановкой или, если указан второй ключ, двойной столбцовой перестановкой. Ключи те же, что и при шифровании, вторая перестановка отменяется первой.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Transposition_cipher#Columnar_transposition");
+
+create_info_struct!(
+    ColumnarTranspositionDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
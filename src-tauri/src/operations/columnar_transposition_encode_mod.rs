@@ -0,0 +1,134 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{columnar_order, transpose},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_columnartranspositionencode, ColumnarTranspositionEncode);
+
+impl Operation<'_, DeserializeMeDaddy> for ColumnarTranspositionEncode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { key, second_key } = request;
+        let len = input.chars().count();
+
+        let mut output = transpose(input, &columnar_order(len, &key)?);
+        if let Some(second_key) = second_key {
+            output = transpose(&output, &columnar_order(len, &second_key)?);
+        }
+
+        Ok(output)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    second_key: Option<String>,
+}
+
+/// The Columnar transposition writes the text in rows under the key and reads the columns in the alphabetical order of the key letters, equal letters are taken from left to right. With the second key the result is transposed once more, which gives the double transposition. The last row may be incomplete.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Transposition_cipher#Columnar_transposition).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ColumnarTranspositionEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "second_key": Option<string>
+///     }
+/// }
+/// ```
+/// #### where
+///     - key defines the order of the columns by the alphabetical order of its letters
+///     - second_key is the key of the second transposition for the double columnar transposition
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ColumnarTranspositionEncode
+///
+/// {
+///     "input": "WEAREDISCOVEREDFLEEATONCE",
+///     "params": {
+///         "key": "zebras"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "EVLNACDTESEAROFODEECWIREE"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ColumnarTranspositionEncode
+///
+/// {
+///     "input": "WEAREDISCOVEREDFLEEATONCE",
+///     "params": {
+///         "key": "zebras",
+///         "second_key": "striped"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "DOWCRCNEEEAAELSDEETFIVEOR"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ColumnarTranspositionEncode
+///
+/// {
+///     "input": "WEAREDISCOVEREDFLEEATONCE",
+///     "params": {
+///         "key": ""
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Key must not be empty."
+/// }
+/// ```
+pub struct ColumnarTranspositionEncode;
+
+const NAME: &str = "ColumnarTranspositionEncode";
+const DESCRIPTION_EN: &str = "The Columnar transposition writes the text in rows under the key and reads the columns in the alphabetical order of the key letters, equal letters are taken from left to right. With the second key the result is transposed once more, which gives the double transposition. The last row may be incomplete.";
+const DESCRIPTION_RU: &str = "Столбцовая перестановка записывает текст строками под ключом и читает столбцы в алфавитном порядке букв ключа, одинаковые буквы берутся слева направо. Со вторым ключом результат переставляется ещё раз, что даёт двойную перестановку. Последняя строка может быть неполной.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Transposition_cipher#Columnar_transposition");
+
+create_info_struct!(
+    ColumnarTranspositionEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
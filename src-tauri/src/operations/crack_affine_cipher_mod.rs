@@ -0,0 +1,193 @@
+use anyhow::{bail, Result};
+use num::Integer;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        ciphers::affine_cipher_decode,
+        cryptanalysis::{letter_indexes, score_text},
+    },
+    run_op,
+    utils::SupportedLanguages,
+    Operation, DOCS_URL,
+};
+
+run_op!(run_crackaffinecipher, CrackAffineCipher);
+
+impl Operation<'_, DeserializeMeDaddy> for CrackAffineCipher {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, count } = request;
+
+        if count == 0 {
+            bail!("Count must be a positive number.");
+        }
+        if letter_indexes(input, &lang).is_empty() {
+            bail!("Input must contain letters of the chosen alphabet.");
+        }
+
+        let alphabet = Alphabet::from(lang);
+        let alp_length = alphabet.length() as i16;
+
+        let mut candidates: Vec<(i16, i16, f64, String)> = Vec::new();
+        for a in (1..alp_length).filter(|a| a.gcd(&alp_length) == 1) {
+            for b in 0..alp_length {
+                let text = affine_cipher_decode(input, &alphabet, a, b)?;
+                candidates.push((a, b, score_text(&text, &lang), text));
+            }
+        }
+        candidates.sort_by(|x, y| y.2.total_cmp(&x.2));
+
+        Ok(candidates
+            .into_iter()
+            .take(count)
+            .map(|(a, b, score, text)| format!("a = {a}, b = {b} (score {score:.2}): {text}"))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: SupportedLanguages,
+    count: usize,
+}
+
+/// Brute-forces all keys (a, b) of the Affine cipher and ranks the decoded texts by how much they look like the chosen language. The score is the average log probability of the letters with a bonus for the most frequent quadgrams, the higher the better.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Affine_cipher#Weaknesses).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/CrackAffineCipher with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": SupportedLanguages,
+///         "count": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - count is number of the best candidates to return
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/CrackAffineCipher
+///
+/// {
+///     "input": "Izzisg iz xiov, zrc cvcqy wu ocig",
+///     "params": {
+///         "lang": "en",
+///         "count": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "a = 5, b = 8 (score -1.19): Attack at dawn, the enemy is weak\na = 11, b = 24 (score -1.31): Ittiqw it hisv, txy yvyea oc syiw\na = 21, b = 2 (score -1.33): Ellecu el beir, lxa arasg wm iaeu"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CrackAffineCipher
+///
+/// {
+///     "input": "Лфнг чицефг ичвьыа, а рэе тюгфа пцгщюванг",
+///     "params": {
+///         "lang": "ru_with_yo",
+///         "count": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "a = 7, b = 3 (score -1.39): Ёлка горела огнями, и все ждали праздника"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CrackAffineCipher
+///
+/// {
+///     "input": "Hello + world",
+///     "params": {
+///         "lang": "en",
+///         "count": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Wrong language."
+/// }
+/// ```
+pub struct CrackAffineCipher;
+
+const NAME: &str = "CrackAffineCipher";
+const DESCRIPTION_EN: &str = "Brute-forces all keys (a, b) of the Affine cipher and ranks the decoded texts by how much they look like the chosen language. The score is the average log probability of the letters with a bonus for the most frequent quadgrams, the higher the better.";
+const DESCRIPTION_RU: &str = "Перебирает все ключи (a, b) аффинного шифра и ранжирует расшифрованные тексты по тому, насколько они похожи на выбранный язык. Оценка — это средняя логарифмическая вероятность букв с бонусом за самые частые квадграммы, чем она выше, тем лучше.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Affine_cipher#Weaknesses");
+
+create_info_struct!(
+    CrackAffineCipherInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_affinecipherencode, AffineCipherEncode};
+
+    const PLAINTEXT: &str = "The old lighthouse keeper walked along the shore every morning \
+        before the sun came up and counted the boats that were leaving the harbour.";
+
+    fn crack(input: &str) -> Result<String, String> {
+        run_crackaffinecipher(CrackAffineCipher, input, r#"{"lang": "en", "count": 1}"#)
+    }
+
+    #[test]
+    fn recovers_key() {
+        let encoded = run_affinecipherencode(
+            AffineCipherEncode,
+            PLAINTEXT,
+            r#"{"lang": "en", "a": 5, "b": 8}"#,
+        )
+        .unwrap();
+        let cracked = crack(&encoded).unwrap();
+        assert!(cracked.starts_with("a = 5, b = 8 (score "), "{cracked}");
+        assert!(cracked.ends_with(PLAINTEXT), "{cracked}");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = "Input must contain letters of the chosen alphabet.";
+        assert_eq!(crack("").unwrap_err(), error);
+        assert_eq!(crack("42, 17 & 9!").unwrap_err(), error);
+    }
+}
@@ -0,0 +1,194 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        ciphers::caesar_cipher,
+        cryptanalysis::{letter_indexes, score_text},
+    },
+    run_op,
+    utils::SupportedLanguages,
+    Operation, DOCS_URL,
+};
+
+run_op!(run_crackcaesarcipher, CrackCaesarCipher);
+
+impl Operation<'_, DeserializeMeDaddy> for CrackCaesarCipher {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, count } = request;
+
+        if count == 0 {
+            bail!("Count must be a positive number.");
+        }
+        if letter_indexes(input, &lang).is_empty() {
+            bail!("Input must contain letters of the chosen alphabet.");
+        }
+
+        let alphabet = Alphabet::from(lang);
+        let mut candidates: Vec<(i16, f64, String)> = (0..alphabet.length() as i16)
+            .map(|shift| {
+                let text = caesar_cipher(input, &alphabet, -shift);
+                (shift, score_text(&text, &lang), text)
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        Ok(candidates
+            .into_iter()
+            .take(count)
+            .map(|(shift, score, text)| format!("Shift {shift} (score {score:.2}): {text}"))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: SupportedLanguages,
+    count: usize,
+}
+
+/// Brute-forces all shifts of the Caesar cipher and ranks the decoded texts by how much they look like the chosen language. The score is the average log probability of the letters with a bonus for the most frequent quadgrams, the higher the better.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Caesar_cipher#Breaking_the_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/CrackCaesarCipher with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": SupportedLanguages,
+///         "count": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - count is number of the best candidates to return
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/CrackCaesarCipher
+///
+/// {
+///     "input": "Esp bftnv mczhy qzi ufxad zgpc esp wlkj ozr",
+///     "params": {
+///         "lang": "en",
+///         "count": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Shift 11 (score -1.48): The quick brown fox jumps over the lazy dog\nShift 17 (score -1.60): Nby kocwe vliqh zir dogjm ipyl nby futs xia\nShift 1 (score -1.61): Dro aesmu lbygx pyh tewzc yfob dro vkji nyq"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CrackCaesarCipher
+///
+/// {
+///     "input": "Цякэб лк кюк вчнъ сдипнъ щхетышмцпнъ жшруп, йе зафко ьег",
+///     "params": {
+///         "lang": "ru",
+///         "count": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Shift 5 (score -1.56): Съешь же еще этих мягких французских булок, да выпей чаю"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CrackCaesarCipher
+///
+/// {
+///     "input": "12345",
+///     "params": {
+///         "lang": "en",
+///         "count": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Input must contain letters of the chosen alphabet."
+/// }
+/// ```
+pub struct CrackCaesarCipher;
+
+const NAME: &str = "CrackCaesarCipher";
+const DESCRIPTION_EN: &str = "Brute-forces all shifts of the Caesar cipher and ranks the decoded texts by how much they look like the chosen language. The score is the average log probability of the letters with a bonus for the most frequent quadgrams, the higher the better.";
+const DESCRIPTION_RU: &str = "Перебирает все сдвиги шифра Цезаря и ранжирует расшифрованные тексты по тому, насколько они похожи на выбранный язык. Оценка — это средняя логарифмическая вероятность букв с бонусом за самые частые квадграммы, чем она выше, тем лучше.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Caesar_cipher#Breaking_the_cipher");
+
+create_info_struct!(
+    CrackCaesarCipherInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_caesarcipher, CaesarCipher};
+
+    fn crack(input: &str, lang: &str) -> Result<String, String> {
+        let request = format!(r#"{{"lang": "{lang}", "count": 1}}"#);
+        run_crackcaesarcipher(CrackCaesarCipher, input, &request)
+    }
+
+    #[test]
+    fn recovers_shift() {
+        for (lang, plaintext) in [
+            (
+                "en",
+                "The old keeper counted the boats that were leaving the harbour.",
+            ),
+            (
+                "ru",
+                "Старый смотритель считал лодки, которые выходили из гавани.",
+            ),
+        ] {
+            let request = format!(r#"{{"lang": "{lang}", "shift": 7}}"#);
+            let encoded = run_caesarcipher(CaesarCipher, plaintext, &request).unwrap();
+            let cracked = crack(&encoded, lang).unwrap();
+            assert!(cracked.starts_with("Shift 7 (score "), "{cracked}");
+            assert!(cracked.ends_with(plaintext), "{cracked}");
+        }
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = "Input must contain letters of the chosen alphabet.";
+        assert_eq!(crack("", "en").unwrap_err(), error);
+        assert_eq!(crack("Hello", "ru").unwrap_err(), error);
+    }
+}
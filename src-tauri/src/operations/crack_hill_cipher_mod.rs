@@ -0,0 +1,144 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        hill::{matrix_key, recover_key},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_crackhillcipher, CrackHillCipher);
+
+impl Operation<'_, DeserializeMeDaddy> for CrackHillCipher {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            lang,
+            plaintext,
+            size,
+        } = request;
+
+        let key = recover_key(&lang, &plaintext, input, size)?;
+
+        Ok(matrix_key(&lang, &key))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    plaintext: String,
+    size: usize,
+}
+
+/// Recovers the key of the Hill cipher from a known plaintext and its ciphertext given as the input. The blocks of the plaintext whose matrix is invertible modulo the alphabet length give the key matrix, which is then checked against the rest of the text. The key is returned as the letters of the matrix row by row.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Hill_cipher#Security).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/CrackHillCipher with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "plaintext": String,
+///         "size": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+///     - plaintext is the known text the input was encrypted from
+///     - size is the size of the key matrix
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/CrackHillCipher
+///
+/// {
+///     "input": "Hakgcc rw evox, jhrqzsd vp wtulaa",
+///     "params": {
+///         "lang": "en",
+///         "plaintext": "Attack at dawn, retreat at dusk",
+///         "size": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "GYBNQKURP"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CrackHillCipher
+///
+/// {
+///     "input": "Алувь ве шкф эьъв тшитъв сдъжьюялщфю рнэлж",
+///     "params": {
+///         "lang": "ru_with_yo",
+///         "plaintext": "Съешь же ещё этих мягких французских булок",
+///         "size": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ШИФР"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CrackHillCipher
+///
+/// {
+///     "input": "AAAA",
+///     "params": {
+///         "lang": "en",
+///         "plaintext": "aaaa",
+///         "size": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "The plaintext blocks don't give an invertible matrix, a longer plaintext is needed."
+/// }
+/// ```
+pub struct CrackHillCipher;
+
+const NAME: &str = "CrackHillCipher";
+const DESCRIPTION_EN: &str = "Recovers the key of the Hill cipher from a known plaintext and its ciphertext given as the input. The blocks of the plaintext whose matrix is invertible modulo the alphabet length give the key matrix, which is then checked against the rest of the text. The key is returned as the letters of the matrix row by row.";
+const DESCRIPTION_RU: &str = "Восстанавливает ключ шифра Хилла по известному открытому тексту и его шифртексту, переданному на вход. Блоки открытого текста, матрица которых обратима по модулю длины алфавита, дают матрицу ключа, которая затем проверяется на остальном тексте. Ключ возвращается буквами матрицы построчно.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Hill_cipher#Security");
+
+create_info_struct!(
+    CrackHillCipherInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,157 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        cryptanalysis::{letter_indexes, score_text, substitution_key},
+    },
+    run_op,
+    utils::{get_char_by_index, SupportedLanguages},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_cracksubstitutioncipher, CrackSubstitutionCipher);
+
+impl Operation<'_, DeserializeMeDaddy> for CrackSubstitutionCipher {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let lang = request.lang;
+
+        let letters = letter_indexes(input, &lang);
+        if letters.is_empty() {
+            bail!("Input must contain letters of the chosen alphabet.");
+        }
+
+        let key = substitution_key(input, &lang);
+
+        let alphabet = Alphabet::from(lang);
+        let (alp_lower, alp_upper) = (alphabet.lower(), alphabet.upper());
+        let text: String = input
+            .chars()
+            .map(|ch| {
+                for alp in [alp_lower, alp_upper] {
+                    if let Some(idx) = alp.chars().position(|x| x == ch) {
+                        return get_char_by_index(alp, key[idx]);
+                    }
+                }
+                ch
+            })
+            .collect();
+        let key: String = key
+            .iter()
+            .map(|&idx| get_char_by_index(alp_lower, idx))
+            .collect();
+
+        Ok(format!(
+            "Key {key} (score {:.2}): {text}",
+            score_text(&text, &lang)
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: SupportedLanguages,
+}
+
+/// Suggests the key of a monoalphabetic substitution cipher. The ciphertext letters are first matched with the letters of the chosen language by their frequencies, then pairs of letters are swapped while the decoded text looks more like the language judged by the frequent bigrams, quadgrams and words, as the cipher keeps the word boundaries. The key is the plaintext letter for every letter of the alphabet and can be refined by hand with the Substitute operation. The longer the text the better the suggestion, short texts are usually decoded only partially.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Substitution_cipher#Security_for_simple_substitution_ciphers).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/CrackSubstitutionCipher with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": SupportedLanguages
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/CrackSubstitutionCipher
+///
+/// {
+///     "input": "Sgbcdl stn hddf ldtpdn egk lgcd sgqkl of lobdfud wops sol bgfr, psof htua uqkvdn gvdk t usdcoutb vdlldb of wsous sd wtl hkdwofr t itkpouqbtkby ctbgngkgql ikgnqup. Sol sdtn wtl lqfa qigf sol hkdtlp, tfn sd bggadn ekgc cy igofp ge vodw boad t lpktfrd, btfa hokn, wops nqbb rkdy ibqctrd tfn t hbtua pgi-afgp. Lg, Wtplgf, lton sd, lqnndfby, ygq ng fgp ikgigld pg ofvdlp of Lgqps Tekoutf lduqkopodl?",
+///     "params": {
+///         "lang": "en"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Key klmefnobpjrsxditughacvwqyz (score -1.26): Holmes had been seated for some hours in silence with his long, thin back curved over a chemical vessel in which he was brewing a particularly malodorous product. His head was sunk upon his breast, and he looked from my point of view like a strange, lank bird, with dull grey plumage and a black top-knot. So, Watson, said he, suddenly, you do not propose to invest in South African securities?"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CrackSubstitutionCipher
+///
+/// {
+///     "input": "Снп нчлнребсып нпжьб кйхйаб омут зл омутл, глаоля зпнчлнребсля нпжья зпнчлнребсл кй-нсйпжу. Снп нжпшлейнь с ойжп Йиейзнгбх. Апзл удзлел, чрй жуа иые с нсядб н иысшпю с бх ойжп фмлзцуапзгйю-туспмзлзргйв, б йиъясбел жуау, чрй зп жйапр абрь н збж с йозйж ойжп. Кйейапзбп эрй кмйойеалейнь уап рмпрбв опзь б жучбрпеьзй чуснрсйслейнь б нлжбжб нукмутлжб, б снпжб чепзлжб нпжьб, б ойжйчлоцлжб. Снп чепзы нпжьб б ойжйчлоцы чуснрсйслеб, чрй зпр нжынел с бх нйабрпеьнрсп б чрй зл глаойж кйнрйяейж осймп неучлвзй нйшпошбпня еюоб ийепп нсядлзы жпаоу нйийв, чпж йзб, чепзы нпжьб б ойжйчлоцы Йиейзнгбх. Апзл зп сыхйобел бд нсйбх гйжзлр, жуал рмпрбв опзь зп иыей ойжл. Опрб иптлеб кй снпжу ойжу, глг кйрпмяззып; лзтебчлзгл кйннймбелнь н эгйзйжгйв б злкбнлел длкбнгу кмбярпеьзбцп, кмйня кмббнглрь пв зйсйп жпнрй.",
+///     "params": {
+///         "lang": "ru"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Key жийкзлмнбопарсдетвгуфхшчюъщыьэця (score -1.32): Все счастливые семьи похожи друг на друга, каждая несчастливая семья несчастлива по-своему. Все смеюалось в доме Облонских. Жена узнала, что муж был в связи с бывюец в их доме франшуженкоц-гувернанткой, и общявила мужу, что не может жить с ним в одном доме. Положение это продолжалось уже третий день и мучительно чувствовалось и самими супругами, и всеми членами семьи, и домочадшами. Все члены семьи и домочадшы чувствовали, что нет смысла в их сожительстве и что на каждом постоялом дворе случайно союедюиеся лцди более связаны между собой, чем они, члены семьи и домочадшы Облонских. Жена не выходила из своих комнат, мужа третий день не было дома. Дети бегали по всему дому, как потерянные; англичанка поссорилась с экономкой и написала записку приятельнише, прося приискать ей новое место."
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CrackSubstitutionCipher
+///
+/// {
+///     "input": "Съешь же ещё этих мягких французских булок",
+///     "params": {
+///         "lang": "en"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Input must contain letters of the chosen alphabet."
+/// }
+/// ```
+pub struct CrackSubstitutionCipher;
+
+const NAME: &str = "CrackSubstitutionCipher";
+const DESCRIPTION_EN: &str = "Suggests the key of a monoalphabetic substitution cipher. The ciphertext letters are first matched with the letters of the chosen language by their frequencies, then pairs of letters are swapped while the decoded text looks more like the language judged by the frequent bigrams, quadgrams and words, as the cipher keeps the word boundaries. The key is the plaintext letter for every letter of the alphabet and can be refined by hand with the Substitute operation. The longer the text the better the suggestion, short texts are usually decoded only partially.";
+const DESCRIPTION_RU: &str = "Предлагает ключ шифра простой замены. Сначала буквы шифртекста сопоставляются с буквами выбранного языка по их частотам, затем пары букв меняются местами, пока расшифрованный текст становится больше похож на язык по частым биграммам, квадграммам и словам, так как шифр сохраняет границы слов. Ключ — это буква открытого текста для каждой буквы алфавита, его можно уточнить вручную с помощью операции Substitute. Чем длиннее текст, тем лучше предложение, короткие тексты обычно расшифровываются лишь частично.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Substitution_cipher#Security_for_simple_substitution_ciphers");
+
+create_info_struct!(
+    CrackSubstitutionCipherInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,234 @@
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        cryptanalysis::{
+            letter_indexes, nearly_repeats, score_text, shortest_period, vigenere_key,
+            vigenere_key_lengths,
+        },
+        vigenere_trait::VigenereCipher,
+    },
+    run_op,
+    utils::{get_char_by_index, sub, SupportedLanguages},
+    Operation, DOCS_URL,
+};
+
+impl VigenereCipher for CrackVigenereCipher {}
+
+run_op!(run_crackvigenerecipher, CrackVigenereCipher);
+
+impl Operation<'_, DeserializeMeDaddy> for CrackVigenereCipher {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            lang,
+            max_key_length,
+            count,
+        } = request;
+
+        if count == 0 || max_key_length == 0 {
+            bail!("Count and maximum key length must be positive numbers.");
+        }
+
+        let letters = letter_indexes(input, &lang);
+        if letters.is_empty() {
+            bail!("Input must contain letters of the chosen alphabet.");
+        }
+
+        let keys: Vec<Vec<usize>> = vigenere_key_lengths(&letters, &lang, max_key_length)
+            .into_iter()
+            .map(|length| shortest_period(&vigenere_key(&letters, &lang, length)).to_vec())
+            .unique()
+            .collect();
+
+        let alphabet = Alphabet::from(lang);
+        let keys = keys
+            .iter()
+            .filter(|key| !keys.iter().any(|shorter| nearly_repeats(key, shorter)))
+            .map(|key| {
+                key.iter()
+                    .map(|&idx| get_char_by_index(alphabet.lower(), idx))
+                    .collect::<String>()
+            });
+
+        let mut candidates: Vec<(String, f64, String)> = Vec::new();
+        for key in keys {
+            let text = <Self as VigenereCipher>::cipher(&alphabet, &key, input, sub)?;
+            candidates.push((key, score_text(&text, &lang), text));
+        }
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        Ok(candidates
+            .into_iter()
+            .take(count)
+            .map(|(key, score, text)| format!("Key {key} (score {score:.2}): {text}"))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: SupportedLanguages,
+    max_key_length: usize,
+    count: usize,
+}
+
+/// Recovers the key of the Vigenère cipher without knowing it. Probable key lengths are found with the index of coincidence and the Kasiski examination, every letter of the key is found by frequency analysis of its column. The decoded texts are ranked by how much they look like the chosen language, the higher the score the better.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Vigenère_cipher#Cryptanalysis).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/CrackVigenereCipher with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": SupportedLanguages,
+///         "max_key_length": usize,
+///         "count": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - max_key_length is the longest key length to try
+///     - count is number of the best candidates to return
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/CrackVigenereCipher
+///
+/// {
+///     "input": "Tx iof elq prdx at gtqqg, ve amg gsi icedx at gtqqg, ve amg gsi mur zj iwfosy, wg hee hup ess bq jacytwtbrdw, uh jlw fvr ptaqu zj nsytir, wg hee hup ibcps sr wanvqrhwmfm, ve amg gsi esndsz cs Wmsvg, tx iof elq grlwab bq Hmfxyieg.",
+///     "params": {
+///         "lang": "en",
+///         "max_key_length": 10,
+///         "count": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Key lemon (score -1.11): It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of foolishness, it was the epoch of belief, it was the epoch of incredulity, it was the season of Light, it was the season of Darkness.\nKey oqenbsfep (score -1.39): Fh ebe mgm adnt ns oombs, fa nlo bot umaqw io cecac, id ihc res ihq he ehryol, vo cap tel rra wm ummugvbwnoi, ed wke arc bdwdt he jdkdee, vo cap tel vakko dd gwauymdhypi, id ihc res afmlnv ne Gifuo, ot tap ayp omhhml xd Guatjuoc."
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/CrackVigenereCipher
+///
+/// {
+///     "input": "Фяр вйоъдщууну ъчъзщ бьюафу хвбм яо пбесй, ьосхтн цчявсгафърлр гухон шцгейгацщфо ша-янячъь. Фяр вюубтщщво р наър Яущчяяхщз. Фояо юшяофт, еэя юбп уйц у грищц ь тнрбчм н щз тчюу ябтыяефрюььз-хбнцвыйяахяы, ц чуикуъщй юбсд, йач яу чяшуы шцэн г ысю р щхяьх цьчц.",
+///     "params": {
+///         "lang": "ru",
+///         "max_key_length": 10,
+///         "count": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Key толстой (score -1.38): Все счастливые семьи похожи друг на друга, каждая несчастливая семья несчастлива по-своему. Все смешалось в доме Облонских. Жена узнала, что муж был в связи с бывшею в их доме француженкою-гувернанткой, и объявила мужу, что не может жить с ним в одном доме."
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/CrackVigenereCipher
+///
+/// {
+///     "input": "12345",
+///     "params": {
+///         "lang": "en",
+///         "max_key_length": 10,
+///         "count": 1
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Input must contain letters of the chosen alphabet."
+/// }
+/// ```
+pub struct CrackVigenereCipher;
+
+const NAME: &str = "CrackVigenereCipher";
+const DESCRIPTION_EN: &str = "Recovers the key of the Vigenère cipher without knowing it. Probable key lengths are found with the index of coincidence and the Kasiski examination, every letter of the key is found by frequency analysis of its column. The decoded texts are ranked by how much they look like the chosen language, the higher the score the better.";
+const DESCRIPTION_RU: &str = "Восстанавливает ключ шифра Виженера, не зная его. Вероятные длины ключа находятся с помощью индекса совпадений и метода Касиски, каждая буква ключа находится частотным анализом своего столбца. Расшифрованные тексты ранжируются по тому, насколько они похожи на выбранный язык, чем выше оценка, тем лучше.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Vigenère_cipher#Cryptanalysis");
+
+create_info_struct!(
+    CrackVigenereCipherInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_vigenerecipherencode, VigenereCipherEncode};
+
+    const PLAINTEXT: &str = "The old lighthouse keeper walked along the shore every morning \
+        before the sun came up. He counted the boats that were leaving the harbour and wrote \
+        their names in a small notebook that he kept in the pocket of his coat. When the weather \
+        was bad he stayed inside and listened to the radio, waiting for news from the ships that \
+        were still out at sea.";
+
+    fn crack(input: &str) -> Result<String, String> {
+        let request = r#"{"lang": "en", "max_key_length": 10, "count": 1}"#;
+        run_crackvigenerecipher(CrackVigenereCipher, input, request)
+    }
+
+    #[test]
+    fn recovers_key() {
+        let encoded = run_vigenerecipherencode(
+            VigenereCipherEncode,
+            PLAINTEXT,
+            r#"{"lang": "en", "key": "lemon"}"#,
+        )
+        .unwrap();
+        let cracked = crack(&encoded).unwrap();
+        assert!(cracked.starts_with("Key lemon (score "), "{cracked}");
+        assert!(cracked.ends_with(PLAINTEXT), "{cracked}");
+    }
+
+    #[test]
+    fn invalid_input() {
+        let error = "Input must contain letters of the chosen alphabet.";
+        assert_eq!(crack("").unwrap_err(), error);
+        assert_eq!(crack("42, 17 & 9!").unwrap_err(), error);
+        assert_eq!(
+            run_crackvigenerecipher(
+                CrackVigenereCipher,
+                "text",
+                r#"{"lang": "en", "max_key_length": 10, "count": 0}"#
+            )
+            .unwrap_err(),
+            "Count and maximum key length must be positive numbers."
+        );
+    }
+}
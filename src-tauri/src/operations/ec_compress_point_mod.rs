@@ -0,0 +1,107 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::ec_key::{EcCurve, EcKey, EcKeyFormat},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_eccompresspoint, ECCompressPoint);
+
+impl Operation<'_, DeserializeMeDaddy> for ECCompressPoint {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { curve } = request;
+
+        EcKey::parse_public(input, curve)?.encode_public(EcKeyFormat::Raw, true)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: EcCurve,
+}
+
+/// Compresses an elliptic curve public key point on P-256, P-384 or secp256k1 curve. The output is a SEC1 point made of a parity prefix and the x coordinate.
+/// <br><br/>
+/// For more information go [here](https://www.secg.org/sec1-v2.pdf).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ECCompressPoint with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": EcCurve
+///     }
+/// }
+/// ```
+/// #### where
+///     - EcCurve is enum of "P-256", "P-384", "secp256k1"
+///     - input is hex encoded SEC1 point, SPKI public key in PEM or DER (hex or base64) format or a private key
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ECCompressPoint
+///
+/// {
+///     "input": "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299",
+///     "params": {
+///         "curve": "P-256"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ECCompressPoint
+///
+/// {
+///     "input": "02ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+///     "params": {
+///         "curve": "P-256"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Point is not on P-256 curve."
+/// }
+/// ```
+pub struct ECCompressPoint;
+
+const NAME: &str = "ECCompressPoint";
+const DESCRIPTION_EN: &str = "Compresses an elliptic curve public key point on P-256, P-384 or secp256k1 curve. The output is a SEC1 point made of a parity prefix and the x coordinate.";
+const DESCRIPTION_RU: &str = "Сжимает точку открытого ключа на эллиптической кривой P-256, P-384 или secp256k1. Результат — точка SEC1 из префикса чётности и координаты x.";
+
+const INFO_URL: Option<&str> = Some("https://www.secg.org/sec1-v2.pdf");
+
+create_info_struct!(
+    ECCompressPointInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
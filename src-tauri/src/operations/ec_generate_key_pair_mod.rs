@@ -0,0 +1,133 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::ec_key::{EcCurve, EcKey, EcKeyFormat},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_ecgeneratekeypair, ECGenerateKeyPair);
+
+impl Operation<'_, DeserializeMeDaddy> for ECGenerateKeyPair {
+    fn do_black_magic(&self, _input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            curve,
+            private_key_format,
+            public_key_format,
+            compressed,
+        } = request;
+
+        let key = EcKey::generate(curve);
+
+        Ok(format!(
+            "{}\n{}",
+            key.encode_private(private_key_format)?.trim_end(),
+            key.encode_public(public_key_format, compressed.unwrap_or_default())?
+                .trim_end()
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: EcCurve,
+    private_key_format: EcKeyFormat,
+    public_key_format: EcKeyFormat,
+    compressed: Option<bool>,
+}
+
+/// Generates a new elliptic curve key pair on P-256, P-384 or secp256k1 curve. The private key is printed first, followed by the public key.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Elliptic-curve_cryptography).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ECGenerateKeyPair with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": EcCurve,
+///         "private_key_format": EcKeyFormat,
+///         "public_key_format": EcKeyFormat,
+///         "compressed": Option<bool>
+///     }
+/// }
+/// ```
+/// #### where
+///     - EcCurve is enum of "P-256", "P-384", "secp256k1"
+///     - EcKeyFormat is enum of "raw", "pem", "der"
+///     - "raw" stands for hex encoded private scalar and hex encoded SEC1 public point
+///     - "pem" and "der" stand for PKCS#8 private key and SPKI public key, DER encoded keys are returned as hex
+///     - compressed makes "raw" public key a compressed point, false by default
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ECGenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "curve": "P-256",
+///         "private_key_format": "raw",
+///         "public_key_format": "raw",
+///         "compressed": true
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "8e446b9da0ab1a7bbcc3210374ca73019345392c9d6ab9a988353e5a719cbe90\n027be5d3db0b090157f68982acd1d0f9f34ec77365476985b90fdffed0c64a6adf"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ECGenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "curve": "P-521",
+///         "private_key_format": "raw",
+///         "public_key_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Unknown variant `P-521`, expected one of `P-256`, `P-384`, `secp256k1`."
+/// }
+/// ```
+pub struct ECGenerateKeyPair;
+
+const NAME: &str = "ECGenerateKeyPair";
+const DESCRIPTION_EN: &str = "Generates a new elliptic curve key pair on P-256, P-384 or secp256k1 curve. The private key is printed first, followed by the public key.";
+const DESCRIPTION_RU: &str = "Генерирует новую пару ключей на эллиптической кривой P-256, P-384 или secp256k1. Сначала выводится закрытый ключ, затем открытый.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Elliptic-curve_cryptography");
+
+create_info_struct!(
+    ECGenerateKeyPairInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
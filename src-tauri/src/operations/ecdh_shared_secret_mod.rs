@@ -0,0 +1,136 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{encode_output, OutputFormat},
+        ec_key::{EcCurve, EcKey},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_ecdhsharedsecret, ECDHSharedSecret);
+
+impl Operation<'_, DeserializeMeDaddy> for ECDHSharedSecret {
+    fn do_black_magic(&self, _input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            curve,
+            private_key,
+            public_key,
+            output_format,
+        } = request;
+
+        let private_key = EcKey::parse_private(&private_key, curve)?;
+        let public_key = EcKey::parse_public(&public_key, curve)?;
+
+        Ok(encode_output(
+            &private_key.diffie_hellman(&public_key)?,
+            output_format,
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: EcCurve,
+    private_key: String,
+    public_key: String,
+    output_format: OutputFormat,
+}
+
+/// Computes an ECDH shared secret from your private key and the other party's public key on P-256, P-384 or secp256k1 curve. The result is the x coordinate of the shared point.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Elliptic-curve_Diffie%E2%80%93Hellman).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ECDHSharedSecret with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": EcCurve,
+///         "private_key": string,
+///         "public_key": string,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - EcCurve is enum of "P-256", "P-384", "secp256k1"
+///     - private_key is PKCS#8 or SEC1 private key in PEM or DER (hex or base64) format, or hex encoded private scalar
+///     - public_key is SPKI public key in PEM or DER (hex or base64) format, or hex encoded SEC1 point
+///     - OutputFormat is enum of "hex", "base64", "raw"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ECDHSharedSecret
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "curve": "P-256",
+///         "private_key": "7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534",
+///         "public_key": "04700c48f77f56584c5cc632ca65640db91b6bacce3a4df6b42ce7cc838833d287db71e509e3fd9b060ddb20ba5c51dcc5948d46fbf640dfe0441782cab85fa4ac",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "46fc62106420ff012e54a434fbdd2d25ccc5852060561e68040dd7778997bd7b"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ECDHSharedSecret
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "curve": "P-256",
+///         "private_key": "7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534",
+///         "public_key": "04700c48f77f56584c5cc632ca65640db91b6bacce3a4df6b42ce7cc838833d287db71e509e3fd9b060ddb20ba5c51dcc5948d46fbf640dfe0441782cab85fa4ad",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Point is not on P-256 curve."
+/// }
+/// ```
+pub struct ECDHSharedSecret;
+
+const NAME: &str = "ECDHSharedSecret";
+const DESCRIPTION_EN: &str = "Computes an ECDH shared secret from your private key and the other party's public key on P-256, P-384 or secp256k1 curve. The result is the x coordinate of the shared point.";
+const DESCRIPTION_RU: &str = "Вычисляет общий секрет ECDH из вашего закрытого ключа и открытого ключа другой стороны на кривой P-256, P-384 или secp256k1. Результат — координата x общей точки.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Elliptic-curve_Diffie%E2%80%93Hellman");
+
+create_info_struct!(
+    ECDHSharedSecretInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
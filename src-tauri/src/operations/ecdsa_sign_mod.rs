@@ -0,0 +1,161 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{encode_output, OutputFormat},
+        ec_key::{EcCurve, EcKey, EcSignatureEncoding},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_ecdsasign, ECDSASign);
+
+impl Operation<'_, DeserializeMeDaddy> for ECDSASign {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            curve,
+            private_key,
+            deterministic,
+            signature_encoding,
+            output_format,
+        } = request;
+
+        let key = EcKey::parse_private(&private_key, curve)?;
+        let signature = key.sign(
+            input.as_bytes(),
+            deterministic.unwrap_or(true),
+            signature_encoding,
+        )?;
+
+        Ok(encode_output(&signature, output_format))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: EcCurve,
+    private_key: String,
+    deterministic: Option<bool>,
+    signature_encoding: EcSignatureEncoding,
+    output_format: OutputFormat,
+}
+
+/// Signs a message with ECDSA on P-256, P-384 or secp256k1 curve. The message is hashed with SHA-256, or with SHA-384 for P-384.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Elliptic_Curve_Digital_Signature_Algorithm).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ECDSASign with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": EcCurve,
+///         "private_key": string,
+///         "deterministic": Option<bool>,
+///         "signature_encoding": EcSignatureEncoding,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - EcCurve is enum of "P-256", "P-384", "secp256k1"
+///     - private_key is PKCS#8 or SEC1 private key in PEM or DER (hex or base64) format, or hex encoded private scalar
+///     - deterministic derives the nonce from the key and the message as described in RFC 6979, true by default
+///     - EcSignatureEncoding is enum of "der" and "raw", where "raw" is r and s concatenated
+///     - OutputFormat is enum of "hex", "base64", "raw"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ECDSASign
+///
+/// {
+///     "input": "sample",
+///     "params": {
+///         "curve": "P-256",
+///         "private_key": "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+///         "signature_encoding": "raw",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ECDSASign
+///
+/// {
+///     "input": "sample",
+///     "params": {
+///         "curve": "P-256",
+///         "private_key": "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+///         "signature_encoding": "der",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "3046022100efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716022100f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ECDSASign
+///
+/// {
+///     "input": "sample",
+///     "params": {
+///         "curve": "P-384",
+///         "private_key": "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+///         "signature_encoding": "der",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid private key for P-384 curve."
+/// }
+/// ```
+pub struct ECDSASign;
+
+const NAME: &str = "ECDSASign";
+const DESCRIPTION_EN: &str = "Signs a message with ECDSA on P-256, P-384 or secp256k1 curve. The message is hashed with SHA-256, or with SHA-384 for P-384.";
+const DESCRIPTION_RU: &str = "Подписывает сообщение по алгоритму ECDSA на кривой P-256, P-384 или secp256k1. Сообщение хешируется SHA-256, а для P-384 — SHA-384.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Elliptic_Curve_Digital_Signature_Algorithm");
+
+create_info_struct!(
+    ECDSASignInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
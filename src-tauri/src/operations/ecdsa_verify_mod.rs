@@ -0,0 +1,141 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{decode_input, InputFormat},
+        ec_key::{EcCurve, EcKey, EcSignatureEncoding},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_ecdsaverify, ECDSAVerify);
+
+impl Operation<'_, DeserializeMeDaddy> for ECDSAVerify {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            curve,
+            public_key,
+            signature,
+            signature_format,
+            signature_encoding,
+        } = request;
+
+        let key = EcKey::parse_public(&public_key, curve)?;
+        let signature = decode_input(&signature, signature_format)?;
+
+        match key.verify(input.as_bytes(), &signature, signature_encoding)? {
+            true => Ok("Verified OK.".to_string()),
+            false => Err(anyhow!("Verification failure.")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: EcCurve,
+    public_key: String,
+    signature: String,
+    signature_format: InputFormat,
+    signature_encoding: EcSignatureEncoding,
+}
+
+/// Verifies an ECDSA signature of a message on P-256, P-384 or secp256k1 curve. The message is hashed with SHA-256, or with SHA-384 for P-384.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Elliptic_Curve_Digital_Signature_Algorithm).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ECDSAVerify with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": EcCurve,
+///         "public_key": string,
+///         "signature": string,
+///         "signature_format": InputFormat,
+///         "signature_encoding": EcSignatureEncoding
+///     }
+/// }
+/// ```
+/// #### where
+///     - EcCurve is enum of "P-256", "P-384", "secp256k1"
+///     - public_key is SPKI public key in PEM or DER (hex or base64) format, or hex encoded SEC1 point, a private key can be used as well
+///     - InputFormat is enum of "hex", "base64", "raw"
+///     - EcSignatureEncoding is enum of "der" and "raw", where "raw" is r and s concatenated
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ECDSAVerify
+///
+/// {
+///     "input": "sample",
+///     "params": {
+///         "curve": "P-256",
+///         "public_key": "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6",
+///         "signature": "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+///         "signature_format": "hex",
+///         "signature_encoding": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Verified OK."
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ECDSAVerify
+///
+/// {
+///     "input": "example",
+///     "params": {
+///         "curve": "P-256",
+///         "public_key": "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6",
+///         "signature": "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+///         "signature_format": "hex",
+///         "signature_encoding": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Verification failure."
+/// }
+/// ```
+pub struct ECDSAVerify;
+
+const NAME: &str = "ECDSAVerify";
+const DESCRIPTION_EN: &str = "Verifies an ECDSA signature of a message on P-256, P-384 or secp256k1 curve. The message is hashed with SHA-256, or with SHA-384 for P-384.";
+const DESCRIPTION_RU: &str = "Проверяет подпись ECDSA сообщения на кривой P-256, P-384 или secp256k1. Сообщение хешируется SHA-256, а для P-384 — SHA-384.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Elliptic_Curve_Digital_Signature_Algorithm");
+
+create_info_struct!(
+    ECDSAVerifyInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
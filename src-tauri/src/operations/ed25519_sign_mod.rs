@@ -0,0 +1,220 @@
+use anyhow::Result;
+use ed25519_dalek::{Digest, Sha512, Signer, SigningKey};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{encode_output, OutputFormat},
+        curve25519_key::{Curve25519Algorithm, Curve25519Key, Ed25519Variant},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_ed25519sign, Ed25519Sign);
+
+impl Operation<'_, DeserializeMeDaddy> for Ed25519Sign {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            private_key,
+            variant,
+            context,
+            output_format,
+        } = request;
+
+        let key = Curve25519Key::parse_private(&private_key, Curve25519Algorithm::Ed25519)?;
+        let signing_key = SigningKey::from_bytes(&key.secret()?);
+
+        let signature = match variant {
+            Ed25519Variant::Ed25519 => signing_key.sign(input.as_bytes()),
+            Ed25519Variant::Ed25519ph => signing_key.sign_prehashed(
+                Sha512::new_with_prefix(input.as_bytes()),
+                context.as_deref().map(str::as_bytes),
+            )?,
+        };
+
+        Ok(encode_output(&signature.to_bytes(), output_format))
+    }
+
+    fn validate(&self, request: &'_ str) -> Result<DeserializeMeDaddy> {
+        let request = self.deserialize(request)?;
+        request.variant.check_context(request.context.as_deref())?;
+        Ok(request)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    private_key: String,
+    variant: Ed25519Variant,
+    context: Option<String>,
+    output_format: OutputFormat,
+}
+
+/// Signs a message with an Ed25519 private key. Ed25519ph variant signs SHA-512 hash of the message and accepts an optional context.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/EdDSA#Ed25519).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Ed25519Sign with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "private_key": string,
+///         "variant": Ed25519Variant,
+///         "context": Option<string>,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - private_key is Ed25519 private key as raw hex (32 byte seed or 64 byte seed with public key), PKCS#8 PEM or OpenSSH private key
+///     - Ed25519Variant is enum of "ed25519", "ed25519ph"
+///     - context is up to 255 bytes long and can be used only with "ed25519ph"
+///     - OutputFormat is enum of "hex", "base64", "raw"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Ed25519Sign
+///
+/// {
+///     "input": "r",
+///     "params": {
+///         "private_key": "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+///         "variant": "ed25519",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Ed25519Sign
+///
+/// {
+///     "input": "abc",
+///     "params": {
+///         "private_key": "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+///         "variant": "ed25519ph",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Ed25519Sign
+///
+/// {
+///     "input": "abc",
+///     "params": {
+///         "private_key": "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+///         "variant": "ed25519",
+///         "context": "foo",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Context is supported only by Ed25519ph."
+/// }
+/// ```
+pub struct Ed25519Sign;
+
+const NAME: &str = "Ed25519Sign";
+const DESCRIPTION_EN: &str = "Signs a message with an Ed25519 private key. Ed25519ph variant signs SHA-512 hash of the message and accepts an optional context.";
+const DESCRIPTION_RU: &str = "Подписывает сообщение закрытым ключом Ed25519. Вариант Ed25519ph подписывает хеш SHA-512 сообщения и принимает необязательный контекст.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/EdDSA#Ed25519");
+
+create_info_struct!(
+    Ed25519SignInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_ed25519verify, Ed25519Verify};
+
+    #[test]
+    fn rfc8032() {
+        // Section 7.1 tests 1 and 2 and section 7.3 test abc.
+        for (input, private_key, public_key, variant, signature) in [
+            (
+                "",
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "ed25519",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "r",
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "ed25519",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "abc",
+                "833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42",
+                "ec172b93ad5e563bf4932c70e1245034c35467ef2efd4d64ebf819683467e2bf",
+                "ed25519ph",
+                "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406",
+            ),
+        ] {
+            let request = format!(
+                r#"{{"private_key": "{private_key}", "variant": "{variant}", "output_format": "hex"}}"#
+            );
+            assert_eq!(
+                run_ed25519sign(Ed25519Sign, input, &request).unwrap(),
+                signature,
+                "{variant}"
+            );
+
+            let request = format!(
+                r#"{{"public_key": "{public_key}", "signature": "{signature}", "signature_format": "hex", "variant": "{variant}"}}"#
+            );
+            assert_eq!(
+                run_ed25519verify(Ed25519Verify, input, &request).unwrap(),
+                "Verified OK."
+            );
+            assert_eq!(
+                run_ed25519verify(Ed25519Verify, "tampered", &request).unwrap_err(),
+                "Verification failure."
+            );
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use anyhow::{anyhow, bail, Result};
+use ed25519_dalek::{Digest, Sha512, Signature, VerifyingKey};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{decode_input, InputFormat},
+        curve25519_key::{Curve25519Algorithm, Curve25519Key, Ed25519Variant},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_ed25519verify, Ed25519Verify);
+
+impl Operation<'_, DeserializeMeDaddy> for Ed25519Verify {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            public_key,
+            signature,
+            signature_format,
+            variant,
+            context,
+        } = request;
+
+        let key = Curve25519Key::parse_public(&public_key, Curve25519Algorithm::Ed25519)?;
+        let verifying_key = VerifyingKey::from_bytes(&key.public())?;
+        let signature = decode_input(&signature, signature_format)?;
+        let Ok(signature) = Signature::from_slice(&signature) else {
+            bail!(
+                "Signature must be {} bytes long, got {} bytes.",
+                Signature::BYTE_SIZE,
+                signature.len()
+            );
+        };
+
+        let verified = match variant {
+            Ed25519Variant::Ed25519 => verifying_key.verify_strict(input.as_bytes(), &signature),
+            Ed25519Variant::Ed25519ph => verifying_key.verify_prehashed_strict(
+                Sha512::new_with_prefix(input.as_bytes()),
+                context.as_deref().map(str::as_bytes),
+                &signature,
+            ),
+        };
+
+        match verified {
+            Ok(_) => Ok("Verified OK.".to_string()),
+            Err(_) => Err(anyhow!("Verification failure.")),
+        }
+    }
+
+    fn validate(&self, request: &'_ str) -> Result<DeserializeMeDaddy> {
+        let request = self.deserialize(request)?;
+        request.variant.check_context(request.context.as_deref())?;
+        Ok(request)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    public_key: String,
+    signature: String,
+    signature_format: InputFormat,
+    variant: Ed25519Variant,
+    context: Option<String>,
+}
+
+/// Verifies an Ed25519 or Ed25519ph signature of a message.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/EdDSA#Ed25519).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Ed25519Verify with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "public_key": string,
+///         "signature": string,
+///         "signature_format": InputFormat,
+///         "variant": Ed25519Variant,
+///         "context": Option<string>
+///     }
+/// }
+/// ```
+/// #### where
+///     - public_key is Ed25519 public key as raw hex, SPKI PEM or OpenSSH public key, a private key in PEM or OpenSSH format can be used as well
+///     - InputFormat is enum of "hex", "base64", "raw"
+///     - Ed25519Variant is enum of "ed25519", "ed25519ph"
+///     - context is up to 255 bytes long and can be used only with "ed25519ph"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Ed25519Verify
+///
+/// {
+///     "input": "r",
+///     "params": {
+///         "public_key": "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+///         "signature": "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+///         "signature_format": "hex",
+///         "variant": "ed25519"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Verified OK."
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Ed25519Verify
+///
+/// {
+///     "input": "s",
+///     "params": {
+///         "public_key": "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+///         "signature": "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+///         "signature_format": "hex",
+///         "variant": "ed25519"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Verification failure."
+/// }
+/// ```
+pub struct Ed25519Verify;
+
+const NAME: &str = "Ed25519Verify";
+const DESCRIPTION_EN: &str = "Verifies an Ed25519 or Ed25519ph signature of a message.";
+const DESCRIPTION_RU: &str = "Проверяет подпись сообщения Ed25519 или Ed25519ph.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/EdDSA#Ed25519");
+
+create_info_struct!(
+    Ed25519VerifyInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,167 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::cipher_machines::enigma::{
+        enigma, EnigmaFourthRotor, EnigmaModel, EnigmaReflector, EnigmaRotor, EnigmaSettings,
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_enigma, Enigma);
+
+impl Operation<'_, DeserializeMeDaddy> for Enigma {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+
+        let mut machine = enigma(EnigmaSettings {
+            model: request.model,
+            fourth_rotor: request.fourth_rotor,
+            rotors: request.rotors,
+            reflector: request.reflector,
+            ring_settings: &request.ring_settings,
+            positions: &request.positions,
+            plugboard: &request.plugboard,
+        })?;
+
+        Ok(machine.encipher(input))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    model: EnigmaModel,
+    fourth_rotor: Option<EnigmaFourthRotor>,
+    rotors: [EnigmaRotor; 3],
+    reflector: EnigmaReflector,
+    ring_settings: String,
+    positions: String,
+    plugboard: String,
+}
+
+/// The Enigma is a rotor cipher machine used by the German armed forces. Every letter goes through the plugboard, three rotors, the reflector and back, and the rightmost rotor turns before every letter, turning the others over at their notches with the double stepping of the middle rotor. The naval M4 adds a fourth rotor that stays in place and a thin reflector. The machine is reciprocal, so the same settings both encrypt and decrypt. Only the latin letters are enciphered, in upper case, other characters are left as is and don't move the rotors.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Enigma_machine).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Enigma with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "model": EnigmaModel,
+///         "fourth_rotor": Option<EnigmaFourthRotor>,
+///         "rotors": [EnigmaRotor],
+///         "reflector": EnigmaReflector,
+///         "ring_settings": string,
+///         "positions": string,
+///         "plugboard": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - EnigmaModel is enum of "m3", "m4"
+///     - Option<EnigmaFourthRotor> is optional enum of "beta", "gamma", the fourth rotor of the M4
+///     - [EnigmaRotor] is array of three rotors from left to right, each is enum of "i", "ii", "iii", "iv", "v", "vi", "vii", "viii"
+///     - EnigmaReflector is enum of "b", "c" for the M3 and "b_thin", "c_thin" for the M4
+///     - ring_settings and positions are letters for every rotor from left to right, starting with the fourth rotor of the M4
+///     - plugboard is pairs of swapped letters separated by spaces, like "AB CD"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Enigma
+///
+/// {
+///     "input": "EDPUD NRGYS ZRCXN UYTPO MRMBO FKTBZ REZKM LXLVE FGUEY SIOZV EQMIK UBPMM YLKLT TDEIS MDICA GYKUA CTCDO MOHWX MUUIA UBSTS LRNBZ SZWNR FXWFY SSXJZ VIJHI DISHP RKLKA YUPAD TXQSP INQMA TLPIF SVKDA SCTAC DPBOP VHJK",
+///     "params": {
+///         "model": "m3",
+///         "rotors": ["ii", "iv", "v"],
+///         "reflector": "b",
+///         "ring_settings": "BUL",
+///         "positions": "BLA",
+///         "plugboard": "AV BS CG DL FU HZ IN KM OW RX"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "AUFKL XABTE ILUNG XVONX KURTI NOWAX KURTI NOWAX NORDW ESTLX SEBEZ XSEBE ZXUAF FLIEG ERSTR ASZER IQTUN GXDUB ROWKI XDUBR OWKIX OPOTS CHKAX OPOTS CHKAX UMXEI NSAQT DREIN ULLXU HRANG ETRET ENXAN GRIFF XINFX RGTX"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Enigma
+///
+/// {
+///     "input": "LANO TCTO UARB BFPM HPHG CZXT DYGA HGUF XGEW KBLK GJWL QXXT GPJJ AVTO CKZF SLPP QIHZ FXOE BWII EKFZ LCLO AQJU LJOY HSSM BBGW HZAN VOII PYRB RTDJ QDJJ OQKC XWDN BBTY VXLY TAPG VEAT XSON PNYN QFUD BBHH VWEP YEYD OHNL XKZD NWRH DUWU JUMW WVII WZXI VIUQ DRHY MNCY EFUA PNHO TKHK GDNP SAKN UAGH JZSM JBMH VTRE QEDG XHLZ WIFU SKDQ VELN MIMI THBH DBWV HDFY HJOQ IHOR TDJD BWXE MEAY XGYQ XOHF DMYU XXNO JAZR SGHP LWML RECW WUTL RTTV LBHY OORG LGOW UXNX HMHY FAAC QEKT HSJW",
+///     "params": {
+///         "model": "m4",
+///         "fourth_rotor": "beta",
+///         "rotors": ["v", "vi", "viii"],
+///         "reflector": "c_thin",
+///         "ring_settings": "EPEL",
+///         "positions": "CDSZ",
+///         "plugboard": "AE BF CM DQ HU JN LX PR SZ VW"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "KRKR ALLE XXFO LGEN DESI STSO FORT BEKA NNTZ UGEB ENXX ICHH ABEF OLGE LNBE BEFE HLER HALT ENXX JANS TERL EDES BISH ERIG XNRE ICHS MARS CHAL LSJG OERI NGJS ETZT DERF UEHR ERSI EYHV RRGR ZSSA DMIR ALYA LSSE INEN NACH FOLG EREI NXSC HRIF TLSC HEVO LLMA CHTU NTER WEGS XABS OFOR TSOL LENS IESA EMTL ICHE MASS NAHM ENVE RFUE GENY DIES ICHA USDE RGEG ENWA ERTI GENL AGEE RGEB ENXG EZXR EICH SLEI TEIK KTUL PEKK JBOR MANN JXXO BXDX MMMD URNH FKST XKOM XADM XUUU BOOI EXKP"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Enigma
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "model": "m3",
+///         "rotors": ["i", "ii", "iii"],
+///         "reflector": "b",
+///         "ring_settings": "AA",
+///         "positions": "AA",
+///         "plugboard": ""
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Ring settings and positions must have a letter for each of the 3 rotors."
+/// }
+/// ```
+pub struct Enigma;
+
+const NAME: &str = "Enigma";
+const DESCRIPTION_EN: &str = "The Enigma is a rotor cipher machine used by the German armed forces. Every letter goes through the plugboard, three rotors, the reflector and back, and the rightmost rotor turns before every letter, turning the others over at their notches with the double stepping of the middle rotor. The naval M4 adds a fourth rotor that stays in place and a thin reflector. The machine is reciprocal, so the same settings both encrypt and decrypt. Only the latin letters are enciphered, in upper case, other characters are left as is and don't move the rotors.";
+const DESCRIPTION_RU: &str = "Энигма — роторная шифровальная машина вооружённых сил Германии. Каждая буква проходит через коммутационную панель, три ротора, рефлектор и обратно, правый ротор поворачивается перед каждой буквой и на своих выемках поворачивает остальные, средний ротор при этом делает двойной шаг. Морская M4 добавляет неподвижный четвёртый ротор и тонкий рефлектор. Машина взаимна, поэтому одни и те же настройки и шифруют, и расшифровывают. Шифруются только латинские буквы, в верхнем регистре, остальные символы остаются как есть и не поворачивают роторы.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Enigma_machine");
+
+create_info_struct!(
+    EnigmaInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
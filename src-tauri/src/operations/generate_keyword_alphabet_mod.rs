@@ -0,0 +1,131 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, ciphers::keyword_alphabet},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_generatekeywordalphabet, GenerateKeywordAlphabet);
+
+impl Operation<'_, DeserializeMeDaddy> for GenerateKeywordAlphabet {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let lang = request.lang;
+
+        if input
+            .chars()
+            .any(|ch| !ch.is_alphabetic() && !ch.is_whitespace() && !lang.contains(ch))
+        {
+            bail!("The keyword must consist only of letters.");
+        }
+
+        Ok(keyword_alphabet(&lang, input))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+}
+
+/// Generates a mixed alphabet for the substitution cipher: the letters of the keyword without repeats followed by the rest of the alphabet in its usual order. Letters of the keyword from other alphabets are skipped.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Substitution_cipher#Simple_substitution).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/GenerateKeywordAlphabet with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/GenerateKeywordAlphabet
+///
+/// {
+///     "input": "Zebras",
+///     "params": {
+///         "lang": "en"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "zebrascdfghijklmnopqtuvwxy"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/GenerateKeywordAlphabet
+///
+/// {
+///     "input": "Ёлочка",
+///     "params": {
+///         "lang": "ru_with_yo"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ёлочкабвгдежзиймнпрстуфхцшщъыьэюя"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/GenerateKeywordAlphabet
+///
+/// {
+///     "input": "key-1",
+///     "params": {
+///         "lang": "en"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "The keyword must consist only of letters."
+/// }
+/// ```
+pub struct GenerateKeywordAlphabet;
+
+const NAME: &str = "GenerateKeywordAlphabet";
+const DESCRIPTION_EN: &str = "Generates a mixed alphabet for the substitution cipher: the letters of the keyword without repeats followed by the rest of the alphabet in its usual order. Letters of the keyword from other alphabets are skipped.";
+const DESCRIPTION_RU: &str = "Генерирует смешанный алфавит для шифра простой замены: буквы ключевого слова без повторов, за которыми следуют остальные буквы алфавита в обычном порядке. Буквы ключевого слова из других алфавитов пропускаются.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/Substitution_cipher#Simple_substitution");
+
+create_info_struct!(
+    GenerateKeywordAlphabetInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
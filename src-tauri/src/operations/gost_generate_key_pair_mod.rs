@@ -0,0 +1,117 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::gost_3410::{GostCurve, GostKey},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_gostgeneratekeypair, GOSTGenerateKeyPair);
+
+impl Operation<'_, DeserializeMeDaddy> for GOSTGenerateKeyPair {
+    fn do_black_magic(&self, _input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { curve } = request;
+
+        let key = GostKey::generate(curve);
+
+        Ok(format!(
+            "{}\n{}",
+            key.encode_private()?,
+            key.encode_public()
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: GostCurve,
+}
+
+/// Generates a new GOST R 34.10-2012 key pair over one of the standard curve parameter sets. The private key is printed first, followed by the public key.
+/// <br><br/>
+/// For more information go [here](https://datatracker.ietf.org/doc/html/rfc7091).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/GOSTGenerateKeyPair with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": GostCurve
+///     }
+/// }
+/// ```
+/// #### where
+///     - GostCurve is enum of "id-tc26-gost-3410-2012-256-paramSetA", "id-tc26-gost-3410-2012-256-paramSetB",
+///       "id-tc26-gost-3410-2012-256-paramSetC", "id-tc26-gost-3410-2012-256-paramSetD",
+///       "id-tc26-gost-3410-2012-512-paramSetA", "id-tc26-gost-3410-2012-512-paramSetB",
+///       "id-tc26-gost-3410-2012-512-paramSetC" and the test sets "id-GostR3410-2001-TestParamSet",
+///       "id-tc26-gost-3410-2012-512-paramSetTest"
+///     - the private key is hex encoded number d, the public key is hex encoded coordinates x and y concatenated
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/GOSTGenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "curve": "id-tc26-gost-3410-2012-256-paramSetB"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "edd82fff73c62a52022e70528d8bbadc9638736c92b84ee7db35d6ed032580fa\n56fb9fdfa3d54499057a43ab50fa6df8a7dd5cb37bc4609240ac86965db0fabd58061968fac9c10255a7e981da53920f53928809a46c9b000eb3fa9b9755e4bb"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/GOSTGenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "curve": "id-tc26-gost-3410-2012-1024-paramSetA"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Unknown variant `id-tc26-gost-3410-2012-1024-paramSetA`, expected one of `id-GostR3410-2001-TestParamSet`, `id-tc26-gost-3410-2012-256-paramSetA`, `id-tc26-gost-3410-2012-256-paramSetB`, `id-tc26-gost-3410-2012-256-paramSetC`, `id-tc26-gost-3410-2012-256-paramSetD`, `id-tc26-gost-3410-2012-512-paramSetTest`, `id-tc26-gost-3410-2012-512-paramSetA`, `id-tc26-gost-3410-2012-512-paramSetB`, `id-tc26-gost-3410-2012-512-paramSetC`."
+/// }
+/// ```
+pub struct GOSTGenerateKeyPair;
+
+const NAME: &str = "GOSTGenerateKeyPair";
+const DESCRIPTION_EN: &str = "Generates a new GOST R 34.10-2012 key pair over one of the standard curve parameter sets. The private key is printed first, followed by the public key.";
+const DESCRIPTION_RU: &str = "Генерирует новую пару ключей ГОСТ Р 34.10-2012 на одном из стандартных наборов параметров кривых. Сначала выводится закрытый ключ, затем открытый.";
+
+const INFO_URL: Option<&str> = Some("https://datatracker.ietf.org/doc/html/rfc7091");
+
+create_info_struct!(
+    GOSTGenerateKeyPairInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
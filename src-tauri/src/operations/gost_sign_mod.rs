@@ -0,0 +1,129 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{encode_output, OutputFormat},
+        gost_3410::{GostCurve, GostKey},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_gostsign, GOSTSign);
+
+impl Operation<'_, DeserializeMeDaddy> for GOSTSign {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            curve,
+            private_key,
+            output_format,
+        } = request;
+
+        let key = GostKey::parse_private(&private_key, curve)?;
+
+        Ok(encode_output(&key.sign(input.as_bytes())?, output_format))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: GostCurve,
+    private_key: String,
+    output_format: OutputFormat,
+}
+
+/// Signs a message with GOST R 34.10-2012. The message is hashed with Streebog-256 for 256-bit curves and with Streebog-512 for 512-bit curves. The signature is s and r concatenated as in RFC 7091.
+/// <br><br/>
+/// For more information go [here](https://datatracker.ietf.org/doc/html/rfc7091).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/GOSTSign with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": GostCurve,
+///         "private_key": string,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - GostCurve is enum of "id-tc26-gost-3410-2012-256-paramSetA", "id-tc26-gost-3410-2012-256-paramSetB",
+///       "id-tc26-gost-3410-2012-256-paramSetC", "id-tc26-gost-3410-2012-256-paramSetD",
+///       "id-tc26-gost-3410-2012-512-paramSetA", "id-tc26-gost-3410-2012-512-paramSetB",
+///       "id-tc26-gost-3410-2012-512-paramSetC" and the test sets "id-GostR3410-2001-TestParamSet",
+///       "id-tc26-gost-3410-2012-512-paramSetTest"
+///     - private_key is hex encoded number d
+///     - OutputFormat is enum of "hex", "base64", "raw"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/GOSTSign
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "curve": "id-GostR3410-2001-TestParamSet",
+///         "private_key": "7a929ade789bb9be10ed359dd39a72c11b60961f49397eee1d19ce9891ec3b28",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "4d920d3666782c95fb7bc9cb3cd6a585eafcf643a528911f66bf0166356715665982c1427acd321a578f5775637ed10575bc079d3778c793d8570418bcf5bdec"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/GOSTSign
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "curve": "id-GostR3410-2001-TestParamSet",
+///         "private_key": "8000000000000000000000000000000150fe8a1892976154c59cfc193accf5b3",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Private key must be a number between 1 and q - 1 of the curve."
+/// }
+/// ```
+pub struct GOSTSign;
+
+const NAME: &str = "GOSTSign";
+const DESCRIPTION_EN: &str = "Signs a message with GOST R 34.10-2012. The message is hashed with Streebog-256 for 256-bit curves and with Streebog-512 for 512-bit curves. The signature is s and r concatenated as in RFC 7091.";
+const DESCRIPTION_RU: &str = "Подписывает сообщение по ГОСТ Р 34.10-2012. Сообщение хешируется Стрибогом-256 для 256-битных кривых и Стрибогом-512 для 512-битных. Подпись — конкатенация s и r, как в RFC 7091.";
+
+const INFO_URL: Option<&str> = Some("https://datatracker.ietf.org/doc/html/rfc7091");
+
+create_info_struct!(
+    GOSTSignInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,138 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{decode_input, InputFormat},
+        gost_3410::{GostCurve, GostKey},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_gostverify, GOSTVerify);
+
+impl Operation<'_, DeserializeMeDaddy> for GOSTVerify {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            curve,
+            public_key,
+            signature,
+            signature_format,
+        } = request;
+
+        let key = GostKey::parse_public(&public_key, curve)?;
+        let signature = decode_input(&signature, signature_format)?;
+
+        match key.verify(input.as_bytes(), &signature)? {
+            true => Ok("Verified OK.".to_string()),
+            false => Err(anyhow!("Verification failure.")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    curve: GostCurve,
+    public_key: String,
+    signature: String,
+    signature_format: InputFormat,
+}
+
+/// Verifies a GOST R 34.10-2012 signature of a message. The message is hashed with Streebog-256 for 256-bit curves and with Streebog-512 for 512-bit curves. The signature is s and r concatenated as in RFC 7091.
+/// <br><br/>
+/// For more information go [here](https://datatracker.ietf.org/doc/html/rfc7091).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/GOSTVerify with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "curve": GostCurve,
+///         "public_key": string,
+///         "signature": string,
+///         "signature_format": InputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - GostCurve is enum of "id-tc26-gost-3410-2012-256-paramSetA", "id-tc26-gost-3410-2012-256-paramSetB",
+///       "id-tc26-gost-3410-2012-256-paramSetC", "id-tc26-gost-3410-2012-256-paramSetD",
+///       "id-tc26-gost-3410-2012-512-paramSetA", "id-tc26-gost-3410-2012-512-paramSetB",
+///       "id-tc26-gost-3410-2012-512-paramSetC" and the test sets "id-GostR3410-2001-TestParamSet",
+///       "id-tc26-gost-3410-2012-512-paramSetTest"
+///     - public_key is hex encoded coordinates x and y concatenated, optionally prefixed with 04
+///     - InputFormat is enum of "hex", "base64", "raw"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/GOSTVerify
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "curve": "id-GostR3410-2001-TestParamSet",
+///         "public_key": "7f2b49e270db6d90d8595bec458b50c58585ba1d4e9b788f6689dbd8e56fd80b26f1b489d6701dd185c8413a977b3cbbaf64d1c593d26627dffb101a87ff77da",
+///         "signature": "4d920d3666782c95fb7bc9cb3cd6a585eafcf643a528911f66bf0166356715665982c1427acd321a578f5775637ed10575bc079d3778c793d8570418bcf5bdec",
+///         "signature_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Verified OK."
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/GOSTVerify
+///
+/// {
+///     "input": "hellO",
+///     "params": {
+///         "curve": "id-GostR3410-2001-TestParamSet",
+///         "public_key": "7f2b49e270db6d90d8595bec458b50c58585ba1d4e9b788f6689dbd8e56fd80b26f1b489d6701dd185c8413a977b3cbbaf64d1c593d26627dffb101a87ff77da",
+///         "signature": "4d920d3666782c95fb7bc9cb3cd6a585eafcf643a528911f66bf0166356715665982c1427acd321a578f5775637ed10575bc079d3778c793d8570418bcf5bdec",
+///         "signature_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Verification failure."
+/// }
+/// ```
+pub struct GOSTVerify;
+
+const NAME: &str = "GOSTVerify";
+const DESCRIPTION_EN: &str = "Verifies a GOST R 34.10-2012 signature of a message. The message is hashed with Streebog-256 for 256-bit curves and with Streebog-512 for 512-bit curves. The signature is s and r concatenated as in RFC 7091.";
+const DESCRIPTION_RU: &str = "Проверяет подпись сообщения по ГОСТ Р 34.10-2012. Сообщение хешируется Стрибогом-256 для 256-битных кривых и Стрибогом-512 для 512-битных. Подпись — конкатенация s и r, как в RFC 7091.";
+
+const INFO_URL: Option<&str> = Some("https://datatracker.ietf.org/doc/html/rfc7091");
+
+create_info_struct!(
+    GOSTVerifyInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,178 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, ciphers::gronsfeld_key, vigenere_trait::VigenereCipher},
+    run_op,
+    utils::sub,
+    Operation, DOCS_URL,
+};
+
+impl VigenereCipher for GronsfeldCipherDecode {}
+
+run_op!(run_gronsfeldcipherdecode, GronsfeldCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for GronsfeldCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, key } = request;
+
+        let key = gronsfeld_key(&lang, &key)?;
+        <Self as VigenereCipher>::cipher(&lang, &key, input, sub)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    key: String,
+}
+
+/// The Gronsfeld cipher is a Vigenère cipher with a numeric key: every digit of the key is the shift of the corresponding letter, so only the first ten Caesar shifts are used.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Vigenère_cipher#Gronsfeld_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/GronsfeldCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "key": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+///     - key is a string of digits
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/GronsfeldCipherDecode
+///
+/// {
+///     "input": "Kfpmt, Zpvmi!",
+///     "params": {
+///         "lang": "en",
+///         "key": "31415"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Hello, World!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/GronsfeldCipherDecode
+///
+/// {
+///     "input": "Счйкзщ, Нрт!",
+///     "params": {
+///         "lang": "ru",
+///         "key": "2718"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Привет, Мир!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/GronsfeldCipherDecode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "31a"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Key must consist only of digits."
+/// }
+/// ```
+pub struct GronsfeldCipherDecode;
+
+const NAME: &str = "GronsfeldCipherDecode";
+const DESCRIPTION_EN: &str = "The Gronsfeld cipher is a Vigenère cipher with a numeric key: every digit of the key is the shift of the corresponding letter, so only the first ten Caesar shifts are used.";
+const DESCRIPTION_RU: &str = "Шифр Гронсфельда — это шифр Виженера с числовым ключом: каждая цифра ключа задаёт сдвиг соответствующей буквы, поэтому используются только первые десять сдвигов Цезаря.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Vigenère_cipher#Gronsfeld_cipher");
+
+create_info_struct!(
+    GronsfeldCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_gronsfeldcipherencode, GronsfeldCipherEncode};
+
+    fn request(lang: &str, key: &str) -> String {
+        format!(r#"{{"lang": "{}", "key": "{}"}}"#, lang, key)
+    }
+
+    #[test]
+    fn known_ciphertext() {
+        let request = request("en", "31415");
+        assert_eq!(
+            run_gronsfeldcipherencode(GronsfeldCipherEncode, "Hello, World!", &request).unwrap(),
+            "Kfpmt, Zpvmi!"
+        );
+        assert_eq!(
+            run_gronsfeldcipherdecode(GronsfeldCipherDecode, "Kfpmt, Zpvmi!", &request).unwrap(),
+            "Hello, World!"
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        for (input, lang, key) in [
+            ("Attack at Dawn, 42 men!", "en", "0123456789"),
+            (
+                "Съешь же ещё этих мягких французских булок, 42 раза!",
+                "ru_with_yo",
+                "2718",
+            ),
+        ] {
+            let request = request(lang, key);
+            let encoded =
+                run_gronsfeldcipherencode(GronsfeldCipherEncode, input, &request).unwrap();
+            assert_ne!(encoded, input);
+            assert_eq!(
+                run_gronsfeldcipherdecode(GronsfeldCipherDecode, &encoded, &request).unwrap(),
+                input
+            );
+        }
+    }
+}
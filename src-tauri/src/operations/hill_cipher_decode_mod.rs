@@ -0,0 +1,133 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        hill::{hill_cipher, inverse, key_matrix},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_hillcipherdecode, HillCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for HillCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, key } = request;
+
+        let matrix = inverse(&key_matrix(&lang, &key)?, lang.length() as i64)?;
+
+        hill_cipher(input, &lang, &matrix, None)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    key: String,
+}
+
+/// Decodes text encrypted with the Hill cipher by multiplying the blocks of letters by the inverse of the key matrix modulo the alphabet length. The number of the alphabet characters must be a multiple of the key size, characters outside of the alphabet are kept in place, fillers added while encoding are kept in the output.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Hill_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/HillCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "key": String
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/HillCipherDecode
+///
+/// {
+///     "input": "POH",
+///     "params": {
+///         "lang": "en",
+///         "key": "GYBNQKURP"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ACT"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/HillCipherDecode
+///
+/// {
+///     "input": "Алувь ве шкф эьъв тшитъв сдъжьюялщфю рнэлж",
+///     "params": {
+///         "lang": "ru_with_yo",
+///         "key": "шифр"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Съешь же ещё этих мягких французских булок"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/HillCipherDecode
+///
+/// {
+///     "input": "POHA",
+///     "params": {
+///         "lang": "en",
+///         "key": "GYBNQKURP"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "The number of the alphabet characters must be a multiple of the key size."
+/// }
+/// ```
+pub struct HillCipherDecode;
+
+const NAME: &str = "HillCipherDecode";
+const DESCRIPTION_EN: &str = "Decodes text encrypted with the Hill cipher by multiplying the blocks of letters by the inverse of the key matrix modulo the alphabet length. The number of the alphabet characters must be a multiple of the key size, characters outside of the alphabet are kept in place, fillers added while encoding are kept in the output.";
+const DESCRIPTION_RU: &str = "Расшифровывает текст, зашифрованный шифром Хилла, умножая блоки букв на обратную матрицу ключа по модулю длины алфавита. Число символов алфавита должно быть кратно размеру ключа, символы вне алфавита остаются на своих местах, заполнители, добавленные при шифровании, остаются в результате.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Hill_cipher");
+
+create_info_struct!(
+    HillCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,140 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        hill::{hill_cipher, inverse, key_matrix},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_hillcipherencode, HillCipherEncode);
+
+impl Operation<'_, DeserializeMeDaddy> for HillCipherEncode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, key, filler } = request;
+
+        let matrix = key_matrix(&lang, &key)?;
+        // A text encrypted with a singular matrix can't be decrypted.
+        inverse(&matrix, lang.length() as i64)?;
+
+        hill_cipher(input, &lang, &matrix, Some(filler))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    key: String,
+    filler: char,
+}
+
+/// The Hill cipher encrypts blocks of letters by multiplying them by the key matrix modulo the alphabet length. The key of n*n letters fills the n x n matrix row by row and must have a determinant coprime to the alphabet length, so that the matrix can be inverted. Characters outside of the alphabet are kept in place, the filler completes the last block.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Hill_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/HillCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "key": String,
+///         "filler": char
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/HillCipherEncode
+///
+/// {
+///     "input": "ACT",
+///     "params": {
+///         "lang": "en",
+///         "key": "GYBNQKURP",
+///         "filler": "X"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "POH"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/HillCipherEncode
+///
+/// {
+///     "input": "Съешь же ещё этих мягких французских булок",
+///     "params": {
+///         "lang": "ru_with_yo",
+///         "key": "шифр",
+///         "filler": "я"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Алувь ве шкф эьъв тшитъв сдъжьюялщфю рнэлж"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/HillCipherEncode
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "lang": "en",
+///         "key": "GOOD",
+///         "filler": "x"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "The key matrix is not invertible, its determinant 4 is not coprime to the alphabet length (26)."
+/// }
+/// ```
+pub struct HillCipherEncode;
+
+const NAME: &str = "HillCipherEncode";
+const DESCRIPTION_EN: &str = "The Hill cipher encrypts blocks of letters by multiplying them by the key matrix modulo the alphabet length. The key of n*n letters fills the n x n matrix row by row and must have a determinant coprime to the alphabet length, so that the matrix can be inverted. Characters outside of the alphabet are kept in place, the filler completes the last block.";
+const DESCRIPTION_RU: &str = "Шифр Хилла шифрует блоки букв, умножая их на матрицу ключа по модулю длины алфавита. Ключ из n*n букв заполняет матрицу n x n построчно, её определитель должен быть взаимно прост с длиной алфавита, чтобы матрицу можно было обратить. Символы вне алфавита остаются на своих местах, заполнитель дополняет последний блок.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Hill_cipher");
+
+create_info_struct!(
+    HillCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-use hmac::{Hmac, Mac};
+use hmac::Hmac;
 use md2::*;
 use md4::*;
 use md5::*;
@@ -12,7 +12,7 @@ use whirlpool::*;
 
 use crate::{
     create_info_struct,
-    libs::base64::to_base64,
+    libs::{base64::to_base64, mac::hmac},
     run_op,
     utils::{convert_to_byte_array, to_hex, SupportedFormats},
     Operation, DOCS_URL,
@@ -33,82 +33,23 @@ impl Operation<'_, DeserializeMeDaddy> for HMAC {
 
         let key = convert_to_byte_array(&key, &key_format)?;
 
+        let message = input.as_bytes();
         let res = match hash_function {
-            SupportedHashFunctions::MD2 => {
-                let mut hasher = HmacMD2::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::MD4 => {
-                let mut hasher = HmacMD4::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::MD5 => {
-                let mut hasher = HmacMD5::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA1 => {
-                let mut hasher = HmacSha1::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA224 => {
-                let mut hasher = HmacSha224::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA256 => {
-                let mut hasher = HmacSha256::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA384 => {
-                let mut hasher = HmacSha384::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA512 => {
-                let mut hasher = HmacSha512::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA512_224 => {
-                let mut hasher = HmacSha512_224::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::SHA512_256 => {
-                let mut hasher = HmacSha512_256::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd128 => {
-                let mut hasher = HmacRipemd128::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd160 => {
-                let mut hasher = HmacRipemd160::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd256 => {
-                let mut hasher = HmacRipemd256::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::Ripemd320 => {
-                let mut hasher = HmacRipemd320::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
-            SupportedHashFunctions::WhirlPool => {
-                let mut hasher = HmacWhirlPool::new_from_slice(&key)?;
-                hasher.update(input.as_bytes());
-                hasher.finalize().into_bytes().to_vec()
-            }
+            SupportedHashFunctions::MD2 => hmac::<HmacMD2>(&key, message)?,
+            SupportedHashFunctions::MD4 => hmac::<HmacMD4>(&key, message)?,
+            SupportedHashFunctions::MD5 => hmac::<HmacMD5>(&key, message)?,
+            SupportedHashFunctions::SHA1 => hmac::<HmacSha1>(&key, message)?,
+            SupportedHashFunctions::SHA224 => hmac::<HmacSha224>(&key, message)?,
+            SupportedHashFunctions::SHA256 => hmac::<HmacSha256>(&key, message)?,
+            SupportedHashFunctions::SHA384 => hmac::<HmacSha384>(&key, message)?,
+            SupportedHashFunctions::SHA512 => hmac::<HmacSha512>(&key, message)?,
+            SupportedHashFunctions::SHA512_224 => hmac::<HmacSha512_224>(&key, message)?,
+            SupportedHashFunctions::SHA512_256 => hmac::<HmacSha512_256>(&key, message)?,
+            SupportedHashFunctions::Ripemd128 => hmac::<HmacRipemd128>(&key, message)?,
+            SupportedHashFunctions::Ripemd160 => hmac::<HmacRipemd160>(&key, message)?,
+            SupportedHashFunctions::Ripemd256 => hmac::<HmacRipemd256>(&key, message)?,
+            SupportedHashFunctions::Ripemd320 => hmac::<HmacRipemd320>(&key, message)?,
+            SupportedHashFunctions::WhirlPool => hmac::<HmacWhirlPool>(&key, message)?,
         };
 
         Ok(match output_format {
@@ -0,0 +1,238 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::cipher_machines::lorenz::{
+        bletchley_to_ita2, ita2_to_bletchley, ita2_to_text, text_to_ita2, LorenzMachine,
+        LorenzMode, LorenzModel, LorenzSettings, TeleprinterFormat,
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_lorenz, Lorenz);
+
+impl Operation<'_, DeserializeMeDaddy> for Lorenz {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+
+        let codes = match request.input_format {
+            TeleprinterFormat::Plaintext => text_to_ita2(input)?,
+            TeleprinterFormat::Ita2 => bletchley_to_ita2(input)?,
+        };
+
+        let mut machine = LorenzMachine::new(LorenzSettings {
+            model: request.model,
+            chi: &request.chi,
+            psi: &request.psi,
+            mu: &request.mu,
+            chi_start: request.chi_start,
+            psi_start: request.psi_start,
+            mu_start: request.mu_start,
+        })?;
+        let codes = machine.encipher(&codes, request.mode);
+
+        Ok(match request.output_format {
+            TeleprinterFormat::Plaintext => ita2_to_text(&codes),
+            TeleprinterFormat::Ita2 => ita2_to_bletchley(&codes),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    model: LorenzModel,
+    mode: LorenzMode,
+    input_format: TeleprinterFormat,
+    output_format: TeleprinterFormat,
+    chi: [String; 5],
+    psi: [String; 5],
+    mu: [String; 2],
+    chi_start: [usize; 5],
+    psi_start: [usize; 5],
+    mu_start: [usize; 2],
+}
+
+/// The Lorenz SZ40/42 is a teleprinter cipher attachment used by the German Army High Command. Every 5-bit ITA2 code is added to the sum of a character from the five chi wheels and a character from the five psi wheels, the chi wheels move after every character and the psi wheels move together when the two mu (motor) wheels let them, on the SZ42 the motor is also controlled by the limitation. The same operation both encrypts and decrypts, the mode only tells the SZ42b limitation which of the texts is the plaintext. The plaintext format takes the text in letters, figures and spaces and inserts the shifts, the ITA2 format writes the codes in the Bletchley Park notation: letters for the letter codes, / for null, 9 for space, 3 for line feed, 4 for carriage return, 5 or + for figures shift and 8 or - for letters shift.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Lorenz_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Lorenz with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "model": LorenzModel,
+///         "mode": LorenzMode,
+///         "input_format": TeleprinterFormat,
+///         "output_format": TeleprinterFormat,
+///         "chi": [string],
+///         "psi": [string],
+///         "mu": [string],
+///         "chi_start": [usize],
+///         "psi_start": [usize],
+///         "mu_start": [usize]
+///     }
+/// }
+/// ```
+/// #### where
+///     - LorenzModel is enum of "sz40", "sz42a", "sz42b"
+///     - LorenzMode is enum of "send", "receive"
+///     - TeleprinterFormat is enum of "plaintext", "ita2"
+///     - chi, psi and mu are cam patterns of the wheels written with "x" for a cross and "." for a dot, the chi wheels have 41, 31, 29, 26 and 23 cams, the psi wheels have 43, 47, 51, 53 and 59 cams, the mu wheels have 61 and 37 cams
+///     - chi_start, psi_start and mu_start are start positions of the wheels, counted from 1
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Lorenz
+///
+/// {
+///     "input": "Attack at 0600 hours",
+///     "params": {
+///         "model": "sz42b",
+///         "mode": "send",
+///         "input_format": "plaintext",
+///         "output_format": "ita2",
+///         "chi": [
+///             ".x.xxxx...xxx.xx...xxxx.x..x.x.......x.x.",
+///             ".x...x...x.....xx...x...xxx....",
+///             "x......x.x..x.....x.....x...x",
+///             "..x.x..x..x..x....xxx.xxxx",
+///             "...xxx....x.x..x.xxxxxx"
+///         ],
+///         "psi": [
+///             ".x..x.xx..xxxx...xxx.....x......xxx..xx.x.x",
+///             "x.x.x..x..x.x..xx......xxxx.....x...x.x.xx.x.xx",
+///             "xxxx..x....xx....x.xxxx..xx..xxxxx..x.xxxx..xx.xx.x",
+///             "xxxxx.x.........x.x..x.xxxxxxxxxxx..xx.xxxx.x..xxx..x",
+///             "...x..xxx..x...x.x.xx..xx.xx.xx...xx.xxx.x.x..x.x...xx...xx"
+///         ],
+///         "mu": [
+///             "..x.xxxx.xxxxxx....x...xx.xx.xxxxx...xx.x.xx.xxx...xxx...x.xx",
+///             "xx.xxx..x.xxx.x.xx.xxxxx.xx.x.xxxx..."
+///         ],
+///         "chi_start": [1, 2, 3, 4, 5],
+///         "psi_start": [6, 7, 8, 9, 10],
+///         "mu_start": [11, 12]
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ZRE3ANTMAXHB9A4LPT/PX3"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Lorenz
+///
+/// {
+///     "input": "ZRE3ANTMAXHB9A4LPT/PX3",
+///     "params": {
+///         "model": "sz42b",
+///         "mode": "receive",
+///         "input_format": "ita2",
+///         "output_format": "plaintext",
+///         "chi": [
+///             ".x.xxxx...xxx.xx...xxxx.x..x.x.......x.x.",
+///             ".x...x...x.....xx...x...xxx....",
+///             "x......x.x..x.....x.....x...x",
+///             "..x.x..x..x..x....xxx.xxxx",
+///             "...xxx....x.x..x.xxxxxx"
+///         ],
+///         "psi": [
+///             ".x..x.xx..xxxx...xxx.....x......xxx..xx.x.x",
+///             "x.x.x..x..x.x..xx......xxxx.....x...x.x.xx.x.xx",
+///             "xxxx..x....xx....x.xxxx..xx..xxxxx..x.xxxx..xx.xx.x",
+///             "xxxxx.x.........x.x..x.xxxxxxxxxxx..xx.xxxx.x..xxx..x",
+///             "...x..xxx..x...x.x.xx..xx.xx.xx...xx.xxx.x.x..x.x...xx...xx"
+///         ],
+///         "mu": [
+///             "..x.xxxx.xxxxxx....x...xx.xx.xxxxx...xx.x.xx.xxx...xxx...x.xx",
+///             "xx.xxx..x.xxx.x.xx.xxxxx.xx.x.xxxx..."
+///         ],
+///         "chi_start": [1, 2, 3, 4, 5],
+///         "psi_start": [6, 7, 8, 9, 10],
+///         "mu_start": [11, 12]
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ATTACK AT 0600 HOURS"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Lorenz
+///
+/// {
+///     "input": "Attack at 0600 hours",
+///     "params": {
+///         "model": "sz40",
+///         "mode": "send",
+///         "input_format": "plaintext",
+///         "output_format": "ita2",
+///         "chi": [
+///             ".x.xxxx...xxx.xx...xxxx.x..x.x.......x.x.",
+///             ".x...x...x.....xx...x...xxx....",
+///             "x......x.x..x.....x.....x...x",
+///             "..x.x..x..x..x....xxx.xxxx",
+///             "...xxx....x.x..x.xxxxxx"
+///         ],
+///         "psi": [
+///             ".x..x.xx..xxxx...xxx.....x......xxx..xx.x.x",
+///             "x.x.x..x..x.x..xx......xxxx.....x...x.x.xx.x.xx",
+///             "xxxx..x....xx....x.xxxx..xx..xxxxx..x.xxxx..xx.xx.x",
+///             "xxxxx.x.........x.x..x.xxxxxxxxxxx..xx.xxxx.x..xxx..x",
+///             "...x..xxx..x...x.x.xx..xx.xx.xx...xx.xxx.x.x..x.x...xx...xx"
+///         ],
+///         "mu": [
+///             "..x.xxxx.xxxxxx....x...xx.xx.xxxxx...xx.x.xx.xxx...xxx...x.xx",
+///             "xx.xxx..x.xxx.x.xx.xxxxx.xx.x.xxxx.."
+///         ],
+///         "chi_start": [1, 2, 3, 4, 5],
+///         "psi_start": [6, 7, 8, 9, 10],
+///         "mu_start": [11, 12]
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Mu 2 wheel must have 37 cams."
+/// }
+/// ```
+pub struct Lorenz;
+
+const NAME: &str = "Lorenz";
+const DESCRIPTION_EN: &str = "The Lorenz SZ40/42 is a teleprinter cipher attachment used by the German Army High Command. Every 5-bit ITA2 code is added to the sum of a character from the five chi wheels and a character from the five psi wheels, the chi wheels move after every character and the psi wheels move together when the two mu (motor) wheels let them, on the SZ42 the motor is also controlled by the limitation. The same operation both encrypts and decrypts, the mode only tells the SZ42b limitation which of the texts is the plaintext. The plaintext format takes the text in letters, figures and spaces and inserts the shifts, the ITA2 format writes the codes in the Bletchley Park notation: letters for the letter codes, / for null, 9 for space, 3 for line feed, 4 for carriage return, 5 or + for figures shift and 8 or - for letters shift.";
+const DESCRIPTION_RU: &str = "Lorenz SZ40/42 — приставка для шифрования телетайпных сообщений, которую использовало верховное командование вермахта. Каждый 5-битный код ITA2 складывается с суммой символа пяти колёс chi и символа пяти колёс psi, колёса chi поворачиваются после каждого символа, а колёса psi поворачиваются вместе, когда это позволяют два колеса mu (моторных), в SZ42 мотором управляет также ограничение. Одна и та же операция и шифрует, и расшифровывает, режим лишь сообщает ограничению SZ42b, какой из текстов открытый. Формат plaintext принимает текст из букв, цифр и пробелов и вставляет переключения регистров, формат ITA2 записывает коды в нотации Блетчли-парка: буквы для кодов букв, / для пустого символа, 9 для пробела, 3 для перевода строки, 4 для возврата каретки, 5 или + для переключения на цифры и 8 или - для переключения на буквы.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Lorenz_cipher");
+
+create_info_struct!(
+    LorenzInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,12 +1,15 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
 use crate::{
-    libs::libakrypt::{
-        akrypt::Akrypt,
-        structs::{AkryptFunction, InputFormat, Mode, OutputFormat},
+    create_info_struct,
+    libs::block_cipher::{
+        BlockCipher, BlockCipherFunction, InputFormat, Mode, OutputFormat, Padding,
     },
-    run_op, Operation,
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
 };
-use anyhow::Result;
-use serde::Deserialize;
 
 run_op!(run_magmaencrypt, MagmaEncrypt);
 
@@ -15,29 +18,143 @@ impl Operation<'_, DeserializeMeDaddy> for MagmaEncrypt {
         let request = self.validate(request)?;
         let DeserializeMeDaddy {
             key,
+            key_format,
             iv,
+            iv_format,
             mode,
+            padding,
             input_format,
             output_format,
         } = request;
 
-        let akrypt = Akrypt::new(AkryptFunction::Magma)
+        let cipher = BlockCipher::new(BlockCipherFunction::Magma)
             .set_input(input, input_format)?
-            .set_iv(iv.as_bytes())?
-            .set_key(key.as_bytes())?
-            .set_mode(mode);
+            .set_iv(&convert_to_byte_array(&iv, &iv_format)?)?
+            .set_key(&convert_to_byte_array(&key, &key_format)?)?
+            .set_mode(mode)
+            .set_padding(padding);
 
-        akrypt.encrypt(output_format)
+        cipher.encrypt(output_format)
     }
 }
 
 #[derive(Deserialize)]
 struct DeserializeMeDaddy {
     key: String,
+    key_format: SupportedFormats,
     iv: String,
+    iv_format: SupportedFormats,
     mode: Mode,
+    padding: Padding,
     input_format: InputFormat,
     output_format: OutputFormat,
 }
 
+/// Magma is a symmetric block cipher. It has a block size of 64 bits and key length of 256 bits. It is defined in the National Standard of the Russian Federation GOST R 34.12-2015 and also in RFC 8891.
+/// <br><br/>
+/// For more information go [here](https://en.wikipedia.org/wiki/GOST_(block_cipher)).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/MagmaEncrypt with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "iv": string,
+///         "iv_format": SupportedFormats,
+///         "mode": Mode,
+///         "padding": Padding,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Mode is enum of "CBC", "CTR", "OFB", "CFB", "CFB8", "ECB", "XTS"
+///     - Padding is enum of "pkcs7", "iso7816", "x923", "iso10126" (alias "random"), "zero", "none" (used only in "CBC" and "ECB" modes)
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw"
+///     - key must be 32 bytes long
+///     - iv must be 8 bytes long, it is ignored in "ECB" mode
+///     - "XTS" mode is not supported for 64-bit block ciphers
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/MagmaEncrypt
+///
+/// {
+///     "input": "fedcba9876543210",
+///     "params": {
+///         "key": "ffeeddccbbaa99887766554433221100f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+///         "key_format": "hex",
+///         "iv": "0000000000000000",
+///         "iv_format": "hex",
+///         "mode": "ECB",
+///         "padding": "none",
+///         "input_format": "hex",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "4ee901e5c2d8ca3d"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/MagmaEncrypt
+///
+/// {
+///     "input": "fedcba9876543210",
+///     "params": {
+///         "key": "0011",
+///         "key_format": "hex",
+///         "iv": "0000000000000000",
+///         "iv_format": "hex",
+///         "mode": "ECB",
+///         "padding": "none",
+///         "input_format": "hex",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid key length for Magma: expected 32 bytes, got 2 bytes."
+/// }
+/// ```
 pub struct MagmaEncrypt;
+
+const NAME: &str = "MagmaEncrypt";
+const DESCRIPTION_EN: &str = "Magma is a symmetric block cipher. It has a block size of 64 bits and key length of 256 bits. It is defined in the National Standard of the Russian Federation GOST R 34.12-2015 and also in RFC 8891.";
+const DESCRIPTION_RU: &str = "Магма — симметричный блочный шифр. Он имеет размер блока 64 бита и длину ключа 256 бит. Он определен в Национальном стандарте РФ ГОСТ Р 34.12-2015, а также в RFC 8891.";
+
+const INFO_URL: Option<&str> = Some("https://en.wikipedia.org/wiki/GOST_(block_cipher)");
+
+create_info_struct!(
+    MagmaEncryptInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
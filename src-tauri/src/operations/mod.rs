@@ -5,41 +5,128 @@ pub mod add_mod;
 pub mod adler32_checksum_mod;
 pub mod affine_cipher_decode_mod;
 pub mod affine_cipher_encode_mod;
+pub mod analyse_blocks_mod;
 pub mod analyse_hash_mod;
 pub mod and_mod;
 pub mod argon2_compare_mod;
 pub mod argon2_mod;
+pub mod aria_decrypt_mod;
+pub mod aria_encrypt_mod;
 pub mod atbash_cipher_mod;
+pub mod autokey_cipher_decode_mod;
+pub mod autokey_cipher_encode_mod;
 pub mod bacon_cipher_decode_mod;
 pub mod bacon_cipher_encode_mod;
 pub mod bcrypt_compare_mod;
 pub mod bcrypt_mod;
 pub mod bcrypt_parse_mod;
+pub mod beaufort_cipher_decode_mod;
+pub mod beaufort_cipher_encode_mod;
+pub mod bifid_cipher_decode_mod;
 pub mod bifid_cipher_encode_mod;
 pub mod blake2b_mod;
 pub mod blake2s_mod;
+pub mod blowfish_decrypt_mod;
+pub mod blowfish_encrypt_mod;
+pub mod caesar_cipher_mod;
+pub mod camellia_decrypt_mod;
+pub mod camellia_encrypt_mod;
+pub mod columnar_transposition_decode_mod;
+pub mod columnar_transposition_encode_mod;
+pub mod crack_affine_cipher_mod;
+pub mod crack_caesar_cipher_mod;
+pub mod crack_hill_cipher_mod;
+pub mod crack_substitution_cipher_mod;
+pub mod crack_vigenere_cipher_mod;
+pub mod des_decrypt_mod;
+pub mod des_encrypt_mod;
+pub mod ec_compress_point_mod;
+pub mod ec_decompress_point_mod;
+pub mod ec_generate_key_pair_mod;
+pub mod ecdh_shared_secret_mod;
+pub mod ecdsa_sign_mod;
+pub mod ecdsa_verify_mod;
+pub mod ed25519_generate_key_pair_mod;
+pub mod ed25519_sign_mod;
+pub mod ed25519_verify_mod;
+pub mod enigma_mod;
 pub mod filter_mod;
 pub mod from_base64_mod;
 pub mod from_base_mod;
+pub mod generate_keyword_alphabet_mod;
+pub mod gost_generate_key_pair_mod;
+pub mod gost_sign_mod;
+pub mod gost_verify_mod;
+pub mod gronsfeld_cipher_decode_mod;
+pub mod gronsfeld_cipher_encode_mod;
+pub mod hill_cipher_decode_mod;
+pub mod hill_cipher_encode_mod;
 pub mod hmac_mod;
+pub mod jwt_decode_mod;
+pub mod jwt_sign_mod;
+pub mod jwt_verify_mod;
 pub mod kuznechik_decrypt_mod;
 pub mod kuznechik_encrypt_mod;
+pub mod lorenz_mod;
 pub mod magma_decrypt_mod;
 pub mod magma_encrypt_mod;
 pub mod md2_mod;
 pub mod md4_mod;
 pub mod md5_mod;
+pub mod padding_oracle_attack_mod;
+pub mod parse_asn1_mod;
+pub mod parse_csr_mod;
+pub mod parse_x509_certificate_mod;
+pub mod pgp_decrypt_mod;
+pub mod pgp_encrypt_mod;
+pub mod pgp_generate_key_pair_mod;
+pub mod pgp_import_key_mod;
+pub mod pgp_list_packets_mod;
+pub mod pgp_sign_mod;
+pub mod pgp_verify_mod;
+pub mod playfair_cipher_decode_mod;
+pub mod playfair_cipher_encode_mod;
+pub mod porta_cipher_mod;
+pub mod rail_fence_cipher_decode_mod;
+pub mod rail_fence_cipher_encode_mod;
+pub mod rc2_decrypt_mod;
+pub mod rc2_encrypt_mod;
+pub mod rc4_drop_mod;
+pub mod rc4_mod;
 pub mod reverse_mod;
+pub mod rot13_mod;
+pub mod rot47_mod;
+pub mod rot8000_mod;
+pub mod route_cipher_decode_mod;
+pub mod route_cipher_encode_mod;
+pub mod rsa_convert_key_mod;
 pub mod rsa_decrypt_mod;
 pub mod rsa_encrypt_mod;
+pub mod rsa_generate_key_pair_mod;
+pub mod rsa_sign_mod;
+pub mod rsa_verify_mod;
 pub mod scrypt_mod;
+pub mod scytale_cipher_decode_mod;
+pub mod scytale_cipher_encode_mod;
+pub mod serpent_decrypt_mod;
+pub mod serpent_encrypt_mod;
 pub mod sha1_mod;
 pub mod sha2_mod;
 pub mod sha3_mod;
+pub mod sm4_decrypt_mod;
+pub mod sm4_encrypt_mod;
+pub mod substitute_mod;
 pub mod to_base64_mod;
 pub mod to_base_mod;
+pub mod triple_des_decrypt_mod;
+pub mod triple_des_encrypt_mod;
+pub mod twofish_decrypt_mod;
+pub mod twofish_encrypt_mod;
+pub mod typex_mod;
 pub mod vigenere_cipher_decode_mod;
 pub mod vigenere_cipher_encode_mod;
+pub mod x25519_generate_key_pair_mod;
+pub mod x25519_shared_secret_mod;
 
 pub use a1z26_cipher_decode_mod::*;
 pub use a1z26_cipher_encode_mod::*;
@@ -48,41 +135,128 @@ pub use add_mod::*;
 pub use adler32_checksum_mod::*;
 pub use affine_cipher_decode_mod::*;
 pub use affine_cipher_encode_mod::*;
+pub use analyse_blocks_mod::*;
 pub use analyse_hash_mod::*;
 pub use and_mod::*;
 pub use argon2_compare_mod::*;
 pub use argon2_mod::*;
+pub use aria_decrypt_mod::*;
+pub use aria_encrypt_mod::*;
 pub use atbash_cipher_mod::*;
+pub use autokey_cipher_decode_mod::*;
+pub use autokey_cipher_encode_mod::*;
 pub use bacon_cipher_decode_mod::*;
 pub use bacon_cipher_encode_mod::*;
 pub use bcrypt_compare_mod::*;
 pub use bcrypt_mod::*;
 pub use bcrypt_parse_mod::*;
+pub use beaufort_cipher_decode_mod::*;
+pub use beaufort_cipher_encode_mod::*;
+pub use bifid_cipher_decode_mod::*;
 pub use bifid_cipher_encode_mod::*;
 pub use blake2b_mod::*;
 pub use blake2s_mod::*;
+pub use blowfish_decrypt_mod::*;
+pub use blowfish_encrypt_mod::*;
+pub use caesar_cipher_mod::*;
+pub use camellia_decrypt_mod::*;
+pub use camellia_encrypt_mod::*;
+pub use columnar_transposition_decode_mod::*;
+pub use columnar_transposition_encode_mod::*;
+pub use crack_affine_cipher_mod::*;
+pub use crack_caesar_cipher_mod::*;
+pub use crack_hill_cipher_mod::*;
+pub use crack_substitution_cipher_mod::*;
+pub use crack_vigenere_cipher_mod::*;
+pub use des_decrypt_mod::*;
+pub use des_encrypt_mod::*;
+pub use ec_compress_point_mod::*;
+pub use ec_decompress_point_mod::*;
+pub use ec_generate_key_pair_mod::*;
+pub use ecdh_shared_secret_mod::*;
+pub use ecdsa_sign_mod::*;
+pub use ecdsa_verify_mod::*;
+pub use ed25519_generate_key_pair_mod::*;
+pub use ed25519_sign_mod::*;
+pub use ed25519_verify_mod::*;
+pub use enigma_mod::*;
 pub use filter_mod::*;
 pub use from_base64_mod::*;
 pub use from_base_mod::*;
+pub use generate_keyword_alphabet_mod::*;
+pub use gost_generate_key_pair_mod::*;
+pub use gost_sign_mod::*;
+pub use gost_verify_mod::*;
+pub use gronsfeld_cipher_decode_mod::*;
+pub use gronsfeld_cipher_encode_mod::*;
+pub use hill_cipher_decode_mod::*;
+pub use hill_cipher_encode_mod::*;
 pub use hmac_mod::*;
+pub use jwt_decode_mod::*;
+pub use jwt_sign_mod::*;
+pub use jwt_verify_mod::*;
 pub use kuznechik_decrypt_mod::*;
 pub use kuznechik_encrypt_mod::*;
+pub use lorenz_mod::*;
 pub use magma_decrypt_mod::*;
 pub use magma_encrypt_mod::*;
 pub use md2_mod::*;
 pub use md4_mod::*;
 pub use md5_mod::*;
+pub use padding_oracle_attack_mod::*;
+pub use parse_asn1_mod::*;
+pub use parse_csr_mod::*;
+pub use parse_x509_certificate_mod::*;
+pub use pgp_decrypt_mod::*;
+pub use pgp_encrypt_mod::*;
+pub use pgp_generate_key_pair_mod::*;
+pub use pgp_import_key_mod::*;
+pub use pgp_list_packets_mod::*;
+pub use pgp_sign_mod::*;
+pub use pgp_verify_mod::*;
+pub use playfair_cipher_decode_mod::*;
+pub use playfair_cipher_encode_mod::*;
+pub use porta_cipher_mod::*;
+pub use rail_fence_cipher_decode_mod::*;
+pub use rail_fence_cipher_encode_mod::*;
+pub use rc2_decrypt_mod::*;
+pub use rc2_encrypt_mod::*;
+pub use rc4_drop_mod::*;
+pub use rc4_mod::*;
 pub use reverse_mod::*;
+pub use rot13_mod::*;
+pub use rot47_mod::*;
+pub use rot8000_mod::*;
+pub use route_cipher_decode_mod::*;
+pub use route_cipher_encode_mod::*;
+pub use rsa_convert_key_mod::*;
 pub use rsa_decrypt_mod::*;
 pub use rsa_encrypt_mod::*;
+pub use rsa_generate_key_pair_mod::*;
+pub use rsa_sign_mod::*;
+pub use rsa_verify_mod::*;
 pub use scrypt_mod::*;
+pub use scytale_cipher_decode_mod::*;
+pub use scytale_cipher_encode_mod::*;
+pub use serpent_decrypt_mod::*;
+pub use serpent_encrypt_mod::*;
 pub use sha1_mod::*;
 pub use sha2_mod::*;
 pub use sha3_mod::*;
+pub use sm4_decrypt_mod::*;
+pub use sm4_encrypt_mod::*;
+pub use substitute_mod::*;
 pub use to_base64_mod::*;
 pub use to_base_mod::*;
+pub use triple_des_decrypt_mod::*;
+pub use triple_des_encrypt_mod::*;
+pub use twofish_decrypt_mod::*;
+pub use twofish_encrypt_mod::*;
+pub use typex_mod::*;
 pub use vigenere_cipher_decode_mod::*;
 pub use vigenere_cipher_encode_mod::*;
+pub use x25519_generate_key_pair_mod::*;
+pub use x25519_shared_secret_mod::*;
 
 pub use a1z26_cipher_decode_mod::Delimiters;
 pub use bcrypt_parse_mod::HashParts as BcryptParseHashParts;
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::block_cipher::{
+        decode_input, encode_output, modes, padding::unpad, padding_oracle::padding_oracle_attack,
+        BlockCipherFunction, InputFormat, Mode, OutputFormat, Padding,
+    },
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_paddingoracleattack, PaddingOracleAttack);
+
+impl Operation<'_, DeserializeMeDaddy> for PaddingOracleAttack {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            algorithm,
+            key,
+            key_format,
+            iv,
+            iv_format,
+            input_format,
+            output_format,
+        } = request;
+
+        let ciphertext = decode_input(input, input_format)?;
+        let iv = convert_to_byte_array(&iv, &iv_format)?;
+        let cipher = algorithm.new_cipher(&convert_to_byte_array(&key, &key_format)?)?;
+        let block_size = cipher.block_size();
+
+        // The key never leaves the oracle, the attack only learns whether the padding was valid.
+        let oracle = |iv: &[u8], block: &[u8]| {
+            modes::decrypt(cipher.as_ref(), Mode::CBC, Padding::PKCS7, iv, block).is_ok()
+        };
+
+        let plaintext = padding_oracle_attack(block_size, &iv, &ciphertext, oracle)?;
+        let plaintext = unpad(&plaintext, block_size, Padding::PKCS7)?;
+
+        Ok(encode_output(&plaintext, output_format))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    algorithm: BlockCipherFunction,
+    key: String,
+    key_format: SupportedFormats,
+    iv: String,
+    iv_format: SupportedFormats,
+    input_format: InputFormat,
+    output_format: OutputFormat,
+}
+
+/// Demonstrates the padding oracle attack on CBC mode with PKCS#7 padding. The key is only used to build a local oracle that reports whether a forged ciphertext decrypts to valid padding, and the plaintext is recovered byte by byte using nothing but those answers.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Padding_oracle_attack).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PaddingOracleAttack with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "algorithm": BlockCipherFunction,
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "iv": string,
+///         "iv_format": SupportedFormats,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - BlockCipherFunction is enum of "DES", "TripleDES", "Blowfish", "RC2", "SM4", "Camellia", "ARIA", "Twofish", "Serpent", "Magma", "Kuznechik"
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw"
+///     - input is a ciphertext produced in "CBC" mode with "pkcs7" padding
+///     - iv must be as long as the cipher block
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PaddingOracleAttack
+///
+/// {
+///     "input": "e2d37e1d63c0af217d81b093b5a731da",
+///     "params": {
+///         "algorithm": "DES",
+///         "key": "0123456789abcdef",
+///         "key_format": "hex",
+///         "iv": "1122334455667788",
+///         "iv_format": "hex",
+///         "input_format": "hex",
+///         "output_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hello world"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PaddingOracleAttack
+///
+/// {
+///     "input": "e2d37e1d63c0af21",
+///     "params": {
+///         "algorithm": "DES",
+///         "key": "0123456789abcdef",
+///         "key_format": "hex",
+///         "iv": "11223344",
+///         "iv_format": "hex",
+///         "input_format": "hex",
+///         "output_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "IV must be 8 bytes long, got 4 bytes."
+/// }
+/// ```
+pub struct PaddingOracleAttack;
+
+const NAME: &str = "PaddingOracleAttack";
+const DESCRIPTION_EN: &str = "Demonstrates the padding oracle attack on CBC mode with PKCS#7 padding. The key is only used to build a local oracle that reports whether a forged ciphertext decrypts to valid padding, and the plaintext is recovered byte by byte using nothing but those answers.";
+const DESCRIPTION_RU: &str = "Демонстрирует атаку оракула дополнения на режим CBC с дополнением PKCS#7. Ключ используется только для построения локального оракула, который сообщает, корректно ли дополнение у подделанного шифртекста, а открытый текст восстанавливается побайтово исключительно по его ответам.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Padding_oracle_attack");
+
+create_info_struct!(
+    PaddingOracleAttackInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    create_info_struct,
+    libs::asn1::{dump, read_asn1},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_parseasn1, ParseASN1);
+
+impl Operation<'_, ()> for ParseASN1 {
+    fn do_black_magic(&self, input: &str, _request: &str) -> Result<String> {
+        dump(&read_asn1(input)?)
+    }
+}
+
+/// Decodes arbitrary DER or BER data into an indented tree, one element per line, in the layout of `openssl asn1parse`. Each line shows offset, depth, header length, content length, primitive or constructed form, tag with its class and the decoded value of primitives. Object identifiers are shown by name, octet and bit strings that hold a nested structure are expanded.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/X.690).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ParseASN1 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+/// }
+/// ```
+/// #### where
+///     - input is DER or BER data written as hex or base64, or wrapped in PEM with any label
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ParseASN1
+///
+/// {
+///     "input": "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=\n-----END PUBLIC KEY-----",
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "    0:d=0  hl=2 l=  42 cons: SEQUENCE\n    2:d=1  hl=2 l=   5 cons:  SEQUENCE\n    4:d=2  hl=2 l=   3 prim:   OBJECT            :ed25519\n    9:d=1  hl=2 l=  33 prim:  BIT STRING        [HEX DUMP]:19BF44096984CDFE8541BAC167DC3B96C85086AA30B6B6CB0C5C38AD703166E1"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ParseASN1
+///
+/// {
+///     "input": "3080020105a080040361626300000000",
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "    0:d=0  hl=2 l=inf  cons: SEQUENCE\n    2:d=1  hl=2 l=   1 prim:  INTEGER           :05\n    5:d=1  hl=2 l=inf  cons:  cont [ 0 ]\n    7:d=2  hl=2 l=   3 prim:   OCTET STRING      :abc\n   12:d=2  hl=2 l=   0 prim:   EOC\n   14:d=1  hl=2 l=   0 prim:  EOC"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ParseASN1
+///
+/// {
+///     "input": "30050201",
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Length of the element at offset 0 exceeds the available data."
+/// }
+/// ```
+pub struct ParseASN1;
+
+const NAME: &str = "ParseASN1";
+const DESCRIPTION_EN: &str = "Decodes arbitrary DER or BER data into an indented tree, one element per line, in the layout of `openssl asn1parse`. Each line shows offset, depth, header length, content length, primitive or constructed form, tag with its class and the decoded value of primitives. Object identifiers are shown by name, octet and bit strings that hold a nested structure are expanded.";
+const DESCRIPTION_RU: &str = "Разбирает произвольные данные DER или BER в дерево с отступами, по одному элементу в строке, в формате `openssl asn1parse`. Каждая строка содержит смещение, глубину, длину заголовка, длину содержимого, примитивную или составную форму, тег с его классом и декодированное значение примитивов. Идентификаторы объектов показываются по имени, октетные и битовые строки, содержащие вложенную структуру, раскрываются.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/X.690");
+
+create_info_struct!(
+    ParseASN1Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
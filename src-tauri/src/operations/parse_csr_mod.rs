@@ -0,0 +1,124 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::x509::{parse_csr, render, X509OutputFormat},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_parsecsr, ParseCSR);
+
+impl Operation<'_, DeserializeMeDaddy> for ParseCSR {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { output_format } = request;
+
+        render(&parse_csr(input)?, output_format)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    output_format: X509OutputFormat,
+}
+
+/// Parses a PKCS#10 certificate signing request and shows subject, public key, signature algorithm and requested extensions. Accepts PEM or DER encoded requests.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Certificate_signing_request).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ParseCSR with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "output_format": X509OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - input is PEM certificate request, or DER certificate request written as hex or base64
+///     - X509OutputFormat is enum of "text", "json"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ParseCSR
+///
+/// {
+///     "input": "MIIBDTCBtQIBADAUMRIwEAYDVQQDDAlsZWFmLnRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAATt73pVCIHsmlo8u54I8Sx4oVaSIgP3MEtA2omjraGYtXbsF1paIwzF26gYUUp2tB/pMkBG+C0sIOtHuMkNwq9OoD8wPQYJKoZIhvcNAQkOMTAwLjAhBgNVHREEGjAYgglsZWFmLnRlc3SBC2FAbGVhZi50ZXN0MAkGA1UdEwQCMAAwCgYIKoZIzj0EAwIDRwAwRAIgb1aY1QOu/vyvUiNrz/cyBgxxazPmlqUy2J/okCSJDVMCIFrpU11v+NQlPYzowg09gf0hjx3RnKWb2QUZwoYsjcjx",
+///     "params": {
+///         "output_format": "text"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Version: 1\nSubject: CN=leaf.test\nSignature algorithm: ecdsa-with-SHA256\nPublic key:\n    Algorithm: id-ecPublicKey\n    Curve: prime256v1\n    Size: 256\nExtensions:\n    Subject alternative names:\n        DNS:leaf.test\n        email:a@leaf.test\n    Basic constraints:\n        Certificate authority: false"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ParseCSR
+///
+/// {
+///     "input": "MIIBDTCBtQIBADAUMRIwEAYDVQQDDAlsZWFmLnRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAATt73pVCIHsmlo8u54I8Sx4oVaSIgP3MEtA2omjraGYtXbsF1paIwzF26gYUUp2tB/pMkBG+C0sIOtHuMkNwq9OoD8wPQYJKoZIhvcNAQkOMTAwLjAhBgNVHREEGjAYgglsZWFmLnRlc3SBC2FAbGVhZi50ZXN0MAkGA1UdEwQCMAAwCgYIKoZIzj0EAwIDRwAwRAIgb1aY1QOu/vyvUiNrz/cyBgxxazPmlqUy2J/okCSJDVMCIFrpU11v+NQlPYzowg09gf0hjx3RnKWb2QUZwoYsjcjx",
+///     "params": {
+///         "output_format": "json"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "{\n  \"version\": 1,\n  \"subject\": \"CN=leaf.test\",\n  \"signature_algorithm\": \"ecdsa-with-SHA256\",\n  \"public_key\": {\n    \"algorithm\": \"id-ecPublicKey\",\n    \"curve\": \"prime256v1\",\n    \"size\": 256\n  },\n  \"extensions\": {\n    \"subject_alternative_names\": [\n      \"DNS:leaf.test\",\n      \"email:a@leaf.test\"\n    ],\n    \"basic_constraints\": {\n      \"certificate_authority\": false\n    }\n  }\n}"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ParseCSR
+///
+/// {
+///     "input": "MIIBuzCCAW2gAwIBAgIUVgppgJ3VmOBxlKZCavhIDmT4qkEwBQYDK2VwMCgxFDASBgNVBAMMC2V4YW1wbGUuY29tMRAwDgYDVQQKDAdFeGFtcGxlMB4XDTI2MTAxOTA2MTcwMVoXDTM2MTAxNjA2MTcwMVowKDEUMBIGA1UEAwwLZXhhbXBsZS5jb20xEDAOBgNVBAoMB0V4YW1wbGUwKjAFBgMrZXADIQBhkH83f0Atol8R+f96s/SvQRwTj7gJbdPZ7J3Zx1tN3KOBqDCBpTAdBgNVHQ4EFgQUjHyMKnSR5rY0buumC+rSG32dP/cwHwYDVR0jBBgwFoAUjHyMKnSR5rY0buumC+rSG32dP/cwDwYDVR0TAQH/BAUwAwEB/zAtBgNVHREEJjAkggtleGFtcGxlLmNvbYIPd3d3LmV4YW1wbGUuY29thwR/AAABMA4GA1UdDwEB/wQEAwIHgDATBgNVHSUEDDAKBggrBgEFBQcDATAFBgMrZXADQQAVpNhYOVO3/st9OPhrun+zsIwF6jE67kB3wFsEcIMbMmOT6qJRHCRT0luuySZWlBadSfKFPhdbOiGp5fnBSysC",
+///     "params": {
+///         "output_format": "text"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid PKCS#10 certificate request."
+/// }
+/// ```
+pub struct ParseCSR;
+
+const NAME: &str = "ParseCSR";
+const DESCRIPTION_EN: &str = "Parses a PKCS#10 certificate signing request and shows subject, public key, signature algorithm and requested extensions. Accepts PEM or DER encoded requests.";
+const DESCRIPTION_RU: &str = "Разбирает запрос на подпись сертификата PKCS#10 и показывает субъекта, открытый ключ, алгоритм подписи и запрошенные расширения. Принимает запросы в формате PEM или DER.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Certificate_signing_request");
+
+create_info_struct!(
+    ParseCSRInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,129 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::x509::{parse_certificates, render, X509OutputFormat},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_parsex509certificate, ParseX509Certificate);
+
+impl Operation<'_, DeserializeMeDaddy> for ParseX509Certificate {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { output_format } = request;
+
+        let certificates = parse_certificates(input)?;
+
+        match certificates.as_slice() {
+            [certificate] => render(certificate, output_format),
+            _ => render(&certificates, output_format),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    output_format: X509OutputFormat,
+}
+
+/// Parses X.509 certificates and shows subject, issuer, serial number, validity, public key, extensions and fingerprints. Accepts PEM or DER encoded certificates and certificate chains.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/X.509).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ParseX509Certificate with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "output_format": X509OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - input is one or more PEM certificates, or DER certificates written as hex or base64, a chain is returned as a list
+///     - X509OutputFormat is enum of "text", "json"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ParseX509Certificate
+///
+/// {
+///     "input": "MIIBuzCCAW2gAwIBAgIUVgppgJ3VmOBxlKZCavhIDmT4qkEwBQYDK2VwMCgxFDASBgNVBAMMC2V4YW1wbGUuY29tMRAwDgYDVQQKDAdFeGFtcGxlMB4XDTI2MTAxOTA2MTcwMVoXDTM2MTAxNjA2MTcwMVowKDEUMBIGA1UEAwwLZXhhbXBsZS5jb20xEDAOBgNVBAoMB0V4YW1wbGUwKjAFBgMrZXADIQBhkH83f0Atol8R+f96s/SvQRwTj7gJbdPZ7J3Zx1tN3KOBqDCBpTAdBgNVHQ4EFgQUjHyMKnSR5rY0buumC+rSG32dP/cwHwYDVR0jBBgwFoAUjHyMKnSR5rY0buumC+rSG32dP/cwDwYDVR0TAQH/BAUwAwEB/zAtBgNVHREEJjAkggtleGFtcGxlLmNvbYIPd3d3LmV4YW1wbGUuY29thwR/AAABMA4GA1UdDwEB/wQEAwIHgDATBgNVHSUEDDAKBggrBgEFBQcDATAFBgMrZXADQQAVpNhYOVO3/st9OPhrun+zsIwF6jE67kB3wFsEcIMbMmOT6qJRHCRT0luuySZWlBadSfKFPhdbOiGp5fnBSysC",
+///     "params": {
+///         "output_format": "text"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Version: 3\nSerial number: 56:0a:69:80:9d:d5:98:e0:71:94:a6:42:6a:f8:48:0e:64:f8:aa:41\nSignature algorithm: ed25519\nIssuer: CN=example.com, O=Example\nSubject: CN=example.com, O=Example\nValidity:\n    Not before: Oct 19 06:17:01 2026 +00:00\n    Not after: Oct 16 06:17:01 2036 +00:00\nPublic key:\n    Algorithm: ed25519\n    Size: 256\nExtensions:\n    Subject alternative names:\n        DNS:example.com\n        DNS:www.example.com\n        IP Address:127.0.0.1\n    Key usage:\n        Digital Signature\n    Extended key usage:\n        TLS Web Server Authentication\n    Basic constraints:\n        Certificate authority: true\n    Subject key identifier: 8c:7c:8c:2a:74:91:e6:b6:34:6e:eb:a6:0b:ea:d2:1b:7d:9d:3f:f7\n    Authority key identifier: 8c:7c:8c:2a:74:91:e6:b6:34:6e:eb:a6:0b:ea:d2:1b:7d:9d:3f:f7\nFingerprints:\n    SHA-1: 54:75:c1:af:6b:ad:37:12:7c:28:a1:cd:a5:5b:44:21:4e:56:9c:d5\n    SHA-256: eb:70:09:27:17:71:bb:a1:c4:50:2a:8b:15:c9:e7:17:fc:71:21:b3:e0:ad:e9:8d:3c:e7:fc:c4:e5:c7:82:f4"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ParseX509Certificate
+///
+/// {
+///     "input": "MIIBuzCCAW2gAwIBAgIUVgppgJ3VmOBxlKZCavhIDmT4qkEwBQYDK2VwMCgxFDASBgNVBAMMC2V4YW1wbGUuY29tMRAwDgYDVQQKDAdFeGFtcGxlMB4XDTI2MTAxOTA2MTcwMVoXDTM2MTAxNjA2MTcwMVowKDEUMBIGA1UEAwwLZXhhbXBsZS5jb20xEDAOBgNVBAoMB0V4YW1wbGUwKjAFBgMrZXADIQBhkH83f0Atol8R+f96s/SvQRwTj7gJbdPZ7J3Zx1tN3KOBqDCBpTAdBgNVHQ4EFgQUjHyMKnSR5rY0buumC+rSG32dP/cwHwYDVR0jBBgwFoAUjHyMKnSR5rY0buumC+rSG32dP/cwDwYDVR0TAQH/BAUwAwEB/zAtBgNVHREEJjAkggtleGFtcGxlLmNvbYIPd3d3LmV4YW1wbGUuY29thwR/AAABMA4GA1UdDwEB/wQEAwIHgDATBgNVHSUEDDAKBggrBgEFBQcDATAFBgMrZXADQQAVpNhYOVO3/st9OPhrun+zsIwF6jE67kB3wFsEcIMbMmOT6qJRHCRT0luuySZWlBadSfKFPhdbOiGp5fnBSysC",
+///     "params": {
+///         "output_format": "json"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "{\n  \"version\": 3,\n  \"serial_number\": \"56:0a:69:80:9d:d5:98:e0:71:94:a6:42:6a:f8:48:0e:64:f8:aa:41\",\n  \"signature_algorithm\": \"ed25519\",\n  \"issuer\": \"CN=example.com, O=Example\",\n  \"subject\": \"CN=example.com, O=Example\",\n  \"validity\": {\n    \"not_before\": \"Oct 19 06:17:01 2026 +00:00\",\n    \"not_after\": \"Oct 16 06:17:01 2036 +00:00\"\n  },\n  \"public_key\": {\n    \"algorithm\": \"ed25519\",\n    \"size\": 256\n  },\n  \"extensions\": {\n    \"subject_alternative_names\": [\n      \"DNS:example.com\",\n      \"DNS:www.example.com\",\n      \"IP Address:127.0.0.1\"\n    ],\n    \"key_usage\": [\n      \"Digital Signature\"\n    ],\n    \"extended_key_usage\": [\n      \"TLS Web Server Authentication\"\n    ],\n    \"basic_constraints\": {\n      \"certificate_authority\": true\n    },\n    \"subject_key_identifier\": \"8c:7c:8c:2a:74:91:e6:b6:34:6e:eb:a6:0b:ea:d2:1b:7d:9d:3f:f7\",\n    \"authority_key_identifier\": \"8c:7c:8c:2a:74:91:e6:b6:34:6e:eb:a6:0b:ea:d2:1b:7d:9d:3f:f7\"\n  },\n  \"fingerprints\": {\n    \"SHA-1\": \"54:75:c1:af:6b:ad:37:12:7c:28:a1:cd:a5:5b:44:21:4e:56:9c:d5\",\n    \"SHA-256\": \"eb:70:09:27:17:71:bb:a1:c4:50:2a:8b:15:c9:e7:17:fc:71:21:b3:e0:ad:e9:8d:3c:e7:fc:c4:e5:c7:82:f4\"\n  }\n}"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ParseX509Certificate
+///
+/// {
+///     "input": "3082010a",
+///     "params": {
+///         "output_format": "text"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid X.509 certificate."
+/// }
+/// ```
+pub struct ParseX509Certificate;
+
+const NAME: &str = "ParseX509Certificate";
+const DESCRIPTION_EN: &str = "Parses X.509 certificates and shows subject, issuer, serial number, validity, public key, extensions and fingerprints. Accepts PEM or DER encoded certificates and certificate chains.";
+const DESCRIPTION_RU: &str = "Разбирает сертификаты X.509 и показывает субъекта, издателя, серийный номер, срок действия, открытый ключ, расширения и отпечатки. Принимает сертификаты и цепочки сертификатов в формате PEM или DER.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/X.509");
+
+create_info_struct!(
+    ParseX509CertificateInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
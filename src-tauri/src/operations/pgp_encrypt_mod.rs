@@ -0,0 +1,139 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::openpgp::{
+        armor::{armor, MESSAGE},
+        key::Cert,
+        message::encrypt,
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_pgpencrypt, PGPEncrypt);
+
+impl Operation<'_, DeserializeMeDaddy> for PGPEncrypt {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            public_key,
+            passphrase,
+        } = request;
+
+        let recipients = match public_key {
+            Some(public_key) => vec![Cert::read(&public_key)?],
+            None => Vec::new(),
+        };
+
+        Ok(armor(
+            MESSAGE,
+            &encrypt(input.as_bytes(), &recipients, passphrase.as_deref())?,
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    public_key: Option<String>,
+    passphrase: Option<String>,
+}
+
+/// Encrypts a message with OpenPGP for the encryption subkey of a public key, with a passphrase, or with both so that either of them decrypts it. The message is encrypted with AES-256 and integrity protected with a modification detection code.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Pretty_Good_Privacy#OpenPGP).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PGPEncrypt with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "public_key": Option<string>,
+///         "passphrase": Option<string>
+///     }
+/// }
+/// ```
+/// #### where
+///     - public_key is armored OpenPGP public key of the recipient
+///     - passphrase is used for symmetric encryption, at least one of public_key and passphrase is required
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PGPEncrypt
+///
+/// {
+///     "input": "Meet me at noon.",
+///     "params": {
+///         "public_key": "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nxjMEatW8rRYJKwYBBAHaRw8BAQdAQAETXn4xVFsVfmZZ2DcP/0zyLlaWL4VoKzut\njNNCxkHNGUFsaWNlIDxhbGljZUBleGFtcGxlLmNvbT7CigQTFggAMgUCatW8rRYh\nBJTqV+i7z9sUJcepCz67BNy/ozFKAhsDBAsJCAcEFQoJCAQWAgEAAh4BAAoJED67\nBNy/ozFKDmIBALv7VB1WbTIO6oyZkKQVzAFEQuDlrSdAikt8+Vn7co6sAQCIyDOi\nv3rUhhPzVSFbO5ZufGhw1KoqZJceLy2Y81/VAM44BGrVvK0SCisGAQQBl1UBBQEB\nB0CcjLn6+Y4586UOdvSeaytc6UvxD/GygwJEtkREdT+3OwMBCAfCeAQYFggAIAUC\natW8rRYhBJTqV+i7z9sUJcepCz67BNy/ozFKAhsMAAoJED67BNy/ozFKRacA/iwC\nO75WwxkuiDH2wjLB3OxcNxPfIH+HXJ9luMiVYdYcAQCQYJeoWDPJvRPb9u3hjxQn\noA0WTVSg/C6kUSkEtv8aCA==\n=SC8s\n-----END PGP PUBLIC KEY BLOCK-----"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "-----BEGIN PGP MESSAGE-----\n\nwV4D092Apu7SmsQSAQdAFTxoHT9O0mg3wjbBLSeVO6gfmfF/On3QO7F3Aumn9kYw\nZafyZDzG4BQrmtQMipUM23XO4XP14d1nIclzc/zgLdntBLkwxcv6/3iKHEN6r7w4\n0kEBq07PNyHfDHYWVTDyg4VWJORhPwTJNizaoKFLoD2d4dvWfG/EE4nFwLTaxlVT\nEqbvlH2uV7XxQ/2QR97VQA+aCQ==\n=cv9n\n-----END PGP MESSAGE-----"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PGPEncrypt
+///
+/// {
+///     "input": "Meet me at noon.",
+///     "params": {
+///         "passphrase": "correct horse battery staple"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "-----BEGIN PGP MESSAGE-----\n\nww0ECQMIORlt+BAAdAP/0kEBoV7wmiI9Qku+C2XBKDM8yjJ6L7pN65ipEMI3oLee\n7a1b8X2PU1j8R/dLerTrsAqyTYY+xhpejTYA24n4bKpTVQ==\n=Sr2D\n-----END PGP MESSAGE-----"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/PGPEncrypt
+///
+/// {
+///     "input": "Meet me at noon.",
+///     "params": {}
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Public key or passphrase is required to encrypt the message."
+/// }
+/// ```
+pub struct PGPEncrypt;
+
+const NAME: &str = "PGPEncrypt";
+const DESCRIPTION_EN: &str = "Encrypts a message with OpenPGP for the encryption subkey of a public key, with a passphrase, or with both so that either of them decrypts it. The message is encrypted with AES-256 and integrity protected with a modification detection code.";
+const DESCRIPTION_RU: &str = "Шифрует сообщение с помощью OpenPGP для подключа шифрования открытого ключа, парольной фразой или обоими способами, чтобы расшифровать его мог любой из них. Сообщение шифруется AES-256 и защищается от изменений кодом обнаружения модификаций.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Pretty_Good_Privacy#OpenPGP");
+
+create_info_struct!(
+    PGPEncryptInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::{
+    create_info_struct,
+    libs::openpgp::{armor::dearmor, dump::dump},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_pgplistpackets, PGPListPackets);
+
+impl Operation<'_, ()> for PGPListPackets {
+    fn do_black_magic(&self, input: &str, _request: &str) -> Result<String> {
+        dump(&dearmor(input)?.1)
+    }
+}
+
+/// Lists the packets of armored OpenPGP data such as a message, a key or a signature, one packet per line followed by its main fields. Key IDs of encrypted session keys, signature issuers and key fingerprints are shown, compressed data is decompressed and listed nested. Encrypted data is not decrypted.
+/// <br><br/>
+/// For more information go [here](https://www.rfc-editor.org/rfc/rfc4880#section-4).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PGPListPackets with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+/// }
+/// ```
+/// #### where
+///     - input is armored OpenPGP data, binary data written as hex or base64 is accepted too
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PGPListPackets
+///
+/// {
+///     "input": "-----BEGIN PGP MESSAGE-----\n\nwV4D092Apu7SmsQSAQdAFTxoHT9O0mg3wjbBLSeVO6gfmfF/On3QO7F3Aumn9kYw\nZafyZDzG4BQrmtQMipUM23XO4XP14d1nIclzc/zgLdntBLkwxcv6/3iKHEN6r7w4\n0kEBq07PNyHfDHYWVTDyg4VWJORhPwTJNizaoKFLoD2d4dvWfG/EE4nFwLTaxlVT\nEqbvlH2uV7XxQ/2QR97VQA+aCQ==\n=cv9n\n-----END PGP MESSAGE-----"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Public-Key Encrypted Session Key Packet (tag 1), 94 bytes\n    Key ID: D3DD80A6EED29AC4\n    Public key algorithm: ECDH\nSymmetrically Encrypted Integrity Protected Data Packet (tag 18), 65 bytes\n    Version: 1"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PGPListPackets
+///
+/// {
+///     "input": "-----BEGIN PGP SIGNATURE-----\n\nwnUEABYIAB0FAmrVvLkWIQSU6lfou8/bFCXHqQs+uwTcv6MxSgAKCRA+uwTcv6Mx\nSh7lAP4pSfkPOzKtblUBxo950YtSRN4NNq/F2lvr3VX9A5dcywD9GdGT/0D47dqv\nw598bheHDS7YcacJb3YWZ7S3UBAaPgw=\n=86a5\n-----END PGP SIGNATURE-----"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Signature Packet (tag 2), 117 bytes\n    Signature type: binary document (0x00)\n    Public key algorithm: EdDSA (22)\n    Hash algorithm: SHA-256 (8)\n    Created: Oct 19 06:46:17 2026 +00:00\n    Issuer: 3EBB04DCBFA3314A"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/PGPListPackets
+///
+/// {
+///     "input": "-----BEGIN PGP SIGNATURE-----\n\nwnUEABYIAB0FAmrVvLkWIQSU6lfou8/bFCXHqQs+uwTcv6MxSgAKCRA+uwTcv6Mx\nSh7lAP4pSfkPOzKtblUBxo950YtSRN4NNq/F2lvr3VX9A5dcywD9GdGT/0D47dqv\nw598bheHDS7YcacJb3YWZ7S3UBAaPgw=\n=AAAA\n-----END PGP SIGNATURE-----"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Armor checksum mismatch."
+/// }
+/// ```
+pub struct PGPListPackets;
+
+const NAME: &str = "PGPListPackets";
+const DESCRIPTION_EN: &str = "Lists the packets of armored OpenPGP data such as a message, a key or a signature, one packet per line followed by its main fields. Key IDs of encrypted session keys, signature issuers and key fingerprints are shown, compressed data is decompressed and listed nested. Encrypted data is not decrypted.";
+const DESCRIPTION_RU: &str = "Перечисляет пакеты данных OpenPGP в ASCII-броне, таких как сообщение, ключ или подпись, по одному пакету в строке с его основными полями. Показываются идентификаторы ключей зашифрованных сеансовых ключей, издатели подписей и отпечатки ключей, сжатые данные распаковываются и выводятся вложенными. Зашифрованные данные не расшифровываются.";
+
+const INFO_URL: Option<&str> = Some("https://www.rfc-editor.org/rfc/rfc4880#section-4");
+
+create_info_struct!(
+    PGPListPacketsInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,134 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::openpgp::{key::Cert, message::verify},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_pgpverify, PGPVerify);
+
+impl Operation<'_, DeserializeMeDaddy> for PGPVerify {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            public_key,
+            signature,
+        } = request;
+
+        match verify(&Cert::read(&public_key)?, input, signature.as_deref())? {
+            true => Ok("Verified OK.".to_string()),
+            false => Err(anyhow!("Verification failure.")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    public_key: String,
+    signature: Option<String>,
+}
+
+/// Verifies an OpenPGP signature with a public key. The input is either a cleartext signed message, a signed message as produced by "gpg --sign", or the signed data itself when a detached signature is given.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Pretty_Good_Privacy#OpenPGP).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PGPVerify with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "public_key": string,
+///         "signature": Option<string>
+///     }
+/// }
+/// ```
+/// #### where
+///     - public_key is armored OpenPGP public key of the signer
+///     - signature is armored detached signature over the input
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PGPVerify
+///
+/// {
+///     "input": "-----BEGIN PGP SIGNED MESSAGE-----\nHash: SHA256\n\nMeet me at noon.\n-----BEGIN PGP SIGNATURE-----\n\nwnUEARYIAB0FAmrVvLkWIQSU6lfou8/bFCXHqQs+uwTcv6MxSgAKCRA+uwTcv6Mx\nSsfJAQCgsrntzEYEeUB+I4hQAK7QwxcR6v41IE64PdH/37SMMAD/W1kUdVE7x4Ys\nmbk75YmRkqK6SrUC9xKIpgSXWgMexA4=\n=72DV\n-----END PGP SIGNATURE-----",
+///     "params": {
+///         "public_key": "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nxjMEatW8rRYJKwYBBAHaRw8BAQdAQAETXn4xVFsVfmZZ2DcP/0zyLlaWL4VoKzut\njNNCxkHNGUFsaWNlIDxhbGljZUBleGFtcGxlLmNvbT7CigQTFggAMgUCatW8rRYh\nBJTqV+i7z9sUJcepCz67BNy/ozFKAhsDBAsJCAcEFQoJCAQWAgEAAh4BAAoJED67\nBNy/ozFKDmIBALv7VB1WbTIO6oyZkKQVzAFEQuDlrSdAikt8+Vn7co6sAQCIyDOi\nv3rUhhPzVSFbO5ZufGhw1KoqZJceLy2Y81/VAM44BGrVvK0SCisGAQQBl1UBBQEB\nB0CcjLn6+Y4586UOdvSeaytc6UvxD/GygwJEtkREdT+3OwMBCAfCeAQYFggAIAUC\natW8rRYhBJTqV+i7z9sUJcepCz67BNy/ozFKAhsMAAoJED67BNy/ozFKRacA/iwC\nO75WwxkuiDH2wjLB3OxcNxPfIH+HXJ9luMiVYdYcAQCQYJeoWDPJvRPb9u3hjxQn\noA0WTVSg/C6kUSkEtv8aCA==\n=SC8s\n-----END PGP PUBLIC KEY BLOCK-----"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Verified OK."
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PGPVerify
+///
+/// {
+///     "input": "Meet me at noon!",
+///     "params": {
+///         "public_key": "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nxjMEatW8rRYJKwYBBAHaRw8BAQdAQAETXn4xVFsVfmZZ2DcP/0zyLlaWL4VoKzut\njNNCxkHNGUFsaWNlIDxhbGljZUBleGFtcGxlLmNvbT7CigQTFggAMgUCatW8rRYh\nBJTqV+i7z9sUJcepCz67BNy/ozFKAhsDBAsJCAcEFQoJCAQWAgEAAh4BAAoJED67\nBNy/ozFKDmIBALv7VB1WbTIO6oyZkKQVzAFEQuDlrSdAikt8+Vn7co6sAQCIyDOi\nv3rUhhPzVSFbO5ZufGhw1KoqZJceLy2Y81/VAM44BGrVvK0SCisGAQQBl1UBBQEB\nB0CcjLn6+Y4586UOdvSeaytc6UvxD/GygwJEtkREdT+3OwMBCAfCeAQYFggAIAUC\natW8rRYhBJTqV+i7z9sUJcepCz67BNy/ozFKAhsMAAoJED67BNy/ozFKRacA/iwC\nO75WwxkuiDH2wjLB3OxcNxPfIH+HXJ9luMiVYdYcAQCQYJeoWDPJvRPb9u3hjxQn\noA0WTVSg/C6kUSkEtv8aCA==\n=SC8s\n-----END PGP PUBLIC KEY BLOCK-----",
+///         "signature": "-----BEGIN PGP SIGNATURE-----\n\nwnUEABYIAB0FAmrVvLkWIQSU6lfou8/bFCXHqQs+uwTcv6MxSgAKCRA+uwTcv6Mx\nSh7lAP4pSfkPOzKtblUBxo950YtSRN4NNq/F2lvr3VX9A5dcywD9GdGT/0D47dqv\nw598bheHDS7YcacJb3YWZ7S3UBAaPgw=\n=86a5\n-----END PGP SIGNATURE-----"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Verification failure."
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/PGPVerify
+///
+/// {
+///     "input": "Meet me at noon.",
+///     "params": {
+///         "public_key": "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\nxjMEatW8sBYJKwYBBAHaRw8BAQdAJ/bIK5xf2cdKo7AbhLJt5IvlIzgPeeguCupk\nAGsKOU/NFUJvYiA8Ym9iQGV4YW1wbGUuY29tPsKKBBMWCAAyBQJq1bywFiEEWN5A\n7qaZiNtWegdILoDa8nVGyJkCGwMECwkIBwQVCgkIBBYCAQACHgEACgkQLoDa8nVG\nyJlswgD/We2fc76Lh3y4c926/y5gr8scXzrQyrDbnIdywvmNijIBAMNxml5zXM7v\n5M7/ygGjSboFavJyc8GgHBMITgDXEMwJzjgEatW8sBIKKwYBBAGXVQEFAQEHQBAm\nGGGhNp476YTDbVVL0xKU1AKlmclwql/F4+etg1V3AwEIB8J4BBgWCAAgBQJq1byw\nFiEEWN5A7qaZiNtWegdILoDa8nVGyJkCGwwACgkQLoDa8nVGyJkx1wD/XB7M1oLZ\nHQ2gCn+J+8T0MwNK/7wL5EFSeeLYjL9+DzEA+wWCkNt+s8OiL0SSSU5c75ewIv4e\n8s4xvTyJOFMom2sC\n=Wc+O\n-----END PGP PUBLIC KEY BLOCK-----",
+///         "signature": "-----BEGIN PGP SIGNATURE-----\n\nwnUEABYIAB0FAmrVvLkWIQSU6lfou8/bFCXHqQs+uwTcv6MxSgAKCRA+uwTcv6Mx\nSh7lAP4pSfkPOzKtblUBxo950YtSRN4NNq/F2lvr3VX9A5dcywD9GdGT/0D47dqv\nw598bheHDS7YcacJb3YWZ7S3UBAaPgw=\n=86a5\n-----END PGP SIGNATURE-----"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Signature was made by key 3EBB04DCBFA3314A, which is not in the given public key."
+/// }
+/// ```
+pub struct PGPVerify;
+
+const NAME: &str = "PGPVerify";
+const DESCRIPTION_EN: &str = "Verifies an OpenPGP signature with a public key. The input is either a cleartext signed message, a signed message as produced by \"gpg --sign\", or the signed data itself when a detached signature is given.";
+const DESCRIPTION_RU: &str = "Проверяет подпись OpenPGP открытым ключом. На вход подаётся сообщение, подписанное открытым текстом, подписанное сообщение, как его создаёт \"gpg --sign\", или сами подписанные данные, если указана отсоединённая подпись.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Pretty_Good_Privacy#OpenPGP");
+
+create_info_struct!(
+    PGPVerifyInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,221 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        ciphers::{playfair_digraph, playfair_square},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_playfaircipherdecode, PlayfairCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for PlayfairCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, keyword } = request;
+
+        let (square, size) = playfair_square(&lang, &keyword)?;
+
+        let letters: Vec<char> = lang
+            .polybius_normalize(input)
+            .chars()
+            .filter(|ch| square.contains(ch))
+            .collect();
+
+        if letters.len() % 2 != 0 {
+            bail!("The ciphertext must consist of an even number of letters.");
+        }
+
+        Ok(letters
+            .chunks(2)
+            .flat_map(|pair| {
+                let (a, b) = playfair_digraph(&square, size, (pair[0], pair[1]), size - 1);
+                [a, b]
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    keyword: String,
+}
+
+/// Decodes text encrypted with the Playfair cipher using the Polybius square built from the keyword, 5x5 for English, 6x6 for the other presets or the square of a custom alphabet with a square number of symbols. Characters outside of the square are ignored, fillers inserted while encoding are kept in the output.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Playfair_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PlayfairCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "keyword": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PlayfairCipherDecode
+///
+/// {
+///     "input": "BMODZBXDNABEKUDMUIXMMOUVIF",
+///     "params": {
+///         "lang": "en",
+///         "keyword": "playfair example"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "HIDETHEGOLDINTHETREXESTUMP"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PlayfairCipherDecode
+///
+/// {
+///     "input": "ИЦУФКЭБКЗХИСЕИУФЕИХШМГНЦ-Ь",
+///     "params": {
+///         "lang": "ru",
+///         "keyword": "шифровка"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ВСТРЕЧАЕМСЯЗАВТРАВПОЛДЕНЬХ"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/PlayfairCipherDecode
+///
+/// {
+///     "input": "BMODZ",
+///     "params": {
+///         "lang": "en",
+///         "keyword": "playfair example"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "The ciphertext must consist of an even number of letters."
+/// }
+/// ```
+pub struct PlayfairCipherDecode;
+
+const NAME: &str = "PlayfairCipherDecode";
+const DESCRIPTION_EN: &str = "Decodes text encrypted with the Playfair cipher using the Polybius square built from the keyword, 5x5 for English, 6x6 for the other presets or the square of a custom alphabet with a square number of symbols. Characters outside of the square are ignored, fillers inserted while encoding are kept in the output.";
+const DESCRIPTION_RU: &str = "Расшифровывает текст, зашифрованный шифром Плейфера, с помощью квадрата Полибия, построенного по ключевому слову, 5x5 для английского языка, 6x6 для остальных предустановок или квадрата пользовательского алфавита с квадратным числом символов. Символы вне квадрата игнорируются, заполнители, вставленные при шифровании, остаются в результате.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Playfair_cipher");
+
+create_info_struct!(
+    PlayfairCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_playfaircipherencode, PlayfairCipherEncode};
+
+    fn round_trip(input: &str, lang: &str, keyword: &str, filler: &str, rule: &str) -> String {
+        let request = format!(
+            r#"{{"lang": "{}", "keyword": "{}", "filler": "{}", "split_rule": "{}"}}"#,
+            lang, keyword, filler, rule
+        );
+        let encoded = run_playfaircipherencode(PlayfairCipherEncode, input, &request).unwrap();
+        let request = format!(r#"{{"lang": "{}", "keyword": "{}"}}"#, lang, keyword);
+        run_playfaircipherdecode(PlayfairCipherDecode, &encoded, &request).unwrap()
+    }
+
+    #[test]
+    fn known_ciphertext() {
+        let request = r#"{"lang": "en", "keyword": "playfair example", "filler": "x", "split_rule": "insert_filler"}"#;
+        assert_eq!(
+            run_playfaircipherencode(
+                PlayfairCipherEncode,
+                "Hide the gold in the tree stump",
+                request
+            )
+            .unwrap(),
+            "BMODZBXDNABEKUDMUIXMMOUVIF"
+        );
+        let request = r#"{"lang": "en", "keyword": "playfair example"}"#;
+        assert_eq!(
+            run_playfaircipherdecode(PlayfairCipherDecode, "BMODZBXDNABEKUDMUIXMMOUVIF", request)
+                .unwrap(),
+            "HIDETHEGOLDINTHETREXESTUMP"
+        );
+    }
+
+    #[test]
+    fn round_trip_en() {
+        let input = "Attack at Dawn, the Jolly Roger!";
+        assert_eq!(
+            round_trip(input, "en", "monarchy", "x", "insert_filler"),
+            "ATTACKATDAWNTHEIOLLYROGERX"
+        );
+        assert_eq!(
+            round_trip("Balloon", "en", "monarchy", "x", "insert_filler"),
+            "BALXLOON"
+        );
+        assert_eq!(
+            round_trip("Balloon", "en", "monarchy", "q", "replace_with_filler"),
+            "BALQOQNQ"
+        );
+    }
+
+    #[test]
+    fn round_trip_ru() {
+        // The 6x6 squares keep the punctuation of the text, other characters are dropped.
+        let input = "Встречаемся завтра в полдень, 42 гостя.";
+        let expected = "ВСТРЕЧАЕМСЯЗАВТРАВПОЛДЕНЬ,ГОСТЯ.";
+        assert_eq!(
+            round_trip(input, "ru", "шифровка", "х", "insert_filler"),
+            expected
+        );
+        assert_eq!(
+            round_trip(input, "ru_with_yo", "ёжик", "я", "replace_with_filler"),
+            expected
+        );
+        assert_eq!(
+            round_trip("Ёлки", "ru_with_yo", "ёжик", "я", "insert_filler"),
+            "ЁЛКИ"
+        );
+    }
+}
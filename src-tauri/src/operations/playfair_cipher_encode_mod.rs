@@ -0,0 +1,190 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        alphabet::Alphabet,
+        ciphers::{playfair_digraph, playfair_square, PlayfairSplitRule},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_playfaircipherencode, PlayfairCipherEncode);
+
+impl Operation<'_, DeserializeMeDaddy> for PlayfairCipherEncode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            lang,
+            keyword,
+            filler,
+            split_rule,
+        } = request;
+
+        let (square, size) = playfair_square(&lang, &keyword)?;
+
+        let filler = match lang.polybius_normalize(&filler.to_string()).chars().next() {
+            Some(filler) if square.contains(&filler) => filler,
+            _ => bail!("The filler must be a character of the alphabet."),
+        };
+
+        let letters: Vec<char> = lang
+            .polybius_normalize(input)
+            .chars()
+            .filter(|ch| square.contains(ch))
+            .collect();
+
+        let mut digraphs: Vec<(char, char)> = Vec::new();
+        let mut i = 0;
+        while i < letters.len() {
+            let a = letters[i];
+            match letters.get(i + 1) {
+                Some(&b) if b != a => {
+                    digraphs.push((a, b));
+                    i += 2;
+                }
+                Some(_) if split_rule == PlayfairSplitRule::ReplaceWithFiller => {
+                    digraphs.push((a, filler));
+                    i += 2;
+                }
+                _ => {
+                    digraphs.push((a, filler));
+                    i += 1;
+                }
+            }
+        }
+
+        if digraphs.iter().any(|(a, b)| a == b) {
+            bail!("The filler can't split a pair of filler letters, choose another filler.");
+        }
+
+        Ok(digraphs
+            .into_iter()
+            .flat_map(|digraph| {
+                let (a, b) = playfair_digraph(&square, size, digraph, 1);
+                [a, b]
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    keyword: String,
+    filler: char,
+    split_rule: PlayfairSplitRule,
+}
+
+/// The Playfair cipher encrypts pairs of letters with a Polybius square built from a keyword: 5x5 for English, where J is merged into I, 6x6 for the other presets or the square of a custom alphabet with a square number of symbols. Characters outside of the square are removed, a pair of equal letters is split by the filler and the filler also completes the last pair.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Playfair_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PlayfairCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "keyword": string,
+///         "filler": char,
+///         "split_rule": PlayfairSplitRule
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+///     - PlayfairSplitRule is enum of "insert_filler", "replace_with_filler". The first one inserts the filler between equal letters, the second one replaces the second of them.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PlayfairCipherEncode
+///
+/// {
+///     "input": "Hide the gold in the tree stump",
+///     "params": {
+///         "lang": "en",
+///         "keyword": "playfair example",
+///         "filler": "x",
+///         "split_rule": "insert_filler"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "BMODZBXDNABEKUDMUIXMMOUVIF"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PlayfairCipherEncode
+///
+/// {
+///     "input": "Встречаемся завтра в полдень",
+///     "params": {
+///         "lang": "ru",
+///         "keyword": "шифровка",
+///         "filler": "х",
+///         "split_rule": "replace_with_filler"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ИЦУФКЭБКЗХИСЕИУФЕИХШМГНЦ-Ь"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/PlayfairCipherEncode
+///
+/// {
+///     "input": "Hide the gold in the tree stump",
+///     "params": {
+///         "lang": "en",
+///         "keyword": "playfair example",
+///         "filler": "1",
+///         "split_rule": "insert_filler"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "The filler must be a character of the alphabet."
+/// }
+/// ```
+pub struct PlayfairCipherEncode;
+
+const NAME: &str = "PlayfairCipherEncode";
+const DESCRIPTION_EN: &str = "The Playfair cipher encrypts pairs of letters with a Polybius square built from a keyword: 5x5 for English, where J is merged into I, 6x6 for the other presets or the square of a custom alphabet with a square number of symbols. Characters outside of the square are removed, a pair of equal letters is split by the filler and the filler also completes the last pair.";
+const DESCRIPTION_RU: &str = "Шифр Плейфера шифрует пары букв с помощью квадрата Полибия, построенного по ключевому слову: 5x5 для английского языка, где J объединена с I, 6x6 для остальных предустановок или квадрата пользовательского алфавита с квадратным числом символов. Символы вне квадрата удаляются, пара одинаковых букв разделяется заполнителем, он же дополняет последнюю пару.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Playfair_cipher");
+
+create_info_struct!(
+    PlayfairCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
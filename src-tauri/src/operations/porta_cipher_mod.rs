@@ -0,0 +1,196 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{alphabet::Alphabet, vigenere_trait::VigenereCipher},
+    run_op,
+    utils::modulus,
+    Operation, DOCS_URL,
+};
+
+impl VigenereCipher for PortaCipher {}
+
+run_op!(run_portacipher, PortaCipher);
+
+impl Operation<'_, DeserializeMeDaddy> for PortaCipher {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { lang, key } = request;
+
+        let alp_length = lang.length();
+        if alp_length % 2 != 0 {
+            bail!("The Porta cipher needs an alphabet with an even number of letters.");
+        }
+
+        let half = alp_length as i16 / 2;
+        <Self as VigenereCipher>::cipher(&lang, &key, input, |text, key| match text < half {
+            true => half + modulus(text + key / 2, half),
+            false => modulus(text - half - key / 2, half),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    lang: Alphabet,
+    key: String,
+}
+
+/// The Porta cipher is a reciprocal polyalphabetic cipher: every pair of key letters selects one of the tables that swap the letters of the first half of the alphabet with the letters of the second half, so the same operation both encrypts and decrypts. The alphabet must have an even number of letters, so the Russian alphabet with Ё is not supported.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Giambattista_della_Porta).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/PortaCipher with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "lang": Alphabet,
+///         "key": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/PortaCipher
+///
+/// {
+///     "input": "Defend the east wall",
+///     "params": {
+///         "lang": "en",
+///         "key": "fortify"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Synnjs hwy zwbe kpst"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/PortaCipher
+///
+/// {
+///     "input": "Защищайте восточную стену",
+///     "params": {
+///         "lang": "ru",
+///         "key": "крепость"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Ьшзявштдъ ърклцоыож пльхк"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/PortaCipher
+///
+/// {
+///     "input": "Ёлка",
+///     "params": {
+///         "lang": "ru_with_yo",
+///         "key": "ключ"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "The Porta cipher needs an alphabet with an even number of letters."
+/// }
+/// ```
+pub struct PortaCipher;
+
+const NAME: &str = "PortaCipher";
+const DESCRIPTION_EN: &str = "The Porta cipher is a reciprocal polyalphabetic cipher: every pair of key letters selects one of the tables that swap the letters of the first half of the alphabet with the letters of the second half, so the same operation both encrypts and decrypts. The alphabet must have an even number of letters, so the Russian alphabet with Ё is not supported.";
+const DESCRIPTION_RU: &str = "Шифр Порта — это взаимный полиалфавитный шифр: каждая пара букв ключа выбирает одну из таблиц, меняющих местами буквы первой половины алфавита с буквами второй половины, поэтому одна и та же операция и шифрует, и расшифровывает. Алфавит должен содержать чётное число букв, поэтому русский алфавит с буквой Ё не поддерживается.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Giambattista_della_Porta");
+
+create_info_struct!(
+    PortaCipherInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn porta(input: &str, lang: &str, key: &str) -> Result<String, String> {
+        let request = format!(r#"{{"lang": "{}", "key": "{}"}}"#, lang, key);
+        run_portacipher(PortaCipher, input, &request)
+    }
+
+    #[test]
+    fn known_ciphertext() {
+        assert_eq!(
+            porta("DEFENDTHEEASTWALLOFTHECASTLE", "en", "FORTIFICATION").unwrap(),
+            "SYNNJSCVRNRLAHUTUKUCVRYRLANY"
+        );
+    }
+
+    #[test]
+    fn tableau() {
+        // Key letters A and B swap the halves of the alphabet, every next pair shifts them by one.
+        assert_eq!(porta("anmz", "en", "a").unwrap(), "nazm");
+        assert_eq!(porta("anmz", "en", "b").unwrap(), "nazm");
+        assert_eq!(porta("anmz", "en", "c").unwrap(), "omnl");
+        assert_eq!(porta("anmz", "en", "z").unwrap(), "zbya");
+    }
+
+    #[test]
+    fn reciprocal() {
+        for (input, lang, key) in [
+            (
+                "Defend the East Wall of the Castle, 42 men!",
+                "en",
+                "Fortification",
+            ),
+            (
+                "Съешь же этих мягких французских булок, 42 раза!",
+                "ru",
+                "Шифр",
+            ),
+        ] {
+            let encoded = porta(input, lang, key).unwrap();
+            assert_ne!(encoded, input);
+            assert_eq!(porta(&encoded, lang, key).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn odd_alphabet() {
+        assert_eq!(
+            porta("ёлка", "ru_with_yo", "ключ").unwrap_err(),
+            "The Porta cipher needs an alphabet with an even number of letters."
+        );
+    }
+}
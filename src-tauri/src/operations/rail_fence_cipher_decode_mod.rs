@@ -0,0 +1,130 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{rail_fence_order, untranspose},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_railfencecipherdecode, RailFenceCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for RailFenceCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { rails, offset } = request;
+        let len = input.chars().count();
+
+        Ok(untranspose(input, &rail_fence_order(len, rails, offset)?))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    rails: usize,
+    offset: usize,
+}
+
+/// Decodes text encrypted with the Rail fence cipher by putting the characters of every rail back to their places in the zigzag.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Rail_fence_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RailFenceCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "rails": usize,
+///         "offset": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - rails is number of rails, at least 2 and at most the input length
+///     - offset is number of zigzag steps skipped before the first character
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RailFenceCipherDecode
+///
+/// {
+///     "input": "WRIVDETCEAEDSOEE LEA NE  CRF O",
+///     "params": {
+///         "rails": 3,
+///         "offset": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WE ARE DISCOVERED FLEE AT ONCE"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RailFenceCipherDecode
+///
+/// {
+///     "input": " нжзиржзоонигдШфеедро ооилойр",
+///     "params": {
+///         "rails": 4,
+///         "offset": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Шифр железнодорожной изгороди"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/RailFenceCipherDecode
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "rails": 1,
+///         "offset": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Number of rails must be at least 2."
+/// }
+/// ```
+pub struct RailFenceCipherDecode;
+
+const NAME: &str = "RailFenceCipherDecode";
+const DESCRIPTION_EN: &str = "Decodes text encrypted with the Rail fence cipher by putting the characters of every rail back to their places in the zigzag.";
+const DESCRIPTION_RU: &str = "Расшифровывает текст, зашифрованный шифром железнодорожной изгороди, возвращая символы каждого рельса на их места в зигзаге.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Rail_fence_cipher");
+
+create_info_struct!(
+    RailFenceCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
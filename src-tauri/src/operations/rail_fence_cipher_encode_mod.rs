@@ -0,0 +1,130 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{rail_fence_order, transpose},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_railfencecipherencode, RailFenceCipherEncode);
+
+impl Operation<'_, DeserializeMeDaddy> for RailFenceCipherEncode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { rails, offset } = request;
+        let len = input.chars().count();
+
+        Ok(transpose(input, &rail_fence_order(len, rails, offset)?))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    rails: usize,
+    offset: usize,
+}
+
+/// The Rail fence cipher is a transposition cipher that writes the text in a zigzag over a number of rails and then reads the rails one by one. All characters including spaces are transposed.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Rail_fence_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RailFenceCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "rails": usize,
+///         "offset": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - rails is number of rails, at least 2 and at most the input length
+///     - offset is number of zigzag steps skipped before the first character
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RailFenceCipherEncode
+///
+/// {
+///     "input": "WE ARE DISCOVERED FLEE AT ONCE",
+///     "params": {
+///         "rails": 3,
+///         "offset": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WRIVDETCEAEDSOEE LEA NE  CRF O"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RailFenceCipherEncode
+///
+/// {
+///     "input": "Шифр железнодорожной изгороди",
+///     "params": {
+///         "rails": 4,
+///         "offset": 2
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": " нжзиржзоонигдШфеедро ооилойр"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/RailFenceCipherEncode
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "rails": 1,
+///         "offset": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Number of rails must be at least 2."
+/// }
+/// ```
+pub struct RailFenceCipherEncode;
+
+const NAME: &str = "RailFenceCipherEncode";
+const DESCRIPTION_EN: &str = "The Rail fence cipher is a transposition cipher that writes the text in a zigzag over a number of rails and then reads the rails one by one. All characters including spaces are transposed.";
+const DESCRIPTION_RU: &str = "Шифр железнодорожной изгороди — это шифр перестановки, который записывает текст зигзагом по нескольким рельсам, а затем читает рельсы один за другим. Переставляются все символы, включая пробелы.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Rail_fence_cipher");
+
+create_info_struct!(
+    RailFenceCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,149 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{decode_input, encode_output, InputFormat, OutputFormat},
+        rc4::Rc4,
+    },
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_rc4drop, RC4Drop);
+
+const MAX_DROP_BYTES: usize = 1 << 20;
+
+impl Operation<'_, DeserializeMeDaddy> for RC4Drop {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            key,
+            key_format,
+            drop_bytes,
+            input_format,
+            output_format,
+        } = request;
+
+        let drop_bytes = drop_bytes.unwrap_or(768);
+        if drop_bytes > MAX_DROP_BYTES {
+            bail!("Number of dropped bytes must not exceed {MAX_DROP_BYTES}.");
+        }
+
+        let mut data = decode_input(input, input_format)?;
+        let mut rc4 = Rc4::new(&convert_to_byte_array(&key, &key_format)?)?;
+        rc4.discard(drop_bytes);
+        rc4.apply_keystream(&mut data);
+
+        Ok(encode_output(&data, output_format))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    key_format: SupportedFormats,
+    drop_bytes: Option<usize>,
+    input_format: InputFormat,
+    output_format: OutputFormat,
+}
+
+/// It was discovered that the first few bytes of the RC4 keystream are strongly non-random and leak information about the key. We can defend against this attack by discarding the initial portion of the keystream. This modified algorithm is traditionally called RC4-drop.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/RC4#Fluhrer,_Mantin_and_Shamir_attack).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RC4Drop with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "drop_bytes": Option<usize>,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Option<usize> is number of discarded keystream bytes up to 1048576 or null (768 bytes by default)
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw", raw input is taken as UTF-8 text and invalid UTF-8 in raw output is replaced, so only hex and base64 keep arbitrary bytes
+///     - key must be from 1 to 256 bytes long
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RC4Drop
+///
+/// {
+///     "input": "Plaintext",
+///     "params": {
+///         "key": "Key",
+///         "key_format": "utf8",
+///         "drop_bytes": 768,
+///         "input_format": "raw",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "857047028b192029fd"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RC4Drop
+///
+/// {
+///     "input": "Plaintext",
+///     "params": {
+///         "key": "Key",
+///         "key_format": "utf8",
+///         "drop_bytes": -1,
+///         "input_format": "raw",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid value: integer `-1`, expected usize."
+/// }
+/// ```
+pub struct RC4Drop;
+
+const NAME: &str = "RC4Drop";
+const DESCRIPTION_EN: &str = "It was discovered that the first few bytes of the RC4 keystream are strongly non-random and leak information about the key. We can defend against this attack by discarding the initial portion of the keystream. This modified algorithm is traditionally called RC4-drop.";
+const DESCRIPTION_RU: &str = "Было обнаружено, что первые байты ключевого потока RC4 сильно неслучайны и раскрывают информацию о ключе. От этой атаки можно защититься, отбрасывая начальную часть ключевого потока. Такой модифицированный алгоритм традиционно называется RC4-drop.";
+
+const INFO_URL: Option<&str> =
+    Some("https://wikipedia.org/wiki/RC4#Fluhrer,_Mantin_and_Shamir_attack");
+
+create_info_struct!(
+    RC4DropInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
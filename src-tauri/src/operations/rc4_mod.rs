@@ -0,0 +1,154 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{decode_input, encode_output, InputFormat, OutputFormat},
+        rc4::Rc4,
+    },
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_rc4, RC4);
+
+impl Operation<'_, DeserializeMeDaddy> for RC4 {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            key,
+            key_format,
+            input_format,
+            output_format,
+        } = request;
+
+        let mut data = decode_input(input, input_format)?;
+        let mut rc4 = Rc4::new(&convert_to_byte_array(&key, &key_format)?)?;
+        rc4.apply_keystream(&mut data);
+
+        Ok(encode_output(&data, output_format))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    key_format: SupportedFormats,
+    input_format: InputFormat,
+    output_format: OutputFormat,
+}
+
+/// RC4 (also known as ARC4) is a widely-used stream cipher designed by Ron Rivest. It is used in popular protocols such as SSL and WEP. Although remarkable for its simplicity and speed, the algorithm's history doesn't inspire confidence in its security.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/RC4).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RC4 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw", raw input is taken as UTF-8 text and invalid UTF-8 in raw output is replaced, so only hex and base64 keep arbitrary bytes
+///     - key must be from 1 to 256 bytes long
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RC4
+///
+/// {
+///     "input": "Plaintext",
+///     "params": {
+///         "key": "Key",
+///         "key_format": "utf8",
+///         "input_format": "raw",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "bbf316e8d940af0ad3"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RC4
+///
+/// {
+///     "input": "u/MW6NlArwrT",
+///     "params": {
+///         "key": "4b6579",
+///         "key_format": "hex",
+///         "input_format": "base64",
+///         "output_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Plaintext"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/RC4
+///
+/// {
+///     "input": "Plaintext",
+///     "params": {
+///         "key": "",
+///         "key_format": "utf8",
+///         "input_format": "raw",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid key length for RC4: expected from 1 to 256 bytes, got 0 bytes."
+/// }
+/// ```
+pub struct RC4;
+
+const NAME: &str = "RC4";
+const DESCRIPTION_EN: &str = "RC4 (also known as ARC4) is a widely-used stream cipher designed by Ron Rivest. It is used in popular protocols such as SSL and WEP. Although remarkable for its simplicity and speed, the algorithm's history doesn't inspire confidence in its security.";
+const DESCRIPTION_RU: &str = "RC4 (также известный как ARC4) — широко используемый потоковый шифр, разработанный Рональдом Ривестом. Он используется в популярных протоколах, таких как SSL и WEP. Несмотря на простоту и скорость, история алгоритма не внушает доверия к его безопасности.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/RC4");
+
+create_info_struct!(
+    RC4Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{create_info_struct, run_op, utils::modulus, Operation, DOCS_URL};
+use anyhow::Result;
+
+run_op!(run_rot13, ROT13);
+
+impl Operation<'_, DeserializeMeDaddy> for ROT13 {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            rotate_lower,
+            rotate_upper,
+            rotate_numbers,
+            amount,
+        } = request;
+
+        let rotate = |c: char, first: u8, len: i16| {
+            let shift = modulus(amount, len);
+            (first + modulus(c as i16 - first as i16 + shift, len) as u8) as char
+        };
+
+        Ok(input
+            .chars()
+            .map(|c| match c {
+                'a'..='z' if rotate_lower => rotate(c, b'a', 26),
+                'A'..='Z' if rotate_upper => rotate(c, b'A', 26),
+                '0'..='9' if rotate_numbers => rotate(c, b'0', 10),
+                _ => c,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    rotate_lower: bool,
+    rotate_upper: bool,
+    rotate_numbers: bool,
+    amount: i16,
+}
+
+/// A simple caesar substitution cipher which rotates alphabet characters by the specified amount, 13 for the classic ROT13. Lowercase letters, uppercase letters and digits are rotated only when their option is enabled, digits are rotated within 0-9.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/ROT13).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ROT13 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "rotate_lower": bool,
+///         "rotate_upper": bool,
+///         "rotate_numbers": bool,
+///         "amount": i16
+///     }
+/// }
+/// ```
+/// #### where
+///     - amount is number of positions to rotate by, negative amount rotates backwards
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ROT13
+///
+/// {
+///     "input": "Hello, World! 2024",
+///     "params": {
+///         "rotate_lower": true,
+///         "rotate_upper": true,
+///         "rotate_numbers": false,
+///         "amount": 13
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Uryyb, Jbeyq! 2024"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ROT13
+///
+/// {
+///     "input": "Hello, World! 2024",
+///     "params": {
+///         "rotate_lower": true,
+///         "rotate_upper": false,
+///         "rotate_numbers": true,
+///         "amount": 5
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Hjqqt, Wtwqi! 7579"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ROT13
+///
+/// {
+///     "input": "Hello, World! 2024",
+///     "params": {
+///         "rotate_lower": true,
+///         "rotate_upper": true,
+///         "rotate_numbers": true
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Missing field `amount`."
+/// }
+/// ```
+pub struct ROT13;
+
+const NAME: &str = "ROT13";
+const DESCRIPTION_EN: &str = "A simple caesar substitution cipher which rotates alphabet characters by the specified amount, 13 for the classic ROT13. Lowercase letters, uppercase letters and digits are rotated only when their option is enabled, digits are rotated within 0-9.";
+const DESCRIPTION_RU: &str = "Простой шифр подстановки Цезаря, который сдвигает символы алфавита на указанное количество позиций, 13 для классического ROT13. Строчные буквы, заглавные буквы и цифры сдвигаются, только если включена соответствующая опция, цифры сдвигаются в пределах 0-9.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/ROT13");
+
+create_info_struct!(
+    ROT13Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rot13(input: &str, amount: i16) -> String {
+        let request = format!(
+            r#"{{"rotate_lower": true, "rotate_upper": true, "rotate_numbers": true, "amount": {}}}"#,
+            amount
+        );
+        run_rot13(ROT13, input, &request).unwrap()
+    }
+
+    #[test]
+    fn rotates_letters_and_numbers() {
+        assert_eq!(rot13("Hello, World! 2024", 13), "Uryyb, Jbeyq! 5357");
+        assert_eq!(rot13("Uryyb, Jbeyq! 5357", -13), "Hello, World! 2024");
+    }
+
+    #[test]
+    fn large_and_negative_amounts() {
+        // Letters and numbers both come back after 130 = lcm(26, 10) positions.
+        let input = "Hello, World! 2024";
+        assert_eq!(rot13(input, i16::MAX), rot13(input, 7));
+        assert_eq!(rot13(input, i16::MIN), rot13(input, -8));
+        assert_eq!(rot13(input, -1), "Gdkkn, Vnqkc! 1913");
+        assert_eq!(rot13(&rot13(input, i16::MAX), -i16::MAX), input);
+    }
+}
@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{create_info_struct, run_op, utils::modulus, Operation, DOCS_URL};
+use anyhow::Result;
+
+run_op!(run_rot47, ROT47);
+
+impl Operation<'_, DeserializeMeDaddy> for ROT47 {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let shift = modulus(request.amount, 94);
+
+        Ok(input
+            .chars()
+            .map(|c| match c {
+                '!'..='~' => (b'!' + modulus(c as i16 - b'!' as i16 + shift, 94) as u8) as char,
+                _ => c,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    amount: i16,
+}
+
+/// A slightly more complex variation of a caesar cipher, which includes ASCII characters from 33 '!' to 126 '~'. Classic ROT47 rotates by 47, spaces and all other characters are left as is.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/ROT13#Variants).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ROT47 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "amount": i16
+///     }
+/// }
+/// ```
+/// #### where
+///     - amount is number of positions to rotate by, 47 for classic ROT47
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ROT47
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "amount": 47
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "w6==@[ (@C=5P"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ROT47
+///
+/// {
+///     "input": "7=28LC_E0cf0`d0?_E0b?4CJAE`_?N",
+///     "params": {
+///         "amount": 47
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "flag{r0t_47_15_n0t_3ncrypt10n}"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ROT47
+///
+/// {
+///     "input": "Hello, World!",
+///     "params": {
+///         "amount": "47"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid type: string \"47\", expected i16."
+/// }
+/// ```
+pub struct ROT47;
+
+const NAME: &str = "ROT47";
+const DESCRIPTION_EN: &str = "A slightly more complex variation of a caesar cipher, which includes ASCII characters from 33 '!' to 126 '~'. Classic ROT47 rotates by 47, spaces and all other characters are left as is.";
+const DESCRIPTION_RU: &str = "Немного более сложный вариант шифра Цезаря, который включает символы ASCII от 33 '!' до 126 '~'. Классический ROT47 использует сдвиг 47, пробелы и все остальные символы остаются без изменений.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/ROT13#Variants");
+
+create_info_struct!(
+    ROT47Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rot47(input: &str, amount: i16) -> String {
+        run_rot47(ROT47, input, &format!(r#"{{"amount": {}}}"#, amount)).unwrap()
+    }
+
+    #[test]
+    fn rotates_printable_ascii() {
+        assert_eq!(rot47("Hello, World!", 47), "w6==@[ (@C=5P");
+        assert_eq!(rot47("w6==@[ (@C=5P", -47), "Hello, World!");
+    }
+
+    #[test]
+    fn large_and_negative_amounts() {
+        let input = "Hello, World! ~";
+        assert_eq!(rot47(input, i16::MAX), rot47(input, i16::MAX % 94));
+        assert_eq!(rot47(input, i16::MIN), rot47(input, i16::MIN % 94 + 94));
+        assert_eq!(rot47(input, -1), "Gdkkn+ Vnqkc~ }");
+        assert_eq!(rot47(&rot47(input, i16::MAX), -i16::MAX), input);
+    }
+}
@@ -0,0 +1,140 @@
+use serde::Serialize;
+
+use crate::{create_info_struct, run_op, Operation, DOCS_URL};
+use anyhow::Result;
+
+run_op!(run_rot8000, ROT8000);
+
+impl Operation<'_, ()> for ROT8000 {
+    fn do_black_magic(&self, input: &str, _request: &str) -> Result<String> {
+        let total: u32 = VALID_RANGES
+            .iter()
+            .map(|(start, end)| end - start + 1)
+            .sum();
+
+        Ok(input
+            .chars()
+            .map(|c| match position(c as u32) {
+                Some(idx) => code_point((idx + total / 2) % total),
+                None => c,
+            })
+            .collect())
+    }
+}
+
+/// Basic Multilingual Plane ranges rotated by ROT8000, whitespace, control characters and surrogates are excluded.
+const VALID_RANGES: [(u32, u32); 9] = [
+    (0x0021, 0x007E),
+    (0x00A1, 0x167F),
+    (0x1681, 0x1FFF),
+    (0x200B, 0x2027),
+    (0x202A, 0x202E),
+    (0x2030, 0x205E),
+    (0x2060, 0x2FFF),
+    (0x3001, 0xD7FF),
+    (0xE000, 0xFFFF),
+];
+
+fn position(code: u32) -> Option<u32> {
+    let mut offset = 0;
+    for (start, end) in VALID_RANGES {
+        if (start..=end).contains(&code) {
+            return Some(offset + code - start);
+        }
+        offset += end - start + 1;
+    }
+    None
+}
+
+fn code_point(mut idx: u32) -> char {
+    for (start, end) in VALID_RANGES {
+        if idx <= end - start {
+            return char::from_u32(start + idx).unwrap();
+        }
+        idx -= end - start + 1;
+    }
+    unreachable!()
+}
+
+/// The simple Caesar-cypher encryption that replaces each Unicode character with the one 0x8000 places forward or back along the alphabet of the Basic Multilingual Plane. The operation is its own inverse, whitespace and control characters are left as is.
+/// <br><br/>
+/// For more information go [here](https://rot8000.com/info).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ROT8000 with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+/// }
+/// ```
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ROT8000
+///
+/// {
+///     "input": "Hello, World!"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "籑籮籵籵籸簵 籠籸类籵籭簪"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ROT8000
+///
+/// {
+///     "input": "耆耧耟耙耜耩簵 耣耟耧簪"
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Привет, мир!"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ROT8000
+///
+/// {
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Missing field `input`."
+/// }
+/// ```
+pub struct ROT8000;
+
+const NAME: &str = "ROT8000";
+const DESCRIPTION_EN: &str = "The simple Caesar-cypher encryption that replaces each Unicode character with the one 0x8000 places forward or back along the alphabet of the Basic Multilingual Plane. The operation is its own inverse, whitespace and control characters are left as is.";
+const DESCRIPTION_RU: &str = "Простое шифрование в духе шифра Цезаря, которое заменяет каждый символ Unicode на символ, отстоящий на 0x8000 позиций вперёд или назад в алфавите основной многоязычной плоскости. Операция является обратной самой себе, пробельные и управляющие символы остаются без изменений.";
+
+const INFO_URL: Option<&str> = Some("https://rot8000.com/info");
+
+create_info_struct!(
+    ROT8000Info,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,130 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{route_order, untranspose, Route},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_routecipherdecode, RouteCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for RouteCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { columns, route } = request;
+        let len = input.chars().count();
+
+        Ok(untranspose(input, &route_order(len, columns, route)?))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    columns: usize,
+    route: Route,
+}
+
+/// Decodes text encrypted with the Route cipher by placing the characters back into the grid along the same route and reading it row by row.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Transposition_cipher#Route_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RouteCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "columns": usize,
+///         "route": Route
+///     }
+/// }
+/// ```
+/// #### where
+///     - columns is width of the grid the text is written in, at most the input length
+///     - Route is enum of "spiral_clockwise", "spiral_counterclockwise", "snake_rows", "snake_columns", every route starts at the top left corner
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RouteCipherDecode
+///
+/// {
+///     "input": "WEAREODAECNOTFVDISCEEELER",
+///     "params": {
+///         "columns": 5,
+///         "route": "spiral_clockwise"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WEAREDISCOVEREDFLEEATONCE"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RouteCipherDecode
+///
+/// {
+///     "input": "WIREEAESEACDTOFOREVLNCEED",
+///     "params": {
+///         "columns": 6,
+///         "route": "snake_columns"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WEAREDISCOVEREDFLEEATONCE"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/RouteCipherDecode
+///
+/// {
+///     "input": "WEAREDISCOVEREDFLEEATONCE",
+///     "params": {
+///         "columns": 0,
+///         "route": "snake_rows"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Number of columns must be a positive number."
+/// }
+/// ```
+pub struct RouteCipherDecode;
+
+const NAME: &str = "RouteCipherDecode";
+const DESCRIPTION_EN: &str = "Decodes text encrypted with the Route cipher by placing the characters back into the grid along the same route and reading it row by row.";
+const DESCRIPTION_RU: &str = "Расшифровывает текст, зашифрованный маршрутным шифром, размещая символы обратно в таблицу по тому же маршруту и читая её по строкам.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Transposition_cipher#Route_cipher");
+
+create_info_struct!(
+    RouteCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,130 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{route_order, transpose, Route},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_routecipherencode, RouteCipherEncode);
+
+impl Operation<'_, DeserializeMeDaddy> for RouteCipherEncode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy { columns, route } = request;
+        let len = input.chars().count();
+
+        Ok(transpose(input, &route_order(len, columns, route)?))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    columns: usize,
+    route: Route,
+}
+
+/// The Route cipher writes the text in rows of a grid and reads it along a route: a clockwise or counterclockwise spiral to the center, or a snake over the rows or the columns. Cells of the incomplete last row are skipped.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Transposition_cipher#Route_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RouteCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "columns": usize,
+///         "route": Route
+///     }
+/// }
+/// ```
+/// #### where
+///     - columns is width of the grid the text is written in, at most the input length
+///     - Route is enum of "spiral_clockwise", "spiral_counterclockwise", "snake_rows", "snake_columns", every route starts at the top left corner
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RouteCipherEncode
+///
+/// {
+///     "input": "WEAREDISCOVEREDFLEEATONCE",
+///     "params": {
+///         "columns": 5,
+///         "route": "spiral_clockwise"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WEAREODAECNOTFVDISCEEELER"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RouteCipherEncode
+///
+/// {
+///     "input": "WEAREDISCOVEREDFLEEATONCE",
+///     "params": {
+///         "columns": 6,
+///         "route": "snake_columns"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "WIREEAESEACDTOFOREVLNCEED"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/RouteCipherEncode
+///
+/// {
+///     "input": "WEAREDISCOVEREDFLEEATONCE",
+///     "params": {
+///         "columns": 5,
+///         "route": "zigzag"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Unknown variant `zigzag`, expected one of `spiral_clockwise`, `spiral_counterclockwise`, `snake_rows`, `snake_columns`."
+/// }
+/// ```
+pub struct RouteCipherEncode;
+
+const NAME: &str = "RouteCipherEncode";
+const DESCRIPTION_EN: &str = "The Route cipher writes the text in rows of a grid and reads it along a route: a clockwise or counterclockwise spiral to the center, or a snake over the rows or the columns. Cells of the incomplete last row are skipped.";
+const DESCRIPTION_RU: &str = "Маршрутный шифр записывает текст строками в таблицу и читает его по маршруту: по спирали к центру по часовой стрелке или против неё либо змейкой по строкам или столбцам. Ячейки неполной последней строки пропускаются.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Transposition_cipher#Route_cipher");
+
+create_info_struct!(
+    RouteCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -1,15 +1,16 @@
 use anyhow::{bail, Result};
-use rsa::{pkcs1::DecodeRsaPrivateKey, Oaep, Pkcs1v15Encrypt, RsaPrivateKey};
+use rsa::Pkcs1v15Encrypt;
 use serde::{Deserialize, Serialize};
-use sha1::Sha1;
-use sha2::{Sha224, Sha256, Sha384, Sha512};
-use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
 
 use crate::{
     create_info_struct,
-    libs::base64::{from_base64, to_base64},
+    libs::{
+        base64::{from_base64, to_base64},
+        rsa_key::RsaKey,
+        rsa_padding::oaep,
+    },
     run_op,
-    utils::to_hex,
+    utils::{to_hex, SupportedMessageDigestAlgorithm},
     Operation, DOCS_URL,
 };
 
@@ -21,6 +22,7 @@ impl Operation<'_, DeserializeMeDaddy> for RSADecrypt {
 
         let DeserializeMeDaddy {
             private_key,
+            passphrase,
             encrypted_scheme,
             message_digest_algorithm,
             output_format,
@@ -39,21 +41,11 @@ impl Operation<'_, DeserializeMeDaddy> for RSADecrypt {
             SupportedOutputFormat::Raw => input.as_bytes().to_vec(),
         };
 
-        let pem_key: RsaPrivateKey = DecodeRsaPrivateKey::from_pkcs1_pem(&private_key)?;
+        let pem_key = RsaKey::detect(&private_key, passphrase.as_deref())?.into_private_key()?;
 
         let encrypted_text = match encrypted_scheme {
             SupportedEncryptionSchemes::RSA_OAEP => {
-                let padding = match message_digest_algorithm.unwrap() {
-                    SupportedMessageDigestAlgorithm::SHA1 => Oaep::new::<Sha1>(),
-                    SupportedMessageDigestAlgorithm::SHA2_224 => Oaep::new::<Sha224>(),
-                    SupportedMessageDigestAlgorithm::SHA2_256 => Oaep::new::<Sha256>(),
-                    SupportedMessageDigestAlgorithm::SHA2_384 => Oaep::new::<Sha384>(),
-                    SupportedMessageDigestAlgorithm::SHA2_512 => Oaep::new::<Sha512>(),
-                    SupportedMessageDigestAlgorithm::SHA3_224 => Oaep::new::<Sha3_224>(),
-                    SupportedMessageDigestAlgorithm::SHA3_256 => Oaep::new::<Sha3_256>(),
-                    SupportedMessageDigestAlgorithm::SHA3_384 => Oaep::new::<Sha3_384>(),
-                    SupportedMessageDigestAlgorithm::SHA3_512 => Oaep::new::<Sha3_512>(),
-                };
+                let padding = oaep(message_digest_algorithm.unwrap());
                 pem_key.decrypt(padding, &input)
             }
             SupportedEncryptionSchemes::RSA_AES_PKCS1_V1_5 => {
@@ -78,21 +70,6 @@ enum SupportedEncryptionSchemes {
     RSA_AES_PKCS1_V1_5,
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum SupportedMessageDigestAlgorithm {
-    SHA1,
-    SHA2_224,
-    SHA2_256,
-    SHA2_384,
-    SHA2_512,
-    SHA3_224,
-    SHA3_256,
-    SHA3_384,
-    SHA3_512,
-}
-
 #[derive(Deserialize)]
 #[serde(rename_all = "lowercase")]
 enum SupportedOutputFormat {
@@ -105,6 +82,7 @@ enum SupportedOutputFormat {
 struct DeserializeMeDaddy {
     #[serde(rename = "pem_key")]
     private_key: String,
+    passphrase: Option<String>,
     #[serde(rename = "scheme")]
     encrypted_scheme: SupportedEncryptionSchemes,
     #[serde(rename = "digest_alg")]
@@ -113,7 +91,7 @@ struct DeserializeMeDaddy {
     input_format: SupportedOutputFormat,
 }
 
-/// Decrypt a message with a PEM encoded RSA private key.
+/// Decrypt a message with an RSA private key. The key format is detected automatically.
 /// <br><br/>
 /// For more information go [here](https://wikipedia.org/wiki/RSA_(cryptosystem))
 /// <br><br/>
@@ -125,7 +103,8 @@ struct DeserializeMeDaddy {
 /// {
 ///     "input": base64,
 ///     "params": {
-///         "pem_key": PEM,
+///         "pem_key": RsaKey,
+///         "passphrase": Option<string>,
 ///         "scheme": SupportedEncryptionSchemes,
 ///         "digest_alg": Option<SupportedMessageDigestAlgorithm>
 ///         "output_format": SupportedOutputFormat
@@ -134,7 +113,8 @@ struct DeserializeMeDaddy {
 /// ```
 /// #### where
 ///     - base64 is base64 encoded string
-///     - PEM is pem encoded RSA public key
+///     - RsaKey is RSA private key in PKCS#1, PKCS#8, encrypted PKCS#8 PEM, DER (hex or base64) or JWK format
+///     - passphrase is required only for encrypted PKCS#8 private keys
 ///     - SupportedEncryptionSchemes is enum of "oaep" and "pkcs1_v15"
 ///     - Option<SupportedMessageDigestAlgorithm> is optional enum of "sha1", "sha2-224", "sha2-256", "sha2-384", "sha2-512", "sha3-224", "sha3-256", "sha3-384", "sha3-512"
 ///     - SupportedOutputFormat is enum of "hex", "base64", "uint8array"
@@ -218,8 +198,10 @@ struct DeserializeMeDaddy {
 pub struct RSADecrypt;
 
 const NAME: &str = "RSADecrypt";
-const DESCRIPTION_EN: &str = "Decrypt a message with a PEM encoded RSA private key.";
-const DESCRIPTION_RU: &str = "Дешифрует сообщение с помощью приватного ключа RSA с кодировкой PEM.";
+const DESCRIPTION_EN: &str =
+    "Decrypt a message with an RSA private key. The key format is detected automatically.";
+const DESCRIPTION_RU: &str =
+    "Дешифрует сообщение с помощью приватного ключа RSA. Формат ключа определяется автоматически.";
 
 const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/RSA_(cryptosystem)");
 
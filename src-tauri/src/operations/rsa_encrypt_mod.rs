@@ -1,12 +1,13 @@
 use anyhow::{bail, Result};
-use rsa::{pkcs1::DecodeRsaPublicKey, Oaep, Pkcs1v15Encrypt, RsaPublicKey};
+use rsa::Pkcs1v15Encrypt;
 use serde::{Deserialize, Serialize};
-use sha1::Sha1;
-use sha2::{Sha224, Sha256, Sha384, Sha512};
-use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
 
 use crate::{
-    create_info_struct, libs::base64::to_base64, run_op, utils::to_hex, Operation, DOCS_URL,
+    create_info_struct,
+    libs::{base64::to_base64, rsa_key::RsaKey, rsa_padding::oaep},
+    run_op,
+    utils::{to_hex, SupportedMessageDigestAlgorithm},
+    Operation, DOCS_URL,
 };
 
 run_op!(run_rsaencrypt, RSAEncrypt);
@@ -14,8 +15,9 @@ run_op!(run_rsaencrypt, RSAEncrypt);
 impl Operation<'_, DeserializeMeDaddy> for RSAEncrypt {
     fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
         let request = self.validate(request)?;
-        let (public_key, encrypted_scheme, message_digest_algorithm, output_format) = (
+        let (public_key, passphrase, encrypted_scheme, message_digest_algorithm, output_format) = (
             request.public_key,
+            request.passphrase,
             request.encrypted_scheme,
             request.message_digest_algorithm,
             request.output_format,
@@ -27,22 +29,12 @@ impl Operation<'_, DeserializeMeDaddy> for RSAEncrypt {
             bail!("RSA_OAEP must have message digest algorithm");
         }
 
-        let pub_key = RsaPublicKey::from_pkcs1_pem(&public_key)?;
+        let pub_key = RsaKey::detect(&public_key, passphrase.as_deref())?.public_key();
         let mut rng = rand::thread_rng();
 
         let encrypted_text = match encrypted_scheme {
             SupportedEncryptionSchemes::RSA_OAEP => {
-                let padding = match message_digest_algorithm.unwrap() {
-                    SupportedMessageDigestAlgorithm::SHA1 => Oaep::new::<Sha1>(),
-                    SupportedMessageDigestAlgorithm::SHA2_224 => Oaep::new::<Sha224>(),
-                    SupportedMessageDigestAlgorithm::SHA2_256 => Oaep::new::<Sha256>(),
-                    SupportedMessageDigestAlgorithm::SHA2_384 => Oaep::new::<Sha384>(),
-                    SupportedMessageDigestAlgorithm::SHA2_512 => Oaep::new::<Sha512>(),
-                    SupportedMessageDigestAlgorithm::SHA3_224 => Oaep::new::<Sha3_224>(),
-                    SupportedMessageDigestAlgorithm::SHA3_256 => Oaep::new::<Sha3_256>(),
-                    SupportedMessageDigestAlgorithm::SHA3_384 => Oaep::new::<Sha3_384>(),
-                    SupportedMessageDigestAlgorithm::SHA3_512 => Oaep::new::<Sha3_512>(),
-                };
+                let padding = oaep(message_digest_algorithm.unwrap());
                 pub_key.encrypt(&mut rng, padding, input.as_bytes())
             }
             SupportedEncryptionSchemes::RSA_PKCS1_V1_5 => {
@@ -67,21 +59,6 @@ enum SupportedEncryptionSchemes {
     RSA_PKCS1_V1_5,
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum SupportedMessageDigestAlgorithm {
-    SHA1,
-    SHA2_224,
-    SHA2_256,
-    SHA2_384,
-    SHA2_512,
-    SHA3_224,
-    SHA3_256,
-    SHA3_384,
-    SHA3_512,
-}
-
 #[derive(Deserialize)]
 #[serde(rename_all = "lowercase")]
 enum SupportedOutputFormat {
@@ -94,6 +71,7 @@ enum SupportedOutputFormat {
 struct DeserializeMeDaddy {
     #[serde(rename = "pub_key")]
     public_key: String,
+    passphrase: Option<String>,
     #[serde(rename = "scheme")]
     encrypted_scheme: SupportedEncryptionSchemes,
     #[serde(rename = "digest_alg")]
@@ -101,7 +79,7 @@ struct DeserializeMeDaddy {
     output_format: SupportedOutputFormat,
 }
 
-/// Encrypt a message with a PEM encoded RSA public key.
+/// Encrypt a message with an RSA public key. The key format is detected automatically.
 /// <br><br/>
 /// For more information go [here](https://wikipedia.org/wiki/RSA_(cryptosystem))
 /// <br><br/>
@@ -113,7 +91,8 @@ struct DeserializeMeDaddy {
 /// {
 ///     "input": string,
 ///     "params": {
-///         "pub_key": PEM,
+///         "pub_key": RsaKey,
+///         "passphrase": Option<string>,
 ///         "scheme": SupportedEncryptionSchemes,
 ///         "digest_alg": Option<SupportedMessageDigestAlgorithm>
 ///         "output_format": SupportedOutputFormat
@@ -121,7 +100,8 @@ struct DeserializeMeDaddy {
 /// }
 /// ```
 /// #### where
-///     - PEM is pem encoded RSA public key
+///     - RsaKey is RSA key in PKCS#1, PKCS#8, SPKI PEM, DER (hex or base64), JWK or OpenSSH format, a private key can be used as well
+///     - passphrase is required only for encrypted PKCS#8 private keys
 ///     - SupportedEncryptionSchemes is enum of "oaep" and "pkcs1_v15"
 ///     - Option<SupportedMessageDigestAlgorithm> is optional enum of "sha1", "sha2-224", "sha2-256", "sha2-384", "sha2-512", "sha3-224", "sha3-256", "sha3-384", "sha3-512"
 ///     - SupportedOutputFormat is enum of "hex", "base64", "uint8array"
@@ -205,8 +185,10 @@ struct DeserializeMeDaddy {
 pub struct RSAEncrypt;
 
 const NAME: &str = "RSAEncrypt";
-const DESCRIPTION_EN: &str = "Encrypt a message with a PEM encoded RSA public key.";
-const DESCRIPTION_RU: &str = "Шифрует сообщение с помощью открытого ключа RSA с кодировкой PEM.";
+const DESCRIPTION_EN: &str =
+    "Encrypt a message with an RSA public key. The key format is detected automatically.";
+const DESCRIPTION_RU: &str =
+    "Шифрует сообщение с помощью открытого ключа RSA. Формат ключа определяется автоматически.";
 
 const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/RSA_(cryptosystem)");
 
@@ -0,0 +1,159 @@
+use anyhow::{bail, Result};
+use rsa::{BigUint, RsaPrivateKey};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::rsa_key::{RsaKey, RsaKeyFormat},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_rsageneratekeypair, RSAGenerateKeyPair);
+
+impl Operation<'_, DeserializeMeDaddy> for RSAGenerateKeyPair {
+    fn do_black_magic(&self, _input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            bits,
+            public_exponent,
+            private_key_format,
+            public_key_format,
+            passphrase,
+        } = request;
+
+        let exponent = BigUint::from(public_exponent.unwrap_or(65537));
+        let key: RsaKey =
+            RsaPrivateKey::new_with_exp(&mut rand::thread_rng(), bits, &exponent)?.into();
+
+        let private_key = key.encode(private_key_format, passphrase.as_deref())?;
+        let public_key = RsaKey::Public(key.public_key()).encode(public_key_format, None)?;
+
+        Ok(format!(
+            "{}\n{}",
+            private_key.trim_end(),
+            public_key.trim_end()
+        ))
+    }
+
+    fn validate(&self, request: &'_ str) -> Result<DeserializeMeDaddy> {
+        let request = self.deserialize(request)?;
+
+        if !(512..=8192).contains(&request.bits) {
+            bail!("Key size must be from 512 to 8192 bits.");
+        }
+
+        if let Some(exponent) = request.public_exponent {
+            if exponent < 3 || exponent % 2 == 0 {
+                bail!("Public exponent must be an odd number greater than 1.");
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    bits: usize,
+    public_exponent: Option<u32>,
+    private_key_format: RsaKeyFormat,
+    public_key_format: RsaKeyFormat,
+    passphrase: Option<String>,
+}
+
+/// Generates a new RSA key pair. The private key is printed first, followed by the public key.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/RSA_(cryptosystem)#Key_generation).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RSAGenerateKeyPair with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "bits": usize,
+///         "public_exponent": Option<u32>,
+///         "private_key_format": RsaKeyFormat,
+///         "public_key_format": RsaKeyFormat,
+///         "passphrase": Option<string>
+///     }
+/// }
+/// ```
+/// #### where
+///     - bits is key size from 512 to 8192
+///     - Option<u32> is odd public exponent or null (65537 by default)
+///     - RsaKeyFormat is enum of "pkcs1_pem", "pkcs1_der", "pkcs8_pem", "pkcs8_der", "pkcs8_encrypted_pem", "pkcs8_encrypted_der", "spki_pem", "spki_der", "jwk", "openssh"
+///     - public key can be written only as "pkcs1_pem", "pkcs1_der", "spki_pem", "spki_der", "jwk" or "openssh"
+///     - DER encoded keys are returned as hex
+///     - passphrase is required only for "pkcs8_encrypted_pem" and "pkcs8_encrypted_der"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RSAGenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "bits": 512,
+///         "private_key_format": "jwk",
+///         "public_key_format": "openssh"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "{\"kty\":\"RSA\",\"n\":\"sE53uwBdSmewIcoVflthOuXBit9BzlcO8bu8OCwcRFfQVbQT3n_qel5P6KPugXwWO0gSbj33gDNJxZRquBk9KQ\",\"e\":\"AQAB\",\"d\":\"OyFWDqXEpBu6etqn8sT7CyHbk5PlIisYnAJ20fAZWuH6J6npJ95hYUGCDymrgODONi7mrxNr4OasyUekLz3qMQ\",\"p\":\"3F2dbyBQ0pJLoNJx5QuTWFPtGblyGozPmVTO7xWLGuc\",\"q\":\"zND0NKHFUxPJCV21_TmTB-WeX7xFv7yGnES9r4v7dW8\",\"dp\":\"qvYF2dzcV0EgY1hg_C2K1o_l9nskxuxPcsmU8W9pSCk\",\"dq\":\"X6GQ5KJECC5Mx9rBhqLDTASilgDKcGQOVhiimLy7hHE\",\"qi\":\"zRE1pBOINcUUKWbLwuEtMXuf0VnrDzVpIAWY6pGU79w\"}\nssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAAAQQCwTne7AF1KZ7AhyhV+W2E65cGK30HOVw7xu7w4LBxEV9BVtBPef+p6Xk/oo+6BfBY7SBJuPfeAM0nFlGq4GT0p"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RSAGenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "bits": 2048,
+///         "private_key_format": "pkcs1_pem",
+///         "public_key_format": "pkcs8_pem"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "This key format can hold only a private key."
+/// }
+/// ```
+pub struct RSAGenerateKeyPair;
+
+const NAME: &str = "RSAGenerateKeyPair";
+const DESCRIPTION_EN: &str =
+    "Generates a new RSA key pair. The private key is printed first, followed by the public key.";
+const DESCRIPTION_RU: &str =
+    "Генерирует новую пару ключей RSA. Сначала выводится закрытый ключ, затем открытый.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/RSA_(cryptosystem)#Key_generation");
+
+create_info_struct!(
+    RSAGenerateKeyPairInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
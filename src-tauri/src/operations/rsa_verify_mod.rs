@@ -0,0 +1,209 @@
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{decode_input, InputFormat},
+        rsa_key::RsaKey,
+        rsa_padding::{pkcs1v15_sign, Pss, SignatureScheme},
+    },
+    run_op,
+    utils::SupportedMessageDigestAlgorithm,
+    Operation, DOCS_URL,
+};
+
+run_op!(run_rsaverify, RSAVerify);
+
+impl Operation<'_, DeserializeMeDaddy> for RSAVerify {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            public_key,
+            passphrase,
+            signature,
+            signature_format,
+            scheme,
+            message_digest_algorithm,
+            salt_length,
+            mgf_digest_algorithm,
+        } = request;
+
+        let key = RsaKey::detect(&public_key, passphrase.as_deref())?.public_key();
+        let signature = decode_input(&signature, signature_format)?;
+
+        let verified = match scheme {
+            SignatureScheme::Pkcs1V15 => key
+                .verify(
+                    pkcs1v15_sign(message_digest_algorithm),
+                    &message_digest_algorithm.digest(input.as_bytes()),
+                    &signature,
+                )
+                .is_ok(),
+            SignatureScheme::Pss => Pss {
+                digest: message_digest_algorithm,
+                mgf_digest: mgf_digest_algorithm.unwrap_or(message_digest_algorithm),
+                salt_length,
+            }
+            .verify(&key, input.as_bytes(), &signature)?,
+        };
+
+        match verified {
+            true => Ok("Verified OK.".to_string()),
+            false => Err(anyhow!("Verification failure.")),
+        }
+    }
+
+    fn validate(&self, request: &'_ str) -> Result<DeserializeMeDaddy> {
+        let request = self.deserialize(request)?;
+
+        if matches!(request.scheme, SignatureScheme::Pkcs1V15)
+            && (request.salt_length.is_some() || request.mgf_digest_algorithm.is_some())
+        {
+            bail!("Salt length and MGF digest algorithm are used only by PSS scheme.");
+        }
+
+        Ok(request)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    #[serde(rename = "pub_key")]
+    public_key: String,
+    passphrase: Option<String>,
+    signature: String,
+    signature_format: InputFormat,
+    scheme: SignatureScheme,
+    #[serde(rename = "digest_alg")]
+    message_digest_algorithm: SupportedMessageDigestAlgorithm,
+    salt_length: Option<usize>,
+    #[serde(rename = "mgf_digest_alg")]
+    mgf_digest_algorithm: Option<SupportedMessageDigestAlgorithm>,
+}
+
+/// Verifies an RSA signature of a message made with PKCS#1 v1.5 or PSS scheme. The key format is detected automatically.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Probabilistic_signature_scheme).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/RSAVerify with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "pub_key": RsaKey,
+///         "passphrase": Option<string>,
+///         "signature": string,
+///         "signature_format": InputFormat,
+///         "scheme": SignatureScheme,
+///         "digest_alg": SupportedMessageDigestAlgorithm,
+///         "salt_length": Option<usize>,
+///         "mgf_digest_alg": Option<SupportedMessageDigestAlgorithm>
+///     }
+/// }
+/// ```
+/// #### where
+///     - RsaKey is RSA key in PKCS#1, PKCS#8, SPKI PEM, DER (hex or base64), JWK or OpenSSH format, a private key can be used as well
+///     - passphrase is required only for encrypted PKCS#8 private keys
+///     - InputFormat is enum of "hex", "base64", "raw"
+///     - SignatureScheme is enum of "pkcs1_v15" and "pss"
+///     - SupportedMessageDigestAlgorithm is enum of "sha1", "sha2_224", "sha2_256", "sha2_384", "sha2_512", "sha3_224", "sha3_256", "sha3_384", "sha3_512"
+///     - salt_length is PSS salt length in bytes, detected from the signature by default
+///     - mgf_digest_alg is digest algorithm for MGF1 in PSS scheme, equal to digest_alg by default
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/RSAVerify
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "pub_key": "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAAAQQCwTne7AF1KZ7AhyhV+W2E65cGK30HOVw7xu7w4LBxEV9BVtBPef+p6Xk/oo+6BfBY7SBJuPfeAM0nFlGq4GT0p",
+///         "signature": "4db6592e4a7a3177b1772375abd975a7325469152366680918cd0126bdabdf43b87d5313410453609b5268f496b51162f7ad2f5d93a8c2e30109c8d4cab10d2d",
+///         "signature_format": "hex",
+///         "scheme": "pkcs1_v15",
+///         "digest_alg": "sha2_256"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Verified OK."
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/RSAVerify
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "pub_key": "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAAAQQCwTne7AF1KZ7AhyhV+W2E65cGK30HOVw7xu7w4LBxEV9BVtBPef+p6Xk/oo+6BfBY7SBJuPfeAM0nFlGq4GT0p",
+///         "signature": "M7JNoPFrWmHdSJ51bxQ91amUem8FXZ53Q/eNa4lI/NET2vDBg9MMYeHeejC+FIYUEB1DLfBqA5702aUXjJQe4g==",
+///         "signature_format": "base64",
+///         "scheme": "pss",
+///         "digest_alg": "sha2_256",
+///         "salt_length": 20,
+///         "mgf_digest_alg": "sha1"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Verified OK."
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/RSAVerify
+///
+/// {
+///     "input": "hello!",
+///     "params": {
+///         "pub_key": "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAAAQQCwTne7AF1KZ7AhyhV+W2E65cGK30HOVw7xu7w4LBxEV9BVtBPef+p6Xk/oo+6BfBY7SBJuPfeAM0nFlGq4GT0p",
+///         "signature": "4db6592e4a7a3177b1772375abd975a7325469152366680918cd0126bdabdf43b87d5313410453609b5268f496b51162f7ad2f5d93a8c2e30109c8d4cab10d2d",
+///         "signature_format": "hex",
+///         "scheme": "pkcs1_v15",
+///         "digest_alg": "sha2_256"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Verification failure."
+/// }
+/// ```
+pub struct RSAVerify;
+
+const NAME: &str = "RSAVerify";
+const DESCRIPTION_EN: &str = "Verifies an RSA signature of a message made with PKCS#1 v1.5 or PSS scheme. The key format is detected automatically.";
+const DESCRIPTION_RU: &str = "Проверяет подпись RSA сообщения, созданную по схеме PKCS#1 v1.5 или PSS. Формат ключа определяется автоматически.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Probabilistic_signature_scheme");
+
+create_info_struct!(
+    RSAVerifyInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,125 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{scytale_order, untranspose},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_scytalecipherdecode, ScytaleCipherDecode);
+
+impl Operation<'_, DeserializeMeDaddy> for ScytaleCipherDecode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let diameter = request.diameter;
+        let len = input.chars().count();
+
+        Ok(untranspose(input, &scytale_order(len, diameter)?))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    diameter: usize,
+}
+
+/// Decodes text encrypted with the Scytale by winding it back around a rod of the same diameter.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Scytale).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ScytaleCipherDecode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "diameter": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - diameter is number of characters that fit around the rod, at most the input length
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ScytaleCipherDecode
+///
+/// {
+///     "input": "IRYYATBHMVAEHEDLURLP",
+///     "params": {
+///         "diameter": 4
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "IAMHURTVERYBADLYHELP"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ScytaleCipherDecode
+///
+/// {
+///     "input": "Шлаиьлфнорачоякв аап",
+///     "params": {
+///         "diameter": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Шифровальная палочка"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ScytaleCipherDecode
+///
+/// {
+///     "input": "IRYYATBHMVAEHEDLURLP",
+///     "params": {
+///         "diameter": "4"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid type: string \"4\", expected usize."
+/// }
+/// ```
+pub struct ScytaleCipherDecode;
+
+const NAME: &str = "ScytaleCipherDecode";
+const DESCRIPTION_EN: &str =
+    "Decodes text encrypted with the Scytale by winding it back around a rod of the same diameter.";
+const DESCRIPTION_RU: &str = "Расшифровывает текст, зашифрованный скиталой, наматывая его обратно на стержень того же диаметра.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Scytale");
+
+create_info_struct!(
+    ScytaleCipherDecodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
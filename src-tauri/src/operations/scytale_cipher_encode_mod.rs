@@ -0,0 +1,124 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::transposition::{scytale_order, transpose},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_scytalecipherencode, ScytaleCipherEncode);
+
+impl Operation<'_, DeserializeMeDaddy> for ScytaleCipherEncode {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let diameter = request.diameter;
+        let len = input.chars().count();
+
+        Ok(transpose(input, &scytale_order(len, diameter)?))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    diameter: usize,
+}
+
+/// The Scytale is a transposition cipher of ancient Greece: a strip of parchment wound around a rod of a given diameter is written along the rod, so the unwound strip holds the text read from a grid of diameter rows column by column.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Scytale).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/ScytaleCipherEncode with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "diameter": usize
+///     }
+/// }
+/// ```
+/// #### where
+///     - diameter is number of characters that fit around the rod, at most the input length
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/ScytaleCipherEncode
+///
+/// {
+///     "input": "IAMHURTVERYBADLYHELP",
+///     "params": {
+///         "diameter": 4
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "IRYYATBHMVAEHEDLURLP"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/ScytaleCipherEncode
+///
+/// {
+///     "input": "Шифровальная палочка",
+///     "params": {
+///         "diameter": 3
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Шлаиьлфнорачоякв аап"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/ScytaleCipherEncode
+///
+/// {
+///     "input": "IAMHURTVERYBADLYHELP",
+///     "params": {
+///         "diameter": 0
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Diameter must be a positive number."
+/// }
+/// ```
+pub struct ScytaleCipherEncode;
+
+const NAME: &str = "ScytaleCipherEncode";
+const DESCRIPTION_EN: &str = "The Scytale is a transposition cipher of ancient Greece: a strip of parchment wound around a rod of a given diameter is written along the rod, so the unwound strip holds the text read from a grid of diameter rows column by column.";
+const DESCRIPTION_RU: &str = "Скитала — шифр перестановки Древней Греции: на полоску пергамента, намотанную на стержень заданного диаметра, текст пишется вдоль стержня, поэтому размотанная полоска содержит текст, прочитанный по столбцам из таблицы с числом строк, равным диаметру.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Scytale");
+
+create_info_struct!(
+    ScytaleCipherEncodeInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,160 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::block_cipher::{
+        BlockCipher, BlockCipherFunction, InputFormat, Mode, OutputFormat, Padding,
+    },
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_serpentdecrypt, SerpentDecrypt);
+
+impl Operation<'_, DeserializeMeDaddy> for SerpentDecrypt {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            key,
+            key_format,
+            iv,
+            iv_format,
+            mode,
+            padding,
+            input_format,
+            output_format,
+        } = request;
+
+        let cipher = BlockCipher::new(BlockCipherFunction::Serpent)
+            .set_input(input, input_format)?
+            .set_iv(&convert_to_byte_array(&iv, &iv_format)?)?
+            .set_key(&convert_to_byte_array(&key, &key_format)?)?
+            .set_mode(mode)
+            .set_padding(padding);
+
+        cipher.decrypt(output_format)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    key_format: SupportedFormats,
+    iv: String,
+    iv_format: SupportedFormats,
+    mode: Mode,
+    padding: Padding,
+    input_format: InputFormat,
+    output_format: OutputFormat,
+}
+
+/// Serpent is a symmetric key block cipher with a block size of 128 bits and key sizes up to 256 bits. It was a finalist in the Advanced Encryption Standard contest, where it was ranked second to Rijndael.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Serpent_(cipher)).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/SerpentDecrypt with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "iv": string,
+///         "iv_format": SupportedFormats,
+///         "mode": Mode,
+///         "padding": Padding,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Mode is enum of "CBC", "CTR", "OFB", "CFB", "CFB8", "ECB", "XTS"
+///     - Padding is enum of "pkcs7", "iso7816", "x923", "iso10126" (alias "random"), "zero", "none" (used only in "CBC" and "ECB" modes)
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw"
+///     - key must be up to 32 bytes long, shorter keys are padded as described in the specification
+///     - iv must be 16 bytes long, it is ignored in "ECB" mode
+///     - in "XTS" mode key is a concatenation of data and tweak keys and iv is the tweak
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/SerpentDecrypt
+///
+/// {
+///     "input": "264e5481eff42a4606abda06c0bfda3d",
+///     "params": {
+///         "key": "80000000000000000000000000000000",
+///         "key_format": "hex",
+///         "iv": "",
+///         "iv_format": "hex",
+///         "mode": "ECB",
+///         "padding": "none",
+///         "input_format": "hex",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "00000000000000000000000000000000"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/SerpentDecrypt
+///
+/// {
+///     "input": "264e5481eff42a4606abda06c0bfda3d",
+///     "params": {
+///         "key": "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff00",
+///         "key_format": "hex",
+///         "iv": "",
+///         "iv_format": "hex",
+///         "mode": "ECB",
+///         "padding": "none",
+///         "input_format": "hex",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid key length for Serpent: expected up to 32 bytes, got 33 bytes."
+/// }
+/// ```
+pub struct SerpentDecrypt;
+
+const NAME: &str = "SerpentDecrypt";
+const DESCRIPTION_EN: &str = "Serpent is a symmetric key block cipher with a block size of 128 bits and key sizes up to 256 bits. It was a finalist in the Advanced Encryption Standard contest, where it was ranked second to Rijndael.";
+const DESCRIPTION_RU: &str = "Serpent — симметричный блочный шифр с размером блока 128 бит и размером ключа до 256 бит. Он был финалистом конкурса Advanced Encryption Standard, где занял второе место после Rijndael.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Serpent_(cipher)");
+
+create_info_struct!(
+    SerpentDecryptInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,225 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::{create_info_struct, run_op, Operation, DOCS_URL};
+
+run_op!(run_substitute, Substitute);
+
+impl Operation<'_, DeserializeMeDaddy> for Substitute {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            plaintext,
+            ciphertext,
+            ignore_case,
+        } = request;
+
+        if plaintext.chars().count() != ciphertext.chars().count() {
+            bail!("Plaintext and ciphertext alphabets must be of the same length.");
+        }
+
+        let fold = |ch: char| match ignore_case {
+            true => ch.to_lowercase().next().unwrap(),
+            false => ch,
+        };
+
+        let map: HashMap<char, char> = plaintext
+            .chars()
+            .map(fold)
+            .zip(ciphertext.chars())
+            .collect();
+        if map.len() != plaintext.chars().count() {
+            bail!("Plaintext alphabet must not contain repeated characters.");
+        }
+
+        let mut output = String::with_capacity(input.len());
+        for ch in input.chars() {
+            match map.get(&fold(ch)) {
+                Some(&sub) if ignore_case && ch.is_uppercase() => output.extend(sub.to_uppercase()),
+                Some(&sub) if ignore_case && ch.is_lowercase() => output.extend(sub.to_lowercase()),
+                Some(&sub) => output.push(sub),
+                None => output.push(ch),
+            }
+        }
+
+        Ok(output)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    plaintext: String,
+    ciphertext: String,
+    ignore_case: bool,
+}
+
+/// A substitution cipher replaces every character of the plaintext alphabet with the character at the same position of the ciphertext alphabet. Alphabets are arbitrary strings of any characters, including Cyrillic letters and symbols, characters missing from the plaintext alphabet are left as is. To decode swap the alphabets.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Substitution_cipher).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Substitute with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "plaintext": string,
+///         "ciphertext": string,
+///         "ignore_case": bool
+///     }
+/// }
+/// ```
+/// #### where
+///     - plaintext and ciphertext are alphabets of the same length
+///     - ignore_case matches characters regardless of their case and keeps the case of the input
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Substitute
+///
+/// {
+///     "input": "Flee at once. We are discovered!",
+///     "params": {
+///         "plaintext": "abcdefghijklmnopqrstuvwxyz",
+///         "ciphertext": "zebrascdfghijklmnopqtuvwxy",
+///         "ignore_case": true
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "Siaa zq lkba. Va zoa rfpbluaoar!"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Substitute
+///
+/// {
+///     "input": "Привет, мир. Съешь ещё",
+///     "params": {
+///         "plaintext": "абвгдеёжзийклмнопрстуфхцчшщъыьэюя",
+///         "ciphertext": "ΑΒΓΔΕΖΗΘΙΚΛΜΝΞΟΠΡΣΤΥΦΧΨΩ!@#$%^&*+",
+///         "ignore_case": false
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ПΣΚΓΖΥ, ΞΚΣ. С$Ζ@^ Ζ#Η"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Substitute
+///
+/// {
+///     "input": "hello",
+///     "params": {
+///         "plaintext": "abc",
+///         "ciphertext": "xy",
+///         "ignore_case": false
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Plaintext and ciphertext alphabets must be of the same length."
+/// }
+/// ```
+pub struct Substitute;
+
+const NAME: &str = "Substitute";
+const DESCRIPTION_EN: &str = "A substitution cipher replaces every character of the plaintext alphabet with the character at the same position of the ciphertext alphabet. Alphabets are arbitrary strings of any characters, including Cyrillic letters and symbols, characters missing from the plaintext alphabet are left as is. To decode swap the alphabets.";
+const DESCRIPTION_RU: &str = "Шифр простой замены заменяет каждый символ алфавита открытого текста символом на той же позиции алфавита шифртекста. Алфавиты — это произвольные строки из любых символов, включая кириллицу и знаки, символы, отсутствующие в алфавите открытого текста, остаются без изменений. Для расшифровки поменяйте алфавиты местами.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Substitution_cipher");
+
+create_info_struct!(
+    SubstituteInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operations::{run_generatekeywordalphabet, GenerateKeywordAlphabet};
+
+    fn keyword_alphabet(keyword: &str, lang: &str) -> String {
+        let request = format!(r#"{{"lang": "{}"}}"#, lang);
+        run_generatekeywordalphabet(GenerateKeywordAlphabet, keyword, &request).unwrap()
+    }
+
+    fn substitute(input: &str, plaintext: &str, ciphertext: &str, ignore_case: bool) -> String {
+        let request = format!(
+            r#"{{"plaintext": "{}", "ciphertext": "{}", "ignore_case": {}}}"#,
+            plaintext, ciphertext, ignore_case
+        );
+        run_substitute(Substitute, input, &request).unwrap()
+    }
+
+    fn round_trip(input: &str, plaintext: &str, ciphertext: &str, ignore_case: bool) -> String {
+        let encoded = substitute(input, plaintext, ciphertext, ignore_case);
+        assert_ne!(encoded, input);
+        substitute(&encoded, ciphertext, plaintext, ignore_case)
+    }
+
+    #[test]
+    fn keyword_alphabet_substitution() {
+        let alphabet = keyword_alphabet("zebras", "en");
+        assert_eq!(alphabet, "zebrascdfghijklmnopqtuvwxy");
+        assert_eq!(
+            substitute(
+                "Flee at once. We are discovered!",
+                "abcdefghijklmnopqrstuvwxyz",
+                &alphabet,
+                true
+            ),
+            "Siaa zq lkba. Va zoa rfpbluaoar!"
+        );
+    }
+
+    #[test]
+    fn round_trip_en() {
+        let plaintext = "abcdefghijklmnopqrstuvwxyz";
+        let alphabet = keyword_alphabet("Playfair Example", "en");
+        let input = "Flee at once, 42 Jolly Rogers!";
+        assert_eq!(round_trip(input, plaintext, &alphabet, true), input);
+
+        let plaintext = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+        let ciphertext = "QWERTYUIOPASDFGHJKLZXCVBNMqwertyuiopasdfghjklzxcvbnm9876543210";
+        assert_eq!(round_trip(input, plaintext, ciphertext, false), input);
+    }
+
+    #[test]
+    fn round_trip_ru() {
+        let plaintext = "абвгдеёжзийклмнопрстуфхцчшщъыьэюя";
+        let alphabet = keyword_alphabet("шифровка", "ru_with_yo");
+        assert_eq!(alphabet.chars().count(), 33);
+        let input = "Съешь же ещё этих мягких французских булок, 42 раза!";
+        assert_eq!(round_trip(input, plaintext, &alphabet, true), input);
+    }
+}
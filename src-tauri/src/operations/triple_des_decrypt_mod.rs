@@ -0,0 +1,160 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::block_cipher::{
+        BlockCipher, BlockCipherFunction, InputFormat, Mode, OutputFormat, Padding,
+    },
+    run_op,
+    utils::{convert_to_byte_array, SupportedFormats},
+    Operation, DOCS_URL,
+};
+
+run_op!(run_tripledesdecrypt, TripleDESDecrypt);
+
+impl Operation<'_, DeserializeMeDaddy> for TripleDESDecrypt {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            key,
+            key_format,
+            iv,
+            iv_format,
+            mode,
+            padding,
+            input_format,
+            output_format,
+        } = request;
+
+        let cipher = BlockCipher::new(BlockCipherFunction::TripleDES)
+            .set_input(input, input_format)?
+            .set_iv(&convert_to_byte_array(&iv, &iv_format)?)?
+            .set_key(&convert_to_byte_array(&key, &key_format)?)?
+            .set_mode(mode)
+            .set_padding(padding);
+
+        cipher.decrypt(output_format)
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    key: String,
+    key_format: SupportedFormats,
+    iv: String,
+    iv_format: SupportedFormats,
+    mode: Mode,
+    padding: Padding,
+    input_format: InputFormat,
+    output_format: OutputFormat,
+}
+
+/// Triple DES applies DES three times to each block to increase key size. A 16 bytes key selects two-key (EDE2) and a 24 bytes key selects three-key (EDE3) variant.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Triple_DES).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/TripleDESDecrypt with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "key": string,
+///         "key_format": SupportedFormats,
+///         "iv": string,
+///         "iv_format": SupportedFormats,
+///         "mode": Mode,
+///         "padding": Padding,
+///         "input_format": InputFormat,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - SupportedFormats is enum of "binary", "utf8", "hex", "base64", "latin1"
+///     - Mode is enum of "CBC", "CTR", "OFB", "CFB", "CFB8", "ECB", "XTS"
+///     - Padding is enum of "pkcs7", "iso7816", "x923", "iso10126" (alias "random"), "zero", "none" (used only in "CBC" and "ECB" modes)
+///     - InputFormat and OutputFormat are enums of "hex", "base64", "raw"
+///     - key must be 16 bytes (EDE2) or 24 bytes (EDE3) long
+///     - iv must be 8 bytes long, it is ignored in "ECB" mode
+///     - "XTS" mode is not supported for 64-bit block ciphers
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/TripleDESDecrypt
+///
+/// {
+///     "input": "e8cdc44f3feb907a41a3659ac972603f",
+///     "params": {
+///         "key": "0123456789abcdeffedcba987654321089abcdef01234567",
+///         "key_format": "hex",
+///         "iv": "1122334455667788",
+///         "iv_format": "hex",
+///         "mode": "CBC",
+///         "padding": "pkcs7",
+///         "input_format": "hex",
+///         "output_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "hello world"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/TripleDESDecrypt
+///
+/// {
+///     "input": "e8cdc44f3feb907a41a3659ac972603f",
+///     "params": {
+///         "key": "0123456789abcdef",
+///         "key_format": "hex",
+///         "iv": "1122334455667788",
+///         "iv_format": "hex",
+///         "mode": "CBC",
+///         "padding": "pkcs7",
+///         "input_format": "hex",
+///         "output_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Invalid key length for TripleDES: expected 16 bytes (EDE2) or 24 bytes (EDE3), got 8 bytes."
+/// }
+/// ```
+pub struct TripleDESDecrypt;
+
+const NAME: &str = "TripleDESDecrypt";
+const DESCRIPTION_EN: &str = "Triple DES applies DES three times to each block to increase key size. A 16 bytes key selects two-key (EDE2) and a 24 bytes key selects three-key (EDE3) variant.";
+const DESCRIPTION_RU: &str = "Triple DES применяет DES к каждому блоку три раза, чтобы увеличить размер ключа. Ключ длиной 16 байт выбирает вариант с двумя ключами (EDE2), а ключ длиной 24 байта — вариант с тремя ключами (EDE3).";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Triple_DES");
+
+create_info_struct!(
+    TripleDESDecryptInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,171 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::cipher_machines::typex::{typex, TypexRotor, TypexSettings},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_typex, Typex);
+
+impl Operation<'_, DeserializeMeDaddy> for Typex {
+    fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+
+        let mut machine = typex(TypexSettings {
+            rotors: &request.rotors,
+            reflector: &request.reflector,
+            ring_settings: &request.ring_settings,
+            positions: &request.positions,
+            plugboard: &request.plugboard,
+        })?;
+
+        Ok(machine.encipher(input))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    rotors: Vec<TypexRotor>,
+    reflector: String,
+    ring_settings: String,
+    positions: String,
+    plugboard: String,
+}
+
+/// Typex is the British rotor cipher machine derived from the Enigma. It has five rotors, the two leftmost of them are stators that stay in place, every rotor has several notches and can be inserted reversed, and the plugboard together with the reflector swap the letters in pairs. The wirings of the original rotors were never published, so every rotor is given by its wiring. Thanks to the reflector the machine is reciprocal, so the same settings both encrypt and decrypt. Only the latin letters are enciphered, in upper case, other characters are left as is and don't move the rotors.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Typex).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/Typex with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "rotors": [TypexRotor],
+///         "reflector": string,
+///         "ring_settings": string,
+///         "positions": string,
+///         "plugboard": string
+///     }
+/// }
+/// ```
+/// #### where
+///     - [TypexRotor] is array of five rotors from left to right, the two leftmost of them are stators
+///     - TypexRotor is object with "wiring" string of the letters the alphabet is mapped to, "notches" string of the letters shown in the window when the rotor turns the next one over and "reversed" bool
+///     - reflector is all the 13 pairs of swapped letters separated by spaces
+///     - ring_settings and positions are letters for every rotor from left to right
+///     - plugboard is pairs of swapped letters separated by spaces, like "AB CD"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/Typex
+///
+/// {
+///     "input": "Attack at dawn",
+///     "params": {
+///         "rotors": [
+///             { "wiring": "FKTYWCQXGZAOIVJHPLBDMRNUES", "notches": "CLOUY", "reversed": false },
+///             { "wiring": "XSGFZYWIUVQKBPTDLNOHAJCREM", "notches": "AIKMO", "reversed": true },
+///             { "wiring": "GJZTSIFDEWRBYLUMAXVPKHOQNC", "notches": "DINRX", "reversed": false },
+///             { "wiring": "ISBGFQJATNVMCEODYXLKUZHRWP", "notches": "BEJOY", "reversed": true },
+///             { "wiring": "NVCQMEBAGOIZLFWXRPHUDYSTJK", "notches": "GIKNS", "reversed": false }
+///         ],
+///         "reflector": "JO MK EP QI FA DG CZ BR VS LY TX NU WH",
+///         "ring_settings": "AAAAA",
+///         "positions": "QWERT",
+///         "plugboard": "AB CD"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "CCCSPR IB FICG"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/Typex
+///
+/// {
+///     "input": "CCCSPR IB FICG",
+///     "params": {
+///         "rotors": [
+///             { "wiring": "FKTYWCQXGZAOIVJHPLBDMRNUES", "notches": "CLOUY", "reversed": false },
+///             { "wiring": "XSGFZYWIUVQKBPTDLNOHAJCREM", "notches": "AIKMO", "reversed": true },
+///             { "wiring": "GJZTSIFDEWRBYLUMAXVPKHOQNC", "notches": "DINRX", "reversed": false },
+///             { "wiring": "ISBGFQJATNVMCEODYXLKUZHRWP", "notches": "BEJOY", "reversed": true },
+///             { "wiring": "NVCQMEBAGOIZLFWXRPHUDYSTJK", "notches": "GIKNS", "reversed": false }
+///         ],
+///         "reflector": "JO MK EP QI FA DG CZ BR VS LY TX NU WH",
+///         "ring_settings": "AAAAA",
+///         "positions": "QWERT",
+///         "plugboard": "AB CD"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "ATTACK AT DAWN"
+/// }
+/// ```
+/// ## №3
+/// ``` http
+/// POST /api/Typex
+///
+/// {
+///     "input": "Attack at dawn",
+///     "params": {
+///         "rotors": [
+///             { "wiring": "FKTYWCQXGZAOIVJHPLBDMRNUES", "notches": "CLOUY", "reversed": false },
+///             { "wiring": "XSGFZYWIUVQKBPTDLNOHAJCREM", "notches": "AIKMO", "reversed": false },
+///             { "wiring": "GJZTSIFDEWRBYLUMAXVPKHOQNC", "notches": "DINRX", "reversed": false },
+///             { "wiring": "ISBGFQJATNVMCEODYXLKUZHRWP", "notches": "BEJOY", "reversed": false }
+///         ],
+///         "reflector": "JO MK EP QI FA DG CZ BR VS LY TX NU WH",
+///         "ring_settings": "AAAA",
+///         "positions": "QWER",
+///         "plugboard": ""
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Typex needs 5 rotors with a ring setting and a position for each of them."
+/// }
+/// ```
+pub struct Typex;
+
+const NAME: &str = "Typex";
+const DESCRIPTION_EN: &str = "Typex is the British rotor cipher machine derived from the Enigma. It has five rotors, the two leftmost of them are stators that stay in place, every rotor has several notches and can be inserted reversed, and the plugboard together with the reflector swap the letters in pairs. The wirings of the original rotors were never published, so every rotor is given by its wiring. Thanks to the reflector the machine is reciprocal, so the same settings both encrypt and decrypt. Only the latin letters are enciphered, in upper case, other characters are left as is and don't move the rotors.";
+const DESCRIPTION_RU: &str = "Typex — британская роторная шифровальная машина, созданная на основе Энигмы. У неё пять роторов, два левых из них — неподвижные статоры, у каждого ротора несколько выемок и его можно вставить в обратном направлении, а коммутационная панель вместе с рефлектором меняют буквы местами попарно. Коммутация оригинальных роторов никогда не публиковалась, поэтому каждый ротор задаётся своей коммутацией. Благодаря рефлектору машина взаимна, поэтому одни и те же настройки и шифруют, и расшифровывают. Шифруются только латинские буквы, в верхнем регистре, остальные символы остаются как есть и не поворачивают роторы.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Typex");
+
+create_info_struct!(
+    TypexInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
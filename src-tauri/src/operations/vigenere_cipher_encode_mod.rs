@@ -3,9 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     create_info_struct,
-    libs::vigenere_trait::VigenereCipher,
+    libs::{alphabet::Alphabet, vigenere_trait::VigenereCipher},
     run_op,
-    utils::{add, SupportedLanguages},
+    utils::add,
     Operation, DOCS_URL,
 };
 
@@ -17,13 +17,13 @@ impl Operation<'_, DeserializeMeDaddy> for VigenereCipherEncode {
     fn do_black_magic(&self, input: &str, request: &str) -> Result<String> {
         let request = self.validate(request)?;
         let DeserializeMeDaddy { lang, key } = request;
-        <Self as VigenereCipher>::cipher(lang, &key, input, add)
+        <Self as VigenereCipher>::cipher(&lang, &key, input, add)
     }
 }
 
 #[derive(Deserialize)]
 struct DeserializeMeDaddy {
-    lang: SupportedLanguages,
+    lang: Alphabet,
     key: String,
 }
 
@@ -39,13 +39,13 @@ struct DeserializeMeDaddy {
 /// {
 ///     "input": string,
 ///     "params": {
-///         "lang": SupportedLanguages,
+///         "lang": Alphabet,
 ///         "key": String
 ///     }
 /// }
 /// ```
 /// #### where
-///     - SupportedLanguages is enum of "en", "ru", "ru_with_yo".
+///     - Alphabet is one of the presets "en", "ru", "ru_with_yo", "uk", "de" or a custom string of the alphabet symbols.
 /// <br/><br/>
 ///
 /// ### Server response have two possible formats
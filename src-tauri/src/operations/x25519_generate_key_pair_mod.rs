@@ -0,0 +1,124 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    create_info_struct,
+    libs::curve25519_key::{Curve25519Algorithm, Curve25519Key, Curve25519KeyFormat},
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_x25519generatekeypair, X25519GenerateKeyPair);
+
+impl Operation<'_, DeserializeMeDaddy> for X25519GenerateKeyPair {
+    fn do_black_magic(&self, _input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            private_key_format,
+            public_key_format,
+        } = request;
+
+        let key = Curve25519Key::generate(Curve25519Algorithm::X25519);
+
+        Ok(format!(
+            "{}\n{}",
+            key.encode_private(private_key_format)?.trim_end(),
+            key.encode_public(public_key_format)?.trim_end()
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    private_key_format: Curve25519KeyFormat,
+    public_key_format: Curve25519KeyFormat,
+}
+
+/// Generates a new X25519 key pair. The private key is printed first, followed by the public key.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Curve25519).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/X25519GenerateKeyPair with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "private_key_format": Curve25519KeyFormat,
+///         "public_key_format": Curve25519KeyFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - Curve25519KeyFormat is enum of "raw", "pem", "openssh"
+///     - "raw" stands for hex encoded 32 byte key
+///     - "pem" stands for PKCS#8 private key and SPKI public key
+///     - "openssh" is not available for X25519 keys
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/X25519GenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "private_key_format": "raw",
+///         "public_key_format": "raw"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a\n8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/X25519GenerateKeyPair
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "private_key_format": "pem",
+///         "public_key_format": "openssh"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "OpenSSH format is defined only for Ed25519 keys."
+/// }
+/// ```
+pub struct X25519GenerateKeyPair;
+
+const NAME: &str = "X25519GenerateKeyPair";
+const DESCRIPTION_EN: &str =
+    "Generates a new X25519 key pair. The private key is printed first, followed by the public key.";
+const DESCRIPTION_RU: &str =
+    "Генерирует новую пару ключей X25519. Сначала выводится закрытый ключ, затем открытый.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Curve25519");
+
+create_info_struct!(
+    X25519GenerateKeyPairInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
@@ -0,0 +1,169 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+use crate::{
+    create_info_struct,
+    libs::{
+        block_cipher::{encode_output, OutputFormat},
+        curve25519_key::{Curve25519Algorithm, Curve25519Key},
+    },
+    run_op, Operation, DOCS_URL,
+};
+
+run_op!(run_x25519sharedsecret, X25519SharedSecret);
+
+impl Operation<'_, DeserializeMeDaddy> for X25519SharedSecret {
+    fn do_black_magic(&self, _input: &str, request: &str) -> Result<String> {
+        let request = self.validate(request)?;
+        let DeserializeMeDaddy {
+            private_key,
+            public_key,
+            output_format,
+        } = request;
+
+        let private_key = Curve25519Key::parse_private(&private_key, Curve25519Algorithm::X25519)?;
+        let public_key = Curve25519Key::parse_public(&public_key, Curve25519Algorithm::X25519)?;
+
+        let shared_secret = StaticSecret::from(private_key.secret()?)
+            .diffie_hellman(&PublicKey::from(public_key.public()));
+        if !shared_secret.was_contributory() {
+            bail!("Shared secret is all zeros, the public key is a low order point.");
+        }
+
+        Ok(encode_output(shared_secret.as_bytes(), output_format))
+    }
+}
+
+#[derive(Deserialize)]
+struct DeserializeMeDaddy {
+    private_key: String,
+    public_key: String,
+    output_format: OutputFormat,
+}
+
+/// Computes X25519 shared secret from own private key and the public key of the other party.
+/// <br><br/>
+/// For more information go [here](https://wikipedia.org/wiki/Curve25519).
+/// <br><br/>
+///
+/// # How to use
+/// \
+/// Send POST requests to /api/X25519SharedSecret with your data using json payload with this structure.
+/// ``` json
+/// {
+///     "input": string,
+///     "params": {
+///         "private_key": string,
+///         "public_key": string,
+///         "output_format": OutputFormat
+///     }
+/// }
+/// ```
+/// #### where
+///     - private_key is X25519 private key as raw hex or PKCS#8 PEM
+///     - public_key is X25519 public key as raw hex or SPKI PEM
+///     - OutputFormat is enum of "hex", "base64", "raw"
+/// <br/><br/>
+///
+/// ### Server response have two possible formats
+///
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Ok variant
+/// ``` json
+/// { "Ok": `some answer` }
+/// ```
+/// #### &nbsp;&nbsp;&nbsp;&nbsp; Error variant
+/// ``` json
+/// { "Err": `error message` }
+/// ```
+/// # Examples
+/// ## №1
+/// ``` http
+/// POST /api/X25519SharedSecret
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "private_key": "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+///         "public_key": "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 200 Ok
+/// {
+///   "Ok": "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742"
+/// }
+/// ```
+/// ## №2
+/// ``` http
+/// POST /api/X25519SharedSecret
+///
+/// {
+///     "input": "",
+///     "params": {
+///         "private_key": "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+///         "public_key": "0000000000000000000000000000000000000000000000000000000000000000",
+///         "output_format": "hex"
+///     }
+/// }
+/// ```
+/// ```http
+/// HTTP/1.1 400 Bad Request
+/// {
+///   "Err": "Shared secret is all zeros, the public key is a low order point."
+/// }
+/// ```
+pub struct X25519SharedSecret;
+
+const NAME: &str = "X25519SharedSecret";
+const DESCRIPTION_EN: &str =
+    "Computes X25519 shared secret from own private key and the public key of the other party.";
+const DESCRIPTION_RU: &str =
+    "Вычисляет общий секрет X25519 по собственному закрытому ключу и открытому ключу другой стороны.";
+
+const INFO_URL: Option<&str> = Some("https://wikipedia.org/wiki/Curve25519");
+
+create_info_struct!(
+    X25519SharedSecretInfo,
+    NAME,
+    DOCS_URL,
+    DESCRIPTION_EN,
+    DESCRIPTION_RU,
+    INFO_URL
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shared_secret(private_key: &str, public_key: &str) -> Result<String, String> {
+        let request = format!(
+            r#"{{"private_key": "{private_key}", "public_key": "{public_key}", "output_format": "hex"}}"#
+        );
+        run_x25519sharedsecret(X25519SharedSecret, "", &request)
+    }
+
+    #[test]
+    fn rfc7748() {
+        // Section 6.1, both parties get the same secret.
+        let alice = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+        let alice_public = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+        let bob = "5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb";
+        let bob_public = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+        let secret = "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742";
+
+        assert_eq!(shared_secret(alice, bob_public).unwrap(), secret);
+        assert_eq!(shared_secret(bob, alice_public).unwrap(), secret);
+    }
+
+    #[test]
+    fn low_order_point() {
+        let alice = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+        assert_eq!(
+            shared_secret(alice, &"00".repeat(32)).unwrap_err(),
+            "Shared secret is all zeros, the public key is a low order point."
+        );
+    }
+}
@@ -27,10 +27,19 @@ pub fn gsd(mut input: String, ops: Vec<RecipeOperations>) -> Result<String, Stri
             Operations::AffineCipherEncode => {
                 run_affinecipherencode(AffineCipherEncode, &input, &op.request)?
             }
+            Operations::AnalyseBlocks => run_analyseblocks(AnalyseBlocks, &input, &op.request)?,
             Operations::AnalyseHash => run_analysehash(AnalyseHash, &input, &op.request)?,
             Operations::Argon2Compare => run_argon2compare(Argon2Compare, &input, &op.request)?,
             Operations::Argon2 => run_argon2(Argon2, &input, &op.request)?,
+            Operations::ARIADecrypt => run_ariadecrypt(ARIADecrypt, &input, &op.request)?,
+            Operations::ARIAEncrypt => run_ariaencrypt(ARIAEncrypt, &input, &op.request)?,
             Operations::AtbashCipher => run_atbashcipher(AtbashCipher, &input, &op.request)?,
+            Operations::AutokeyCipherDecode => {
+                run_autokeycipherdecode(AutokeyCipherDecode, &input, &op.request)?
+            }
+            Operations::AutokeyCipherEncode => {
+                run_autokeycipherencode(AutokeyCipherEncode, &input, &op.request)?
+            }
             Operations::BaconCipherEncode => {
                 run_baconcipherencode(BaconCipherEncode, &input, &op.request)?
             }
@@ -40,39 +49,203 @@ pub fn gsd(mut input: String, ops: Vec<RecipeOperations>) -> Result<String, Stri
             Operations::BcryptCompare => run_bcryptcompare(BcryptCompare, &input, &op.request)?,
             Operations::Bcrypt => run_bcrypt(Bcrypt, &input, &op.request)?,
             Operations::BcryptParse => run_bcryptparse(BcryptParse, &input, &op.request)?,
+            Operations::BeaufortCipherDecode => {
+                run_beaufortcipherdecode(BeaufortCipherDecode, &input, &op.request)?
+            }
+            Operations::BeaufortCipherEncode => {
+                run_beaufortcipherencode(BeaufortCipherEncode, &input, &op.request)?
+            }
+            Operations::BifidCipherDecode => {
+                run_bifidcipherdecode(BifidCipherDecode, &input, &op.request)?
+            }
             Operations::BifidCipherEncode => {
                 run_bifidcipherencode(BifidCipherEncode, &input, &op.request)?
             }
             Operations::Blake2b => run_blake2b(Blake2b, &input, &op.request)?,
             Operations::Blake2s => run_blake2s(Blake2s, &input, &op.request)?,
+            Operations::BlowfishDecrypt => {
+                run_blowfishdecrypt(BlowfishDecrypt, &input, &op.request)?
+            }
+            Operations::BlowfishEncrypt => {
+                run_blowfishencrypt(BlowfishEncrypt, &input, &op.request)?
+            }
+            Operations::CaesarCipher => run_caesarcipher(CaesarCipher, &input, &op.request)?,
+            Operations::CamelliaDecrypt => {
+                run_camelliadecrypt(CamelliaDecrypt, &input, &op.request)?
+            }
+            Operations::CamelliaEncrypt => {
+                run_camelliaencrypt(CamelliaEncrypt, &input, &op.request)?
+            }
+            Operations::ColumnarTranspositionDecode => {
+                run_columnartranspositiondecode(ColumnarTranspositionDecode, &input, &op.request)?
+            }
+            Operations::ColumnarTranspositionEncode => {
+                run_columnartranspositionencode(ColumnarTranspositionEncode, &input, &op.request)?
+            }
+            Operations::CrackAffineCipher => {
+                run_crackaffinecipher(CrackAffineCipher, &input, &op.request)?
+            }
+            Operations::CrackCaesarCipher => {
+                run_crackcaesarcipher(CrackCaesarCipher, &input, &op.request)?
+            }
+            Operations::CrackHillCipher => {
+                run_crackhillcipher(CrackHillCipher, &input, &op.request)?
+            }
+            Operations::CrackSubstitutionCipher => {
+                run_cracksubstitutioncipher(CrackSubstitutionCipher, &input, &op.request)?
+            }
+            Operations::CrackVigenereCipher => {
+                run_crackvigenerecipher(CrackVigenereCipher, &input, &op.request)?
+            }
+            Operations::DESDecrypt => run_desdecrypt(DESDecrypt, &input, &op.request)?,
+            Operations::DESEncrypt => run_desencrypt(DESEncrypt, &input, &op.request)?,
+            Operations::ECCompressPoint => {
+                run_eccompresspoint(ECCompressPoint, &input, &op.request)?
+            }
+            Operations::ECDecompressPoint => {
+                run_ecdecompresspoint(ECDecompressPoint, &input, &op.request)?
+            }
+            Operations::ECDHSharedSecret => {
+                run_ecdhsharedsecret(ECDHSharedSecret, &input, &op.request)?
+            }
+            Operations::ECDSASign => run_ecdsasign(ECDSASign, &input, &op.request)?,
+            Operations::ECDSAVerify => run_ecdsaverify(ECDSAVerify, &input, &op.request)?,
+            Operations::ECGenerateKeyPair => {
+                run_ecgeneratekeypair(ECGenerateKeyPair, &input, &op.request)?
+            }
+            Operations::Ed25519GenerateKeyPair => {
+                run_ed25519generatekeypair(Ed25519GenerateKeyPair, &input, &op.request)?
+            }
+            Operations::Ed25519Sign => run_ed25519sign(Ed25519Sign, &input, &op.request)?,
+            Operations::Ed25519Verify => run_ed25519verify(Ed25519Verify, &input, &op.request)?,
+            Operations::Enigma => run_enigma(Enigma, &input, &op.request)?,
             Operations::FromBase64 => run_frombase64(FromBase64, &input, &op.request)?,
             Operations::FromBase => run_frombase(FromBase, &input, &op.request)?,
+            Operations::GenerateKeywordAlphabet => {
+                run_generatekeywordalphabet(GenerateKeywordAlphabet, &input, &op.request)?
+            }
+            Operations::GOSTGenerateKeyPair => {
+                run_gostgeneratekeypair(GOSTGenerateKeyPair, &input, &op.request)?
+            }
+            Operations::GOSTSign => run_gostsign(GOSTSign, &input, &op.request)?,
+            Operations::GOSTVerify => run_gostverify(GOSTVerify, &input, &op.request)?,
+            Operations::GronsfeldCipherDecode => {
+                run_gronsfeldcipherdecode(GronsfeldCipherDecode, &input, &op.request)?
+            }
+            Operations::GronsfeldCipherEncode => {
+                run_gronsfeldcipherencode(GronsfeldCipherEncode, &input, &op.request)?
+            }
+            Operations::HillCipherDecode => {
+                run_hillcipherdecode(HillCipherDecode, &input, &op.request)?
+            }
+            Operations::HillCipherEncode => {
+                run_hillcipherencode(HillCipherEncode, &input, &op.request)?
+            }
             Operations::HMAC => run_hmac(HMAC, &input, &op.request)?,
+            Operations::JWTDecode => run_jwtdecode(JWTDecode, &input, &op.request)?,
+            Operations::JWTSign => run_jwtsign(JWTSign, &input, &op.request)?,
+            Operations::JWTVerify => run_jwtverify(JWTVerify, &input, &op.request)?,
             Operations::KuznechikDecrypt => {
                 run_kuznechikdecrypt(KuznechikDecrypt, &input, &op.request)?
             }
             Operations::KuznechikEncrypt => {
                 run_kuznechikencrypt(KuznechikEncrypt, &input, &op.request)?
             }
+            Operations::Lorenz => run_lorenz(Lorenz, &input, &op.request)?,
             Operations::MagmaDecrypt => run_magmadecrypt(MagmaDecrypt, &input, &op.request)?,
             Operations::MagmaEncrypt => run_magmaencrypt(MagmaEncrypt, &input, &op.request)?,
             Operations::MD2 => run_md2(MD2, &input, &op.request)?,
             Operations::MD4 => run_md4(MD4, &input, &op.request)?,
             Operations::MD5 => run_md5(MD5, &input, &op.request)?,
+            Operations::PaddingOracleAttack => {
+                run_paddingoracleattack(PaddingOracleAttack, &input, &op.request)?
+            }
+            Operations::ParseASN1 => run_parseasn1(ParseASN1, &input, &op.request)?,
+            Operations::ParseCSR => run_parsecsr(ParseCSR, &input, &op.request)?,
+            Operations::ParseX509Certificate => {
+                run_parsex509certificate(ParseX509Certificate, &input, &op.request)?
+            }
+            Operations::PGPDecrypt => run_pgpdecrypt(PGPDecrypt, &input, &op.request)?,
+            Operations::PGPEncrypt => run_pgpencrypt(PGPEncrypt, &input, &op.request)?,
+            Operations::PGPGenerateKeyPair => {
+                run_pgpgeneratekeypair(PGPGenerateKeyPair, &input, &op.request)?
+            }
+            Operations::PGPImportKey => run_pgpimportkey(PGPImportKey, &input, &op.request)?,
+            Operations::PGPListPackets => run_pgplistpackets(PGPListPackets, &input, &op.request)?,
+            Operations::PGPSign => run_pgpsign(PGPSign, &input, &op.request)?,
+            Operations::PGPVerify => run_pgpverify(PGPVerify, &input, &op.request)?,
+            Operations::PlayfairCipherDecode => {
+                run_playfaircipherdecode(PlayfairCipherDecode, &input, &op.request)?
+            }
+            Operations::PlayfairCipherEncode => {
+                run_playfaircipherencode(PlayfairCipherEncode, &input, &op.request)?
+            }
+            Operations::PortaCipher => run_portacipher(PortaCipher, &input, &op.request)?,
+            Operations::RailFenceCipherDecode => {
+                run_railfencecipherdecode(RailFenceCipherDecode, &input, &op.request)?
+            }
+            Operations::RailFenceCipherEncode => {
+                run_railfencecipherencode(RailFenceCipherEncode, &input, &op.request)?
+            }
+            Operations::RC2Decrypt => run_rc2decrypt(RC2Decrypt, &input, &op.request)?,
+            Operations::RC2Encrypt => run_rc2encrypt(RC2Encrypt, &input, &op.request)?,
+            Operations::RC4 => run_rc4(RC4, &input, &op.request)?,
+            Operations::RC4Drop => run_rc4drop(RC4Drop, &input, &op.request)?,
+            Operations::ROT13 => run_rot13(ROT13, &input, &op.request)?,
+            Operations::ROT47 => run_rot47(ROT47, &input, &op.request)?,
+            Operations::ROT8000 => run_rot8000(ROT8000, &input, &op.request)?,
+            Operations::RouteCipherDecode => {
+                run_routecipherdecode(RouteCipherDecode, &input, &op.request)?
+            }
+            Operations::RouteCipherEncode => {
+                run_routecipherencode(RouteCipherEncode, &input, &op.request)?
+            }
+            Operations::RSAConvertKey => run_rsaconvertkey(RSAConvertKey, &input, &op.request)?,
             Operations::RSADecrypt => run_rsadecrypt(RSADecrypt, &input, &op.request)?,
             Operations::RSAEncrypt => run_rsaencrypt(RSAEncrypt, &input, &op.request)?,
+            Operations::RSAGenerateKeyPair => {
+                run_rsageneratekeypair(RSAGenerateKeyPair, &input, &op.request)?
+            }
+            Operations::RSASign => run_rsasign(RSASign, &input, &op.request)?,
+            Operations::RSAVerify => run_rsaverify(RSAVerify, &input, &op.request)?,
             Operations::Scrypt => run_scrypt(Scrypt, &input, &op.request)?,
+            Operations::ScytaleCipherDecode => {
+                run_scytalecipherdecode(ScytaleCipherDecode, &input, &op.request)?
+            }
+            Operations::ScytaleCipherEncode => {
+                run_scytalecipherencode(ScytaleCipherEncode, &input, &op.request)?
+            }
+            Operations::SerpentDecrypt => run_serpentdecrypt(SerpentDecrypt, &input, &op.request)?,
+            Operations::SerpentEncrypt => run_serpentencrypt(SerpentEncrypt, &input, &op.request)?,
             Operations::SHA1 => run_sha1(SHA1, &input, &op.request)?,
             Operations::SHA2 => run_sha2(SHA2, &input, &op.request)?,
             Operations::SHA3 => run_sha3(SHA3, &input, &op.request)?,
+            Operations::SM4Decrypt => run_sm4decrypt(SM4Decrypt, &input, &op.request)?,
+            Operations::SM4Encrypt => run_sm4encrypt(SM4Encrypt, &input, &op.request)?,
+            Operations::Substitute => run_substitute(Substitute, &input, &op.request)?,
             Operations::ToBase64 => run_tobase64(ToBase64, &input, &op.request)?,
             Operations::ToBase => run_tobase(ToBase, &input, &op.request)?,
+            Operations::TripleDESDecrypt => {
+                run_tripledesdecrypt(TripleDESDecrypt, &input, &op.request)?
+            }
+            Operations::TripleDESEncrypt => {
+                run_tripledesencrypt(TripleDESEncrypt, &input, &op.request)?
+            }
+            Operations::TwofishDecrypt => run_twofishdecrypt(TwofishDecrypt, &input, &op.request)?,
+            Operations::TwofishEncrypt => run_twofishencrypt(TwofishEncrypt, &input, &op.request)?,
+            Operations::Typex => run_typex(Typex, &input, &op.request)?,
             Operations::VigenereCipherDecode => {
                 run_vigenerecipherdecode(VigenereCipherDecode, &input, &op.request)?
             }
             Operations::VigenereCipherEncode => {
                 run_vigenerecipherencode(VigenereCipherEncode, &input, &op.request)?
             }
+            Operations::X25519GenerateKeyPair => {
+                run_x25519generatekeypair(X25519GenerateKeyPair, &input, &op.request)?
+            }
+            Operations::X25519SharedSecret => {
+                run_x25519sharedsecret(X25519SharedSecret, &input, &op.request)?
+            }
         };
     }
 
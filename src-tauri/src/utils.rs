@@ -1,14 +1,20 @@
-use crate::{libs::base64::from_base64, map, regex_check};
+use crate::{libs::base64::from_base64, map};
 use anyhow::{bail, Result};
+use digest::DynDigest;
 use lazy_static::lazy_static;
 use num::{Integer, ToPrimitive};
 use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Sha224, Sha256, Sha384, Sha512};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
 use std::{
     collections::HashMap,
     fmt::{Debug, LowerHex},
 };
 
-#[derive(Deserialize)]
+/// Languages with the letter statistics used to crack classical ciphers, the ciphers themselves
+/// take an [`Alphabet`](crate::libs::alphabet::Alphabet).
+#[derive(Deserialize, Copy, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum SupportedLanguages {
     EN,
@@ -41,37 +47,49 @@ pub enum SupportedDelimiter {
     Colon,
 }
 
+#[allow(non_camel_case_types)]
+#[derive(Deserialize, Copy, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum SupportedMessageDigestAlgorithm {
+    SHA1,
+    SHA2_224,
+    SHA2_256,
+    SHA2_384,
+    SHA2_512,
+    SHA3_224,
+    SHA3_256,
+    SHA3_384,
+    SHA3_512,
+}
+
+impl SupportedMessageDigestAlgorithm {
+    pub fn hasher(&self) -> Box<dyn DynDigest> {
+        match self {
+            Self::SHA1 => Box::<Sha1>::default(),
+            Self::SHA2_224 => Box::<Sha224>::default(),
+            Self::SHA2_256 => Box::<Sha256>::default(),
+            Self::SHA2_384 => Box::<Sha384>::default(),
+            Self::SHA2_512 => Box::<Sha512>::default(),
+            Self::SHA3_224 => Box::<Sha3_224>::default(),
+            Self::SHA3_256 => Box::<Sha3_256>::default(),
+            Self::SHA3_384 => Box::<Sha3_384>::default(),
+            Self::SHA3_512 => Box::<Sha3_512>::default(),
+        }
+    }
+
+    pub fn digest(&self, data: &[u8]) -> Vec<u8> {
+        let mut hasher = self.hasher();
+        hasher.update(data);
+        hasher.finalize().to_vec()
+    }
+}
+
 impl std::fmt::Display for SupportedDelimiter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
-pub const EN_ALP: (&str, &str, &str, &str, u8, &str) = (
-    "abcdefghijklmnopqrstuvwxyz",
-    "ABCDEFGHIJKLMNOPQRSTUVWXYZ",
-    "abcdefghiklmnopqrstuvwxyz",
-    "ABCDEFGHIKLMNOPQRSTUVWXYZ",
-    26,
-    r"^[a-zA-Z]+$",
-);
-pub const RU_ALP: (&str, &str, &str, &str, u8, &str) = (
-    "абвгдежзийклмнопрстуфхцчшщъыьэюя",
-    "АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
-    "абвгдежзийклмнопрстуфхцчшщъыьэюя,.-=",
-    "АБВГДЕЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ,.-=",
-    32,
-    "^[а-яА-Я]+$",
-);
-pub const RU_ALP_WITH_YO: (&str, &str, &str, &str, u8, &str) = (
-    "абвгдеёжзийклмнопрстуфхцчшщъыьэюя",
-    "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ",
-    "абвгдеёжзийклмнопрстуфхцчшщъыьэюя,.-",
-    "АБВГДЕЁЖЗИЙКЛМНОПРСТУФХЦЧШЩЪЫЬЭЮЯ,.-",
-    33,
-    r"^[а-яА-ЯёЁ]+$",
-);
-
 lazy_static! {
     static ref CHAR_REPR: HashMap<&'static str, &'static str> = map!("Space" => " ",
         "Percent" => "%",
@@ -131,38 +149,39 @@ pub fn from_hex(data: &str) -> Result<Vec<u8>> {
     ))?)
 }
 
-pub fn validate_lang(text: &str, lang: &SupportedLanguages) -> bool {
-    let re = match lang {
-        SupportedLanguages::EN => r"^[a-zA-Z\p{P}\s\d]+$",
-        SupportedLanguages::RU => r"^[а-яА-Я\p{P}\s\d]+$",
-        SupportedLanguages::RU_WITH_YO => r"^[а-яА-ЯёЁ\p{P}\s\d]+$",
+/// Accepts DER bytes written as PEM, base64 or hex. Base64 is taken first when it decodes to a
+/// SEQUENCE like keys and certificates do, a hex SEQUENCE can't be mistaken for it because base64
+/// of the 0x30 tag starts with `M`. Other input is read as hex when it has only hex digits.
+pub fn decode_der(input: &str) -> Result<Vec<u8>> {
+    let input: String = match pem_label(input.trim_start()) {
+        Some(_) => input
+            .lines()
+            .filter(|line| !line.starts_with("-----") && !line.contains(':'))
+            .collect(),
+        None => input.to_string(),
     };
-    regex_check!(re => text)
-}
-
-pub fn get_alphabet(
-    lang: &SupportedLanguages,
-) -> (
-    &'static str,
-    &'static str,
-    &'static str,
-    &'static str,
-    u8,
-    &'static str,
-) {
-    match lang {
-        SupportedLanguages::EN => EN_ALP,
-        SupportedLanguages::RU => RU_ALP,
-        SupportedLanguages::RU_WITH_YO => RU_ALP_WITH_YO,
+    let input: String = input.chars().filter(|x| !x.is_whitespace()).collect();
+
+    if let Ok(der) = from_base64(&input) {
+        if der.first() == Some(&0x30) {
+            return Ok(der);
+        }
+    }
+    match input.chars().all(|x| x.is_ascii_hexdigit()) {
+        true => from_hex(&input),
+        false => from_base64(&input),
     }
 }
 
-pub fn get_char_by_index<T: Integer + ToPrimitive>(text: &str, index: T) -> char {
-    text.chars().nth(index.to_usize().unwrap()).unwrap()
+pub fn pem_label(input: &str) -> Option<&str> {
+    input
+        .strip_prefix("-----BEGIN ")?
+        .split_once("-----")
+        .map(|(label, _)| label)
 }
 
-pub fn get_index_by_char(text: &str, ch: char) -> usize {
-    text.chars().position(|c| c == ch).unwrap()
+pub fn get_char_by_index<T: Integer + ToPrimitive>(text: &str, index: T) -> char {
+    text.chars().nth(index.to_usize().unwrap()).unwrap()
 }
 
 pub fn char_repr(token: &str) -> &str {
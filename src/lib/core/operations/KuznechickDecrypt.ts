@@ -112,12 +112,16 @@ enum Mode {
 	CTR = "CTR",
 	OFB = "OFB",
 	CFB = "CFB",
-	ECB = "ECB"
+	CFB8 = "CFB8",
+	ECB = "ECB",
+	XTS = "XTS"
 }
 
 enum Padding {
 	NO = "no",
 	PKCS5 = "pkcs5",
+	ISO7816 = "iso7816",
+	X923 = "x923",
 	Zero = "zero",
 	Random = "random"
 }